@@ -239,6 +239,8 @@ fn build_preflight_request<'a>() -> RequestContext<'a> {
         access_control_request_method: Some("POST"),
         access_control_request_headers: Some("X-Custom-One, content-type"),
         access_control_request_private_network: true,
+        sec_fetch_site: None,
+        host: None,
     }
 }
 
@@ -249,6 +251,8 @@ fn build_null_origin_request<'a>() -> RequestContext<'a> {
         access_control_request_method: Some("POST"),
         access_control_request_headers: Some("x-custom-one"),
         access_control_request_private_network: true,
+        sec_fetch_site: None,
+        host: None,
     }
 }
 
@@ -259,6 +263,8 @@ fn build_simple_request<'a>() -> RequestContext<'a> {
         access_control_request_method: None,
         access_control_request_headers: None,
         access_control_request_private_network: false,
+        sec_fetch_site: None,
+        host: None,
     }
 }
 
@@ -269,6 +275,8 @@ fn build_simple_request_disallowed_method<'a>() -> RequestContext<'a> {
         access_control_request_method: None,
         access_control_request_headers: None,
         access_control_request_private_network: false,
+        sec_fetch_site: None,
+        host: None,
     }
 }
 
@@ -279,6 +287,8 @@ fn build_simple_request_uppercase() -> RequestContext<'static> {
         access_control_request_method: None,
         access_control_request_headers: Some(HEAVY_HEADER_LINE.as_ref()),
         access_control_request_private_network: false,
+        sec_fetch_site: None,
+        host: None,
     }
 }
 
@@ -289,6 +299,8 @@ fn build_heavy_preflight_request() -> RequestContext<'static> {
         access_control_request_method: Some(HEAVY_ACCESS_METHOD),
         access_control_request_headers: Some(HEAVY_HEADER_LINE.as_ref()),
         access_control_request_private_network: true,
+        sec_fetch_site: None,
+        host: None,
     }
 }
 
@@ -309,6 +321,8 @@ fn build_large_preflight_request(size: usize) -> RequestContext<'static> {
         access_control_request_method: Some(leaked_method),
         access_control_request_headers: Some(leaked_headers),
         access_control_request_private_network: true,
+        sec_fetch_site: None,
+        host: None,
     }
 }
 
@@ -426,10 +440,16 @@ fn bench_origin_matching(c: &mut Criterion) {
         OriginMatcher::Exact("https://bench.allowed".into()),
         OriginMatcher::pattern_str(r"^https://.*\.bench\.allowed$").unwrap(),
     ]);
+    let case_sensitive_list_origin = Origin::list([
+        OriginMatcher::Exact("https://bench.allowed".into()),
+        OriginMatcher::pattern_str_case_sensitive(r"^https://.*\.bench\.allowed$").unwrap(),
+    ]);
     let predicate_origin = Origin::custom(|origin, _| match origin {
         Some(value) if value.ends_with("bench.allowed") => OriginDecision::Mirror,
         _ => OriginDecision::Disallow,
     });
+    let large_exact_origin =
+        Origin::list((0..50_000).map(|idx| format!("https://tenant-{idx}.bench.allowed")));
 
     let mut group = c.benchmark_group("origin_matching");
     let ctx = build_simple_request();
@@ -454,6 +474,17 @@ fn bench_origin_matching(c: &mut Criterion) {
         })
     });
 
+    group.bench_function("list_origin_case_sensitive_regex_match", |b| {
+        b.iter(|| {
+            let decision =
+                case_sensitive_list_origin.resolve(Some("https://api.bench.allowed"), &ctx);
+            match decision {
+                OriginDecision::Mirror => {}
+                other => panic!("unexpected decision: {other:?}"),
+            }
+        })
+    });
+
     group.bench_function("predicate_origin_match", |b| {
         b.iter(|| {
             let decision = predicate_origin.resolve(Some("https://edge.bench.allowed"), &ctx);
@@ -464,6 +495,28 @@ fn bench_origin_matching(c: &mut Criterion) {
         })
     });
 
+    group.bench_function("large_exact_list_match", |b| {
+        b.iter(|| {
+            let decision =
+                large_exact_origin.resolve(Some("HTTPS://TENANT-42424.BENCH.ALLOWED"), &ctx);
+            match decision {
+                OriginDecision::Mirror => {}
+                other => panic!("unexpected decision: {other:?}"),
+            }
+        })
+    });
+
+    group.bench_function("large_exact_list_miss", |b| {
+        b.iter(|| {
+            let decision =
+                large_exact_origin.resolve(Some("https://tenant-50000.bench.allowed"), &ctx);
+            match decision {
+                OriginDecision::Disallow => {}
+                other => panic!("unexpected decision: {other:?}"),
+            }
+        })
+    });
+
     group.finish();
 }
 
@@ -663,6 +716,8 @@ fn bench_request_normalization(c: &mut Criterion) {
         access_control_request_method: Some("PuT"),
         access_control_request_headers: Some("X-Trace, X-DÉBUG"),
         access_control_request_private_network: true,
+        sec_fetch_site: None,
+        host: None,
     };
 
     group.bench_function("mixed_request_normalization", |b| {
@@ -678,6 +733,8 @@ fn bench_request_normalization(c: &mut Criterion) {
         access_control_request_method: Some(HEAVY_ACCESS_METHOD),
         access_control_request_headers: Some(LARGE_HEADER_LINE.as_ref()),
         access_control_request_private_network: true,
+        sec_fetch_site: None,
+        host: None,
     };
 
     group.bench_function("large_header_normalization", |b| {
@@ -71,7 +71,7 @@ where
         let context = owned_ctx.as_request_context();
 
         match cors.check(&context) {
-            Ok(CorsDecision::PreflightAccepted { headers }) => {
+            Ok(CorsDecision::PreflightAccepted { headers, .. }) => {
                 Box::pin(
                     async move { Ok(preflight_response(req, StatusCode::NO_CONTENT, headers)) },
                 )
@@ -80,7 +80,7 @@ where
                 let reason = rejection_message(&rejection.reason);
                 Box::pin(async move { Ok(preflight_rejection(req, rejection.headers, &reason)) })
             }
-            Ok(CorsDecision::SimpleAccepted { headers }) => {
+            Ok(CorsDecision::SimpleAccepted { headers, .. }) => {
                 let fut = self.service.call(req);
                 Box::pin(async move {
                     let mut res = fut.await?.map_into_left_body();
@@ -91,7 +91,7 @@ where
             Ok(CorsDecision::SimpleRejected(rejection)) => {
                 Box::pin(async move { Ok(simple_rejection(req, rejection)) })
             }
-            Ok(CorsDecision::NotApplicable) => {
+            Ok(CorsDecision::AllowedNoHeaders | CorsDecision::NotApplicable) => {
                 let fut = self.service.call(req);
                 Box::pin(async move { Ok(fut.await?.map_into_left_body()) })
             }
@@ -167,18 +167,36 @@ fn rejection_message(reason: &PreflightRejectionReason) -> String {
         PreflightRejectionReason::OriginNotAllowed => {
             "Preflight rejected: origin not allowed".into()
         }
+        PreflightRejectionReason::OriginMissing => "Preflight rejected: origin missing".into(),
+        PreflightRejectionReason::RequestMethodMissing => {
+            "Preflight rejected: request method missing".into()
+        }
         PreflightRejectionReason::MethodNotAllowed { requested_method } => {
             format!("Preflight rejected: method '{requested_method}' not allowed")
         }
         PreflightRejectionReason::HeadersNotAllowed { requested_headers } => {
             format!("Preflight rejected: headers '{requested_headers}' not allowed")
         }
+        PreflightRejectionReason::MalformedRequestMethod { requested_method } => {
+            format!("Preflight rejected: method '{requested_method}' is not a valid token")
+        }
+        PreflightRejectionReason::HeadersTooLarge { length, max } => {
+            format!("Preflight rejected: requested headers length {length} exceeds {max}")
+        }
+        PreflightRejectionReason::Multiple(reasons) => reasons
+            .iter()
+            .map(rejection_message)
+            .collect::<Vec<_>>()
+            .join("; "),
     }
 }
 
 fn simple_rejection_message(reason: &SimpleRejectionReason) -> &'static str {
     match reason {
         SimpleRejectionReason::OriginNotAllowed => "Simple request rejected: origin not allowed",
+        SimpleRejectionReason::MethodNotAllowed { .. } => {
+            "Simple request rejected: method not allowed"
+        }
     }
 }
 
@@ -188,6 +206,8 @@ struct OwnedRequestContext {
     access_control_request_method: Option<String>,
     access_control_request_headers: Option<String>,
     access_control_request_private_network: bool,
+    sec_fetch_site: Option<String>,
+    host: Option<String>,
 }
 
 impl OwnedRequestContext {
@@ -210,6 +230,8 @@ impl OwnedRequestContext {
                 .and_then(|value| value.to_str().ok())
                 .map(|value| value.eq_ignore_ascii_case("true"))
                 .unwrap_or(false),
+            sec_fetch_site: header_value(headers, header::SEC_FETCH_SITE),
+            host: header_value(headers, header::HOST),
         }
     }
 
@@ -220,6 +242,8 @@ impl OwnedRequestContext {
             access_control_request_method: self.access_control_request_method.as_deref(),
             access_control_request_headers: self.access_control_request_headers.as_deref(),
             access_control_request_private_network: self.access_control_request_private_network,
+            sec_fetch_site: self.sec_fetch_site.as_deref(),
+            host: self.host.as_deref(),
         }
     }
 }
@@ -23,7 +23,7 @@ pub async fn cors_middleware(
     let context = owned_ctx.as_request_context();
 
     match cors.check(&context) {
-        Ok(CorsDecision::PreflightAccepted { headers }) => {
+        Ok(CorsDecision::PreflightAccepted { headers, .. }) => {
             preflight_response(StatusCode::NO_CONTENT, headers)
         }
         Ok(CorsDecision::PreflightRejected(rejection)) => {
@@ -32,13 +32,13 @@ pub async fn cors_middleware(
             *response.body_mut() = Body::from(message);
             response
         }
-        Ok(CorsDecision::SimpleAccepted { headers }) => {
+        Ok(CorsDecision::SimpleAccepted { headers, .. }) => {
             let mut response = next.run(request).await;
             apply_headers(response.headers_mut(), &headers);
             response
         }
         Ok(CorsDecision::SimpleRejected(rejection)) => simple_rejection_response(rejection),
-        Ok(CorsDecision::NotApplicable) => next.run(request).await,
+        Ok(CorsDecision::AllowedNoHeaders | CorsDecision::NotApplicable) => next.run(request).await,
         Err(err) => middleware_error_response(err),
     }
 }
@@ -90,18 +90,36 @@ fn rejection_message(reason: &PreflightRejectionReason) -> String {
         PreflightRejectionReason::OriginNotAllowed => {
             "Preflight rejected: origin not allowed".into()
         }
+        PreflightRejectionReason::OriginMissing => "Preflight rejected: origin missing".into(),
+        PreflightRejectionReason::RequestMethodMissing => {
+            "Preflight rejected: request method missing".into()
+        }
         PreflightRejectionReason::MethodNotAllowed { requested_method } => {
             format!("Preflight rejected: method '{requested_method}' not allowed")
         }
         PreflightRejectionReason::HeadersNotAllowed { requested_headers } => {
             format!("Preflight rejected: headers '{requested_headers}' not allowed")
         }
+        PreflightRejectionReason::MalformedRequestMethod { requested_method } => {
+            format!("Preflight rejected: method '{requested_method}' is not a valid token")
+        }
+        PreflightRejectionReason::HeadersTooLarge { length, max } => {
+            format!("Preflight rejected: requested headers length {length} exceeds {max}")
+        }
+        PreflightRejectionReason::Multiple(reasons) => reasons
+            .iter()
+            .map(rejection_message)
+            .collect::<Vec<_>>()
+            .join("; "),
     }
 }
 
 fn simple_rejection_message(reason: &SimpleRejectionReason) -> &'static str {
     match reason {
         SimpleRejectionReason::OriginNotAllowed => "Simple request rejected: origin not allowed",
+        SimpleRejectionReason::MethodNotAllowed { .. } => {
+            "Simple request rejected: method not allowed"
+        }
     }
 }
 
@@ -111,6 +129,8 @@ struct OwnedRequestContext {
     access_control_request_method: Option<String>,
     access_control_request_headers: Option<String>,
     access_control_request_private_network: bool,
+    sec_fetch_site: Option<String>,
+    host: Option<String>,
 }
 
 impl OwnedRequestContext {
@@ -133,6 +153,8 @@ impl OwnedRequestContext {
                 .and_then(|value| value.to_str().ok())
                 .map(|value| value.eq_ignore_ascii_case("true"))
                 .unwrap_or(false),
+            sec_fetch_site: header_value(headers, header::SEC_FETCH_SITE),
+            host: header_value(headers, header::HOST),
         }
     }
 
@@ -143,6 +165,8 @@ impl OwnedRequestContext {
             access_control_request_method: self.access_control_request_method.as_deref(),
             access_control_request_headers: self.access_control_request_headers.as_deref(),
             access_control_request_private_network: self.access_control_request_private_network,
+            sec_fetch_site: self.sec_fetch_site.as_deref(),
+            host: self.host.as_deref(),
         }
     }
 }
@@ -50,7 +50,7 @@ where
         let decision = cors.check(&owned_ctx.as_request_context());
 
         match decision {
-            Ok(CorsDecision::PreflightAccepted { headers }) => {
+            Ok(CorsDecision::PreflightAccepted { headers, .. }) => {
                 Box::pin(async move { Ok(preflight_response(StatusCode::NO_CONTENT, headers)) })
             }
             Ok(CorsDecision::PreflightRejected(rejection)) => {
@@ -59,7 +59,7 @@ where
                     async move { Ok(preflight_rejection(rejection.headers, message.as_str())) },
                 )
             }
-            Ok(CorsDecision::SimpleAccepted { headers }) => {
+            Ok(CorsDecision::SimpleAccepted { headers, .. }) => {
                 let inner = self.inner.clone();
                 Box::pin(async move {
                     let mut response = inner.call(req).await?;
@@ -70,7 +70,7 @@ where
             Ok(CorsDecision::SimpleRejected(rejection)) => {
                 Box::pin(async move { Ok(simple_rejection_response(rejection)) })
             }
-            Ok(CorsDecision::NotApplicable) => {
+            Ok(CorsDecision::AllowedNoHeaders | CorsDecision::NotApplicable) => {
                 let inner = self.inner.clone();
                 Box::pin(async move { inner.call(req).await })
             }
@@ -146,18 +146,36 @@ fn rejection_message(reason: &PreflightRejectionReason) -> String {
         PreflightRejectionReason::OriginNotAllowed => {
             "Preflight rejected: origin not allowed".into()
         }
+        PreflightRejectionReason::OriginMissing => "Preflight rejected: origin missing".into(),
+        PreflightRejectionReason::RequestMethodMissing => {
+            "Preflight rejected: request method missing".into()
+        }
         PreflightRejectionReason::MethodNotAllowed { requested_method } => {
             format!("Preflight rejected: method '{requested_method}' not allowed")
         }
         PreflightRejectionReason::HeadersNotAllowed { requested_headers } => {
             format!("Preflight rejected: headers '{requested_headers}' not allowed")
         }
+        PreflightRejectionReason::MalformedRequestMethod { requested_method } => {
+            format!("Preflight rejected: method '{requested_method}' is not a valid token")
+        }
+        PreflightRejectionReason::HeadersTooLarge { length, max } => {
+            format!("Preflight rejected: requested headers length {length} exceeds {max}")
+        }
+        PreflightRejectionReason::Multiple(reasons) => reasons
+            .iter()
+            .map(rejection_message)
+            .collect::<Vec<_>>()
+            .join("; "),
     }
 }
 
 fn simple_rejection_message(reason: &SimpleRejectionReason) -> &'static str {
     match reason {
         SimpleRejectionReason::OriginNotAllowed => "Simple request rejected: origin not allowed",
+        SimpleRejectionReason::MethodNotAllowed { .. } => {
+            "Simple request rejected: method not allowed"
+        }
     }
 }
 
@@ -167,6 +185,8 @@ struct OwnedRequestContext {
     access_control_request_method: Option<String>,
     access_control_request_headers: Option<String>,
     access_control_request_private_network: bool,
+    sec_fetch_site: Option<String>,
+    host: Option<String>,
 }
 
 impl OwnedRequestContext {
@@ -189,6 +209,8 @@ impl OwnedRequestContext {
                 .and_then(|value| value.to_str().ok())
                 .map(|value| value.eq_ignore_ascii_case("true"))
                 .unwrap_or(false),
+            sec_fetch_site: header_value(headers, header::SEC_FETCH_SITE),
+            host: header_value(headers, header::HOST),
         }
     }
 
@@ -199,6 +221,8 @@ impl OwnedRequestContext {
             access_control_request_method: self.access_control_request_method.as_deref(),
             access_control_request_headers: self.access_control_request_headers.as_deref(),
             access_control_request_private_network: self.access_control_request_private_network,
+            sec_fetch_site: self.sec_fetch_site.as_deref(),
+            host: self.host.as_deref(),
         }
     }
 }
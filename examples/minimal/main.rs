@@ -0,0 +1,37 @@
+use bunner_cors_rs::{
+    AllowedMethods, Cors, CorsDecision, CorsOptions, Origin, OriginMatcher, RequestContext,
+};
+
+fn main() {
+    let cors = Cors::new(
+        CorsOptions::new()
+            .origin(Origin::list([
+                OriginMatcher::exact("https://app.example.com"),
+                OriginMatcher::host_any_port("http://localhost"),
+            ]))
+            .methods(AllowedMethods::list(["GET", "POST"])),
+    )
+    .expect("valid CORS configuration");
+
+    let ctx = RequestContext {
+        method: "GET",
+        origin: Some("https://app.example.com"),
+        access_control_request_method: None,
+        access_control_request_headers: None,
+        access_control_request_private_network: false,
+        sec_fetch_site: None,
+        host: None,
+    };
+
+    match cors.check(&ctx) {
+        Ok(CorsDecision::SimpleAccepted { headers, .. }) => println!("accepted: {headers:?}"),
+        Ok(other) => println!("not accepted: {other:?}"),
+        Err(err) => eprintln!("evaluation failed: {err}"),
+    }
+
+    // Built with `--no-default-features`, `re:` entries are rejected instead of
+    // compiled.
+    if let Err(err) = Origin::from_lines(r"re:^https://.*\.example\.com$") {
+        println!("patterns are disabled: {err}");
+    }
+}
@@ -0,0 +1,197 @@
+//! Optional integration helpers for [Actix Web](https://actix.rs).
+//!
+//! Enabled through the `actix` cargo feature. The module provides
+//! [`apply_cors_headers`] for hand-written middleware and a ready-made
+//! [`BunnerCors`] transform that evaluates every request against a shared
+//! [`Cors`] instance.
+
+use crate::constants::header;
+use crate::context::RequestContext;
+use crate::cors::Cors;
+use crate::headers::Headers;
+use crate::result::CorsDecision;
+use actix_web::body::{EitherBody, MessageBody};
+use actix_web::dev::{Service, ServiceRequest, ServiceResponse, Transform};
+use actix_web::http::StatusCode;
+use actix_web::http::header::{HeaderMap, HeaderName, HeaderValue};
+use actix_web::{Error, HttpResponse};
+use std::future::{Future, Ready, ready};
+use std::pin::Pin;
+use std::rc::Rc;
+use std::sync::Arc;
+use std::task::{Context, Poll};
+
+type LocalBoxFuture<T> = Pin<Box<dyn Future<Output = T>>>;
+
+/// Copies the headers produced by a [`CorsDecision`] onto an Actix response.
+///
+/// Existing values with the same name are replaced. Entries that are not valid
+/// HTTP header names or values are skipped.
+pub fn apply_cors_headers<B>(response: &mut ServiceResponse<B>, headers: &Headers) {
+    insert_headers(response.headers_mut(), headers);
+}
+
+fn insert_headers<'a>(
+    map: &mut HeaderMap,
+    headers: impl IntoIterator<Item = (&'a String, &'a String)>,
+) {
+    for (name, value) in headers {
+        if let (Ok(name), Ok(value)) = (
+            HeaderName::try_from(name.as_str()),
+            HeaderValue::from_str(value),
+        ) {
+            map.insert(name, value);
+        }
+    }
+}
+
+/// Actix Web middleware factory that evaluates requests with a shared [`Cors`].
+///
+/// Accepted preflight requests are answered directly with `204 No Content`,
+/// rejected requests receive the status and headers from their
+/// `to_response_parts` (`403 Forbidden` by default), and accepted simple
+/// requests are forwarded to the wrapped service before the CORS headers are
+/// injected into its response.
+#[derive(Clone)]
+pub struct BunnerCors {
+    cors: Arc<Cors>,
+}
+
+impl BunnerCors {
+    /// Creates a middleware factory backed by the provided evaluator.
+    pub fn new(cors: Arc<Cors>) -> Self {
+        Self { cors }
+    }
+}
+
+impl From<Cors> for BunnerCors {
+    fn from(cors: Cors) -> Self {
+        Self::new(Arc::new(cors))
+    }
+}
+
+impl<S, B> Transform<S, ServiceRequest> for BunnerCors
+where
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error> + 'static,
+    B: MessageBody + 'static,
+{
+    type Response = ServiceResponse<EitherBody<B>>;
+    type Error = Error;
+    type Transform = BunnerCorsMiddleware<S>;
+    type InitError = ();
+    type Future = Ready<Result<Self::Transform, Self::InitError>>;
+
+    fn new_transform(&self, service: S) -> Self::Future {
+        ready(Ok(BunnerCorsMiddleware {
+            service: Rc::new(service),
+            cors: Arc::clone(&self.cors),
+        }))
+    }
+}
+
+/// Service produced by [`BunnerCors`].
+pub struct BunnerCorsMiddleware<S> {
+    service: Rc<S>,
+    cors: Arc<Cors>,
+}
+
+impl<S, B> Service<ServiceRequest> for BunnerCorsMiddleware<S>
+where
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error> + 'static,
+    B: MessageBody + 'static,
+{
+    type Response = ServiceResponse<EitherBody<B>>;
+    type Error = Error;
+    type Future = LocalBoxFuture<Result<Self::Response, Self::Error>>;
+
+    fn poll_ready(&self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.service.poll_ready(cx)
+    }
+
+    fn call(&self, req: ServiceRequest) -> Self::Future {
+        let decision = {
+            let headers = req.headers();
+            let context = RequestContext {
+                method: req.method().as_str(),
+                origin: header_str(headers, header::ORIGIN),
+                access_control_request_method: header_str(
+                    headers,
+                    header::ACCESS_CONTROL_REQUEST_METHOD,
+                ),
+                access_control_request_headers: header_str(
+                    headers,
+                    header::ACCESS_CONTROL_REQUEST_HEADERS,
+                ),
+                access_control_request_private_network: header_str(
+                    headers,
+                    header::ACCESS_CONTROL_REQUEST_PRIVATE_NETWORK,
+                )
+                .is_some_and(|value| value.eq_ignore_ascii_case("true")),
+                sec_fetch_site: header_str(headers, header::SEC_FETCH_SITE),
+                host: header_str(headers, header::HOST),
+            };
+            self.cors.check(&context)
+        };
+
+        match decision {
+            Ok(CorsDecision::PreflightAccepted { headers, .. }) => {
+                let response = respond(req, StatusCode::NO_CONTENT, &headers);
+                Box::pin(ready(Ok(response)))
+            }
+            Ok(CorsDecision::PreflightRejected(rejection)) => {
+                let response = reject(req, rejection.to_response_parts());
+                Box::pin(ready(Ok(response)))
+            }
+            Ok(CorsDecision::SimpleRejected(rejection)) => {
+                let response = reject(req, rejection.to_response_parts());
+                Box::pin(ready(Ok(response)))
+            }
+            Ok(CorsDecision::SimpleAccepted { headers, .. }) => {
+                let service = Rc::clone(&self.service);
+                Box::pin(async move {
+                    let mut response = service.call(req).await?.map_into_left_body();
+                    apply_cors_headers(&mut response, &headers);
+                    Ok(response)
+                })
+            }
+            Ok(CorsDecision::AllowedNoHeaders | CorsDecision::NotApplicable) => {
+                let service = Rc::clone(&self.service);
+                Box::pin(async move { Ok(service.call(req).await?.map_into_left_body()) })
+            }
+            Err(_) => {
+                let response = respond(req, StatusCode::INTERNAL_SERVER_ERROR, &Headers::new());
+                Box::pin(ready(Ok(response)))
+            }
+        }
+    }
+}
+
+fn respond<B>(
+    req: ServiceRequest,
+    status: StatusCode,
+    headers: &Headers,
+) -> ServiceResponse<EitherBody<B>> {
+    let mut response = HttpResponse::new(status);
+    insert_headers(response.headers_mut(), headers);
+    req.into_response(response).map_into_right_body()
+}
+
+/// Answers a rejected request with the status and headers from
+/// `to_response_parts`, falling back to `403 Forbidden` for a status Actix
+/// cannot represent.
+fn reject<B>(
+    req: ServiceRequest,
+    (status, headers): (u16, Vec<(String, String)>),
+) -> ServiceResponse<EitherBody<B>> {
+    let status = StatusCode::from_u16(status).unwrap_or(StatusCode::FORBIDDEN);
+    let mut response = HttpResponse::new(status);
+    insert_headers(
+        response.headers_mut(),
+        headers.iter().map(|(name, value)| (name, value)),
+    );
+    req.into_response(response).map_into_right_body()
+}
+
+fn header_str<'a>(headers: &'a HeaderMap, name: &str) -> Option<&'a str> {
+    headers.get(name).and_then(|value| value.to_str().ok())
+}
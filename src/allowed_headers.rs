@@ -1,4 +1,4 @@
-use crate::util::normalize_lower;
+use crate::util::{is_http_token, normalize_lower};
 use std::cell::RefCell;
 use std::collections::HashSet;
 use std::ops::Deref;
@@ -36,7 +36,11 @@ impl AllowedHeadersCache {
                 .map(|value| value.trim())
                 .filter(|value| !value.is_empty())
                 .for_each(|header| {
-                    self.normalized_tokens.push(normalize_lower(header));
+                    let normalized = normalize_lower(header);
+                    // `X-Test, x-test` names one header; compare it once.
+                    if !self.normalized_tokens.contains(&normalized) {
+                        self.normalized_tokens.push(normalized);
+                    }
                 });
         }
 
@@ -54,10 +58,24 @@ impl AllowedHeadersCache {
 /// This enum mirrors the semantics of `Access-Control-Allow-Headers` and is
 /// typically configured through [`CorsOptions`]. All comparisons are
 /// case-insensitive and duplicate values are automatically removed.
+///
+/// [`AllowedHeaders::MirrorRequest`] accepts every requested header and echoes
+/// the `Access-Control-Request-Headers` value back, which unlike `*` remains
+/// usable when credentials are enabled.
+///
+/// [`AllowedHeaders::ListEchoCase`] validates like [`AllowedHeaders::List`] but
+/// emits each configured header in the casing the client requested it with.
+///
+/// [`AllowedHeaders::EchoRequest`] also accepts every requested header, but
+/// echoes only the tokens that are valid header names and drops the rest
+/// instead of reflecting the value verbatim.
 #[derive(Clone, PartialEq, Eq)]
 pub enum AllowedHeaders {
     Any,
     List(AllowedHeaderList),
+    MirrorRequest,
+    ListEchoCase(AllowedHeaderList),
+    EchoRequest,
 }
 
 impl Default for AllowedHeaders {
@@ -76,17 +94,24 @@ impl AllowedHeaders {
         I: IntoIterator<Item = S>,
         S: Into<String>,
     {
-        let mut seen = HashSet::new();
-        let mut deduped: Vec<String> = Vec::new();
-        for value in values.into_iter() {
-            let trimmed = value.into().trim().to_string();
-            let key = normalize_lower(&trimmed);
-            if seen.insert(key) {
-                deduped.push(trimmed);
-            }
-        }
+        Self::List(AllowedHeaderList::dedup(values))
+    }
+
+    /// Constructs an [`AllowedHeaders::ListEchoCase`] allow-list.
+    ///
+    /// Values are deduplicated exactly like [`AllowedHeaders::list`].
+    pub fn list_echo_case<I, S>(values: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        Self::ListEchoCase(AllowedHeaderList::dedup(values))
+    }
 
-        Self::List(AllowedHeaderList::new(deduped, seen))
+    /// Constructs an [`AllowedHeaders::EchoRequest`] policy, meant for
+    /// diagnostics endpoints that report which valid headers a client sent.
+    pub fn echo_request() -> Self {
+        Self::EchoRequest
     }
 
     /// Validates the requested header list from an `Access-Control-Request-Headers`
@@ -96,11 +121,13 @@ impl AllowedHeaders {
     /// tokenization for identical header strings within a single request.
     pub fn allows_headers(&self, request_headers: &str) -> bool {
         match self {
-            Self::Any => true,
-            Self::List(allowed) => REQUEST_HEADER_CACHE.with(|cache| {
-                let mut cache = cache.borrow_mut();
-                allowed.allows_headers_with_cache(request_headers, &mut cache)
-            }),
+            Self::Any | Self::MirrorRequest | Self::EchoRequest => true,
+            Self::List(allowed) | Self::ListEchoCase(allowed) => {
+                REQUEST_HEADER_CACHE.with(|cache| {
+                    let mut cache = cache.borrow_mut();
+                    allowed.allows_headers_with_cache(request_headers, &mut cache)
+                })
+            }
         }
     }
 
@@ -113,10 +140,36 @@ impl AllowedHeaders {
         cache: &mut AllowedHeadersCache,
     ) -> bool {
         match self {
-            Self::Any => true,
-            Self::List(allowed) => allowed.allows_headers_with_cache(request_headers, cache),
+            Self::Any | Self::MirrorRequest | Self::EchoRequest => true,
+            Self::List(allowed) | Self::ListEchoCase(allowed) => {
+                allowed.allows_headers_with_cache(request_headers, cache)
+            }
         }
     }
+
+    /// Reports, for each provided header name, whether a client may send it
+    /// under this policy.
+    ///
+    /// Names are trimmed and returned in input order. Empty names are never
+    /// allowed, and [`AllowedHeaders::EchoRequest`] allows only valid RFC 7230
+    /// tokens. Useful for documenting which headers an API accepts.
+    pub fn allows_each(&self, headers: &[&str]) -> Vec<(String, bool)> {
+        headers
+            .iter()
+            .map(|header| {
+                let header = header.trim();
+                let allowed = !header.is_empty()
+                    && match self {
+                        Self::Any | Self::MirrorRequest => true,
+                        Self::EchoRequest => is_http_token(header),
+                        Self::List(allowed) | Self::ListEchoCase(allowed) => {
+                            allowed.allows_header(header)
+                        }
+                    };
+                (header.to_string(), allowed)
+            })
+            .collect()
+    }
 }
 
 /// Internally used storage for the normalized allow-list representation.
@@ -127,14 +180,35 @@ pub struct AllowedHeaderList {
 }
 
 impl AllowedHeaderList {
-    fn new(values: Vec<String>, normalized: HashSet<String>) -> Self {
-        Self { values, normalized }
+    fn dedup<I, S>(values: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        let mut normalized = HashSet::new();
+        let mut deduped: Vec<String> = Vec::new();
+        for value in values.into_iter() {
+            let trimmed = value.into().trim().to_string();
+            let key = normalize_lower(&trimmed);
+            if normalized.insert(key) {
+                deduped.push(trimmed);
+            }
+        }
+
+        Self {
+            values: deduped,
+            normalized,
+        }
     }
 
     pub fn values(&self) -> &[String] {
         &self.values
     }
 
+    fn allows_header(&self, header: &str) -> bool {
+        self.normalized.contains(normalize_lower(header).as_str())
+    }
+
     fn allows_headers_with_cache(
         &self,
         request_headers: &str,
@@ -130,6 +130,15 @@ mod allows_headers {
         assert!(headers.allows_headers_with_cache("x-custom", &mut cache));
     }
 
+    #[test]
+    fn should_allow_all_headers_when_mirror_request_variant_then_accept_request_headers() {
+        let headers = AllowedHeaders::MirrorRequest;
+        let mut cache = AllowedHeadersCache::new();
+
+        assert!(headers.allows_headers("x-custom, x-other"));
+        assert!(headers.allows_headers_with_cache("x-custom", &mut cache));
+    }
+
     #[test]
     fn should_allow_headers_when_case_differs_then_accept_request() {
         let headers = AllowedHeaders::list(["X-Custom", "Content-Type"]);
@@ -187,6 +196,72 @@ mod allows_headers {
     }
 }
 
+mod echo_request {
+    use super::*;
+
+    #[test]
+    fn should_accept_any_request_when_echo_request_then_leave_filtering_to_emission() {
+        let headers = AllowedHeaders::echo_request();
+
+        assert!(headers == AllowedHeaders::EchoRequest);
+        assert!(headers.allows_headers("x-test, bad header"));
+    }
+}
+
+mod allows_each {
+    use super::*;
+
+    #[test]
+    fn should_report_each_header_when_list_policy_then_mark_unlisted_headers_disallowed() {
+        let headers = AllowedHeaders::list(["Content-Type", "X-Api-Key"]);
+
+        let result = headers.allows_each(&["content-type", " X-Api-Key ", "X-Debug", ""]);
+
+        assert_eq!(
+            result,
+            vec![
+                ("content-type".to_string(), true),
+                ("X-Api-Key".to_string(), true),
+                ("X-Debug".to_string(), false),
+                (String::new(), false),
+            ]
+        );
+    }
+
+    #[test]
+    fn should_allow_every_header_when_any_policy_then_mark_all_allowed() {
+        let result = AllowedHeaders::Any.allows_each(&["X-Debug", "Authorization"]);
+
+        assert_eq!(
+            result,
+            vec![
+                ("X-Debug".to_string(), true),
+                ("Authorization".to_string(), true),
+            ]
+        );
+    }
+
+    #[test]
+    fn should_allow_every_header_when_mirror_request_policy_then_mark_all_allowed() {
+        let result = AllowedHeaders::MirrorRequest.allows_each(&["X-Trace"]);
+
+        assert_eq!(result, vec![("X-Trace".to_string(), true)]);
+    }
+
+    #[test]
+    fn should_disallow_invalid_token_when_echo_request_policy_then_mark_only_valid_allowed() {
+        let result = AllowedHeaders::echo_request().allows_each(&["X-Trace", "bad header"]);
+
+        assert_eq!(
+            result,
+            vec![
+                ("X-Trace".to_string(), true),
+                ("bad header".to_string(), false),
+            ]
+        );
+    }
+}
+
 mod cache_behavior {
     use super::*;
 
@@ -205,6 +280,15 @@ mod cache_behavior {
         assert_eq!(second[1], "sentinel");
     }
 
+    #[test]
+    fn should_collapse_repeated_tokens_when_case_differs_then_keep_single_entry() {
+        let mut cache = AllowedHeadersCache::new();
+
+        let tokens = cache.prepare("X-Test, x-test, X-Other, X-TEST");
+
+        assert_eq!(tokens, &["x-test".to_string(), "x-other".to_string()]);
+    }
+
     #[test]
     fn should_reset_cache_when_reset_called_then_clear_state() {
         let mut cache = AllowedHeadersCache::new();
@@ -1,5 +1,6 @@
 use crate::constants::method;
-use crate::util::{equals_ignore_case, normalize_lower};
+use crate::options::ValidationError;
+use crate::util::{equals_ignore_case, is_http_token, normalize_lower};
 use std::collections::HashSet;
 use std::ops::{Deref, DerefMut};
 
@@ -34,6 +35,40 @@ impl AllowedMethods {
         Self(deduped)
     }
 
+    /// Parses a comma-separated method line such as `"GET, POST, OPTIONS"`.
+    ///
+    /// Entries are trimmed and deduplicated like [`AllowedMethods::list`]. Empty
+    /// entries, `*`, and values that are not HTTP tokens are rejected with the
+    /// same [`ValidationError`] that [`CorsOptions::validate`](crate::CorsOptions::validate)
+    /// would report.
+    pub fn parse(line: &str) -> Result<Self, ValidationError> {
+        let mut values = Vec::new();
+        for value in line.split(',').map(str::trim) {
+            if value.is_empty() {
+                return Err(ValidationError::AllowedMethodsCannotContainEmptyToken);
+            }
+            if value == "*" {
+                return Err(ValidationError::AllowedMethodsCannotContainWildcard);
+            }
+            if !is_http_token(value) {
+                return Err(ValidationError::AllowedMethodsListContainsInvalidToken);
+            }
+            values.push(value);
+        }
+
+        Ok(Self::list(values))
+    }
+
+    /// Reorders the methods alphabetically (ignoring ASCII case).
+    ///
+    /// [`AllowedMethods::list`] keeps insertion order; use this when the emitted
+    /// `Access-Control-Allow-Methods` value must be deterministic regardless of
+    /// how the list was assembled.
+    pub fn sorted(mut self) -> Self {
+        self.0.sort_by_cached_key(|value| normalize_lower(value));
+        self
+    }
+
     /// Serializes the configured methods into a canonical header string.
     ///
     /// Returns `None` when the list is empty so callers can skip emitting
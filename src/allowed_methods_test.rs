@@ -45,6 +45,79 @@ mod list {
     }
 }
 
+mod parse {
+    use super::*;
+    use crate::options::ValidationError;
+
+    #[test]
+    fn should_split_and_trim_when_line_well_formed_then_build_list() {
+        let methods = AllowedMethods::parse("GET, POST ,OPTIONS, get").expect("valid method line");
+
+        assert_eq!(
+            methods.into_inner(),
+            vec![
+                method::GET.to_string(),
+                method::POST.to_string(),
+                method::OPTIONS.to_string(),
+            ]
+        );
+    }
+
+    #[test]
+    fn should_return_error_when_line_contains_empty_token_then_reject() {
+        let result = AllowedMethods::parse("GET,,POST");
+
+        assert_eq!(
+            result,
+            Err(ValidationError::AllowedMethodsCannotContainEmptyToken)
+        );
+    }
+
+    #[test]
+    fn should_return_error_when_line_contains_invalid_token_then_reject() {
+        let result = AllowedMethods::parse("GET, PO ST");
+
+        assert_eq!(
+            result,
+            Err(ValidationError::AllowedMethodsListContainsInvalidToken)
+        );
+    }
+
+    #[test]
+    fn should_return_error_when_line_contains_wildcard_then_reject() {
+        let result = AllowedMethods::parse("GET, *");
+
+        assert_eq!(
+            result,
+            Err(ValidationError::AllowedMethodsCannotContainWildcard)
+        );
+    }
+}
+
+mod sorted {
+    use super::*;
+
+    #[test]
+    fn should_emit_alphabetical_order_when_sorted_then_ignore_insertion_order() {
+        let methods = AllowedMethods::list(["PUT", "get", "DELETE", "POST"]).sorted();
+
+        assert_eq!(
+            methods.header_value(),
+            Some("DELETE,get,POST,PUT".to_string())
+        );
+    }
+
+    #[test]
+    fn should_emit_insertion_order_when_not_sorted_then_preserve_input() {
+        let methods = AllowedMethods::list(["PUT", "GET", "DELETE", "POST"]);
+
+        assert_eq!(
+            methods.header_value(),
+            Some("PUT,GET,DELETE,POST".to_string())
+        );
+    }
+}
+
 mod header_value {
     use super::*;
 
@@ -10,12 +10,30 @@ pub mod header {
     pub const ACCESS_CONTROL_REQUEST_METHOD: &str = "Access-Control-Request-Method";
     pub const ACCESS_CONTROL_REQUEST_PRIVATE_NETWORK: &str =
         "Access-Control-Request-Private-Network";
+    pub const CONTENT_LENGTH: &str = "Content-Length";
+    pub const HOST: &str = "Host";
+    pub const SEC_FETCH_SITE: &str = "Sec-Fetch-Site";
     pub const TIMING_ALLOW_ORIGIN: &str = "Timing-Allow-Origin";
     pub const ORIGIN: &str = "Origin";
     pub const VARY: &str = "Vary";
 }
 
+/// Response headers emitted by the CORS engine itself.
+pub(crate) const CORS_MANAGED_HEADERS: [&str; 10] = [
+    header::ACCESS_CONTROL_ALLOW_ORIGIN,
+    header::ACCESS_CONTROL_ALLOW_METHODS,
+    header::ACCESS_CONTROL_ALLOW_HEADERS,
+    header::ACCESS_CONTROL_ALLOW_CREDENTIALS,
+    header::ACCESS_CONTROL_ALLOW_PRIVATE_NETWORK,
+    header::ACCESS_CONTROL_EXPOSE_HEADERS,
+    header::ACCESS_CONTROL_MAX_AGE,
+    header::CONTENT_LENGTH,
+    header::TIMING_ALLOW_ORIGIN,
+    header::VARY,
+];
+
 pub mod method {
+    pub const CONNECT: &str = "CONNECT";
     pub const DELETE: &str = "DELETE";
     pub const GET: &str = "GET";
     pub const HEAD: &str = "HEAD";
@@ -23,4 +41,5 @@ pub mod method {
     pub const PATCH: &str = "PATCH";
     pub const POST: &str = "POST";
     pub const PUT: &str = "PUT";
+    pub const TRACE: &str = "TRACE";
 }
@@ -15,4 +15,53 @@ pub struct RequestContext<'a> {
     pub access_control_request_headers: Option<&'a str>,
     /// Indicates that the request is asking for private network access.
     pub access_control_request_private_network: bool,
+    /// Value of the `Sec-Fetch-Site` header (`cross-site`, `same-site`,
+    /// `same-origin`, or `none`), if supplied by the client.
+    ///
+    /// The engine does not act on it; custom origin callbacks and predicates can
+    /// use it as an additional signal.
+    pub sec_fetch_site: Option<&'a str>,
+    /// Value of the `Host` header (or the HTTP/2 `:authority`), if known.
+    ///
+    /// Only consulted by [`CorsOptions::reject_origin_host_mismatch`](crate::CorsOptions::reject_origin_host_mismatch).
+    pub host: Option<&'a str>,
 }
+
+impl RequestContext<'_> {
+    /// Returns `true` when the request is a CORS preflight: an `OPTIONS`
+    /// request (ASCII case-insensitive) carrying a non-empty
+    /// `Access-Control-Request-Method`.
+    ///
+    /// This is the same test [`Cors::check`](crate::Cors::check) applies, so
+    /// middleware can route preflights before evaluating them.
+    pub fn is_preflight(&self) -> bool {
+        self.method.trim().eq_ignore_ascii_case("OPTIONS")
+            && self
+                .access_control_request_method
+                .is_some_and(|method| !method.trim().is_empty())
+    }
+}
+
+#[cfg(feature = "http")]
+impl<'a> RequestContext<'a> {
+    /// Starts a context from an [`http::Method`] with every other field empty.
+    ///
+    /// Fill in the headers with struct update syntax, e.g.
+    /// `RequestContext { origin: Some(origin), ..RequestContext::with_http_method(req.method()) }`.
+    /// Requires the `http` feature.
+    pub fn with_http_method(method: &'a http::Method) -> Self {
+        Self {
+            method: method.as_str(),
+            origin: None,
+            access_control_request_method: None,
+            access_control_request_headers: None,
+            access_control_request_private_network: false,
+            sec_fetch_site: None,
+            host: None,
+        }
+    }
+}
+
+#[cfg(test)]
+#[path = "context_test.rs"]
+mod context_test;
@@ -0,0 +1,71 @@
+use super::*;
+
+fn context<'a>(method: &'a str, request_method: Option<&'a str>) -> RequestContext<'a> {
+    RequestContext {
+        method,
+        origin: Some("https://app.test"),
+        access_control_request_method: request_method,
+        access_control_request_headers: None,
+        access_control_request_private_network: false,
+        sec_fetch_site: None,
+        host: None,
+    }
+}
+
+mod is_preflight {
+    use super::*;
+
+    #[test]
+    fn should_return_true_when_options_with_request_method_then_detect_preflight() {
+        assert!(context("OPTIONS", Some("POST")).is_preflight());
+        assert!(context("options", Some("PUT")).is_preflight());
+    }
+
+    #[test]
+    fn should_return_false_when_options_without_request_method_then_treat_as_plain_options() {
+        assert!(!context("OPTIONS", None).is_preflight());
+        assert!(!context("OPTIONS", Some("  ")).is_preflight());
+    }
+
+    #[test]
+    fn should_return_false_when_method_not_options_then_treat_as_simple() {
+        assert!(!context("GET", Some("POST")).is_preflight());
+    }
+}
+
+#[cfg(feature = "http")]
+mod with_http_method {
+    use super::*;
+    use crate::{Cors, CorsDecision, CorsOptions, Origin};
+
+    #[test]
+    fn should_borrow_method_name_when_built_then_leave_headers_empty() {
+        let method = http::Method::PATCH;
+
+        let context = RequestContext::with_http_method(&method);
+
+        assert_eq!(context.method, "PATCH");
+        assert!(context.origin.is_none());
+        assert!(context.access_control_request_method.is_none());
+        assert!(context.access_control_request_headers.is_none());
+        assert!(!context.access_control_request_private_network);
+        assert!(context.sec_fetch_site.is_none());
+        assert!(context.host.is_none());
+    }
+
+    #[test]
+    fn should_evaluate_as_preflight_when_options_method_used_then_accept_request() {
+        let cors = Cors::new(CorsOptions::new().origin(Origin::list(["https://app.test"])))
+            .expect("valid CORS configuration");
+        let method = http::Method::OPTIONS;
+        let context = RequestContext {
+            origin: Some("https://app.test"),
+            access_control_request_method: Some("POST"),
+            ..RequestContext::with_http_method(&method)
+        };
+
+        let decision = cors.check(&context).expect("evaluation succeeds");
+
+        assert!(matches!(decision, CorsDecision::PreflightAccepted { .. }));
+    }
+}
@@ -1,20 +1,59 @@
+use crate::allowed_headers::AllowedHeaders;
+use crate::constants::{header, method};
 use crate::context::RequestContext;
-use crate::header_builder::HeaderBuilder;
+#[cfg(feature = "serde")]
+use crate::exposed_headers::ExposedHeaders;
+use crate::header_builder::{HeaderBuilder, PrecomputedHeaders};
+use crate::headers::{HeaderCollection, HeaderSink, Headers};
 use crate::normalized_request::NormalizedRequest;
-use crate::options::{CorsOptions, ValidationError};
-use crate::origin::OriginDecision;
+use crate::options::{CorsOptions, ValidationError, ValidationReporterFn};
+#[cfg(feature = "serde")]
+use crate::origin::OriginMatcher;
+use crate::origin::{Origin, OriginDecision};
 use crate::result::{
-    CorsDecision, CorsError, PreflightRejection, PreflightRejectionReason, SimpleRejection,
-    SimpleRejectionReason,
+    AuditReport, CorsDecision, CorsError, PreflightGrants, PreflightRejection,
+    PreflightRejectionReason, SimpleRejection, SimpleRejectionReason, WebSocketDecision,
 };
+use crate::util::{
+    equals_ignore_case, is_cors_safelisted_method, normalize_lower, strip_trailing_slash,
+};
+use arc_swap::ArcSwap;
+#[cfg(feature = "serde")]
+use serde_json::{Value, json};
+use std::sync::Arc;
+#[cfg(feature = "timing")]
+use std::time::{Duration, Instant};
 
 /// High-level entry point that evaluates incoming requests against a [`CorsOptions`]
 /// configuration and produces a [`CorsDecision`].
 ///
-/// The struct is intentionally lightweight; cloning it is cheap because the heavy
-/// lifting happens per-request.
+/// The active configuration lives behind an atomically swappable pointer, so
+/// [`Cors::reload`] can replace it at runtime while in-flight evaluations keep
+/// the snapshot they started with.
 pub struct Cors {
+    state: ArcSwap<CorsState>,
+}
+
+/// Frozen form of [`Cors`] produced by [`Cors::compile`].
+///
+/// Every header value that depends only on the configuration is rendered up
+/// front, so request evaluation performs no lazy initialization and takes no
+/// locks. The type is `Send + Sync` and meant to be shared behind an `Arc`.
+pub struct CompiledCors {
+    state: CorsState,
+}
+
+impl CompiledCors {
+    fn snapshot(&self) -> &CorsState {
+        &self.state
+    }
+}
+
+/// Validated configuration snapshot evaluated by [`Cors`] and [`CompiledCors`].
+#[derive(Clone)]
+struct CorsState {
     options: CorsOptions,
+    precomputed: Option<PrecomputedHeaders>,
 }
 
 impl Cors {
@@ -24,31 +63,557 @@ impl Cors {
     /// so failing fast here prevents inconsistent behaviour later in the pipeline.
     pub fn new(options: CorsOptions) -> Result<Self, ValidationError> {
         options.validate()?;
-        Ok(Self { options })
+        Ok(Self {
+            state: ArcSwap::from_pointee(CorsState {
+                options: options.prepared(),
+                precomputed: None,
+            }),
+        })
     }
 
-    /// Evaluates an incoming request and determines the appropriate CORS response.
+    /// Creates a CORS evaluator like [`Cors::new`], passing the validation
+    /// error to `reporter` before returning it.
     ///
-    /// The method normalizes the raw request metadata, automatically dispatching
-    /// to the preflight or simple request handling paths as defined by the CORS
-    /// specification. The resulting [`CorsDecision`] encapsulates both header
-    /// mutations and rejection reasons so callers can surface precise feedback to
-    /// upstream layers.
-    pub fn check(&self, request: &RequestContext<'_>) -> Result<CorsDecision, CorsError> {
-        let normalized_request = NormalizedRequest::new(request);
+    /// Hosts that build many policies, e.g. one per tenant, can share one
+    /// reporter to collect every misconfiguration in a single place.
+    pub fn new_with_reporter(
+        options: CorsOptions,
+        reporter: Arc<ValidationReporterFn>,
+    ) -> Result<Self, ValidationError> {
+        Self::new(options).inspect_err(|error| reporter(error))
+    }
+
+    /// Renders all configuration-derived header values once and returns an
+    /// immutable evaluator suited for sharing across threads.
+    pub fn compile(self) -> CompiledCors {
+        let mut state = Arc::unwrap_or_clone(self.state.into_inner());
+        state.precomputed = Some(PrecomputedHeaders::new(&state.options));
+        CompiledCors { state }
+    }
+
+    /// Validates `options` and atomically swaps them in as the active
+    /// configuration.
+    ///
+    /// Evaluations already in progress finish against the previous snapshot,
+    /// while subsequent calls observe the new one; no locks are taken on either
+    /// side. On validation failure the current configuration is left untouched.
+    pub fn reload(&self, options: CorsOptions) -> Result<(), ValidationError> {
+        options.validate()?;
+        self.state.store(Arc::new(CorsState {
+            options: options.prepared(),
+            precomputed: None,
+        }));
+        Ok(())
+    }
+
+    fn snapshot(&self) -> arc_swap::Guard<Arc<CorsState>> {
+        self.state.load()
+    }
+}
+
+/// Defines the evaluation API shared by [`Cors`] and [`CompiledCors`], so the
+/// two stay in step. Each type provides a private `snapshot` returning the
+/// [`CorsState`] to evaluate against.
+macro_rules! evaluation_api {
+    ($evaluator:ty) => {
+        impl $evaluator {
+            /// Evaluates an incoming request and determines the appropriate CORS response.
+            ///
+            /// The method normalizes the raw request metadata, automatically dispatching
+            /// to the preflight or simple request handling paths as defined by the CORS
+            /// specification. The resulting [`CorsDecision`] encapsulates both header
+            /// mutations and rejection reasons so callers can surface precise feedback to
+            /// upstream layers.
+            pub fn check(&self, request: &RequestContext<'_>) -> Result<CorsDecision, CorsError> {
+                self.snapshot().check(request)
+            }
+
+            /// Evaluates the request like [`Cors::check`], then moves the decision's
+            /// headers into `sink`.
+            ///
+            /// The headers are still collected into a [`Headers`] map first and then
+            /// drained, so this saves copying them out of the decision, not building the
+            /// map. The returned decision keeps its kind, rejection reason, and grants,
+            /// but its header map is empty. `Vary` is pushed first and the remaining headers
+            /// follow in case-insensitive name order, or in the canonical CORS order
+            /// under [`CorsOptions::deterministic_header_order`]. Nothing is pushed on
+            /// error.
+            pub fn check_with_sink(
+                &self,
+                request: &RequestContext<'_>,
+                sink: &mut impl HeaderSink,
+            ) -> Result<CorsDecision, CorsError> {
+                self.snapshot().check_with_sink(request, sink)
+            }
+
+            /// Evaluates the request `normalized` was built from like [`Cors::check`],
+            /// reusing that normalization.
+            ///
+            /// Middleware that consults several policies for the same request can
+            /// normalize once with [`NormalizedRequest::new`] and share the result.
+            /// When [`CorsOptions::default_origin_when_missing`] substitutes an origin,
+            /// or [`CorsOptions::request_header_rewriter`] is set and the request
+            /// carries `Access-Control-Request-Headers`, the request is normalized
+            /// again internally.
+            pub fn check_normalized(
+                &self,
+                normalized: &NormalizedRequest<'_>,
+            ) -> Result<CorsDecision, CorsError> {
+                self.snapshot().check_normalized(normalized)
+            }
+
+            /// Evaluates the request like [`Cors::check`] and also returns the wall-clock
+            /// time spent, including origin predicates and callbacks.
+            ///
+            /// Available with the `timing` feature.
+            #[cfg(feature = "timing")]
+            pub fn check_timed(
+                &self,
+                request: &RequestContext<'_>,
+            ) -> (Result<CorsDecision, CorsError>, Duration) {
+                let started = Instant::now();
+                let result = self.check(request);
+                (result, started.elapsed())
+            }
+
+            /// Runs only the origin policy for the request, without building headers.
+            ///
+            /// The verdict accounts for `Origin: null` handling and the origin length
+            /// limit exactly as [`Cors::check`] does, which makes it a cheap option for
+            /// audit or logging layers. Unlike `check`, an [`OriginDecision::Any`]
+            /// returned by a custom callback while credentials are enabled is reported
+            /// as-is rather than as a [`CorsError`].
+            pub fn resolve_origin(&self, request: &RequestContext<'_>) -> OriginDecision {
+                self.snapshot().resolve_origin(request)
+            }
+
+            /// Decides whether a WebSocket upgrade request should be accepted.
+            ///
+            /// Browsers do not send preflights for WebSocket handshakes, so servers must
+            /// validate `Origin` themselves. Only the origin policy runs; method, header,
+            /// and credential checks are skipped. Requests the policy does not apply to,
+            /// such as those without an `Origin` header, are allowed with no
+            /// `allow_origin`.
+            pub fn check_websocket(&self, request: &RequestContext<'_>) -> WebSocketDecision {
+                self.snapshot().check_websocket(request)
+            }
+
+            /// Returns the `Access-Control-Allow-Headers` value a preflight carrying
+            /// `requested` as its `Access-Control-Request-Headers` line would receive.
+            ///
+            /// Only the header policy runs, so origin and method checks are skipped.
+            /// Returns `None` when the requested headers would be rejected or when no
+            /// `Access-Control-Allow-Headers` header would be emitted.
+            pub fn preview_allowed_headers(&self, requested: &str) -> Option<String> {
+                self.snapshot().preview_allowed_headers(requested)
+            }
+
+            /// Runs the origin policy for every origin in `allowed` and `blocked` and
+            /// reports those whose verdict differs from the expectation.
+            ///
+            /// Each origin is evaluated as a `GET` request through
+            /// [`Cors::resolve_origin`], except that [`CorsOptions::learning_mode`] is
+            /// ignored: its callback is not invoked and disallowed origins are reported
+            /// as blocked. An origin counts as allowed when the policy
+            /// returns [`OriginDecision::Any`], [`OriginDecision::Exact`], or
+            /// [`OriginDecision::Mirror`]; [`OriginDecision::Skip`] counts as blocked
+            /// because no CORS headers would be emitted.
+            pub fn audit(&self, allowed: &[&str], blocked: &[&str]) -> AuditReport {
+                self.snapshot().audit(allowed, blocked)
+            }
+
+            /// Returns the CORS-relevant parts of `request` that a shared cache must
+            /// include in its key: the header names the response varies on, followed by
+            /// the normalized (trimmed, lowercased) origin when one was sent.
+            ///
+            /// The names are read from the `Vary` header [`Cors::check`] would emit, so
+            /// they follow [`CorsOptions::emit_vary`] and the other options, except that
+            /// [`CorsOptions::learning_mode`] is neither applied nor notified. If the
+            /// evaluation fails, `Origin` is reported so the key stays conservative.
+            ///
+            /// The parts are owned strings: the names come from an evaluated response
+            /// and the origin may need lowercasing, so each call allocates.
+            pub fn cache_key_parts(&self, request: &RequestContext<'_>) -> Vec<String> {
+                self.snapshot().cache_key_parts(request)
+            }
+
+            /// Returns `true` when some response could carry
+            /// `Access-Control-Allow-Credentials`, that is when
+            /// [`CorsOptions::credentials`] is enabled or
+            /// [`CorsOptions::credentialed_origins`] is configured.
+            ///
+            /// This inspects the active configuration only and evaluates no request.
+            pub fn may_emit_credentials(&self) -> bool {
+                self.snapshot().may_emit_credentials()
+            }
+
+            /// Describes the active policy as a JSON object for API documentation
+            /// generators, with the keys `origins`, `methods`, `allowed_headers`,
+            /// `exposed_headers`, `credentials`, and `max_age`.
+            ///
+            /// Wildcards render as `"*"` and lists as arrays. Origins decided by a
+            /// callback, and list entries that are not literal origins such as
+            /// patterns, render as `"dynamic"`. `max_age` is `null` when unset.
+            ///
+            /// Available with the `serde` feature.
+            #[cfg(feature = "serde")]
+            pub fn to_json_description(&self) -> Value {
+                self.snapshot().to_json_description()
+            }
+        }
+    };
+}
+
+evaluation_api!(Cors);
+evaluation_api!(CompiledCors);
+
+impl CorsState {
+    fn check(&self, request: &RequestContext<'_>) -> Result<CorsDecision, CorsError> {
+        self.evaluate(request, true)
+    }
+
+    /// Runs [`CorsState::check`], consulting [`CorsOptions::learning_mode`]
+    /// only when `learn` is set.
+    fn evaluate(
+        &self,
+        request: &RequestContext<'_>,
+        learn: bool,
+    ) -> Result<CorsDecision, CorsError> {
+        if let Some(decision) = self.strict_preflight_decision(request) {
+            return Ok(decision);
+        }
+        let request = &self.with_default_origin(request);
+        if self.is_same_origin(request) {
+            return Ok(CorsDecision::NotApplicable);
+        }
+        let rewritten = request
+            .access_control_request_headers
+            .and_then(|requested| self.options.rewrite_requested_headers(requested));
+        let request = &match rewritten.as_deref() {
+            Some(requested) => RequestContext {
+                access_control_request_headers: Some(requested),
+                ..request.clone()
+            },
+            None => request.clone(),
+        };
+        self.dispatch(&NormalizedRequest::new(request), learn)
+    }
+
+    fn check_with_sink(
+        &self,
+        request: &RequestContext<'_>,
+        sink: &mut impl HeaderSink,
+    ) -> Result<CorsDecision, CorsError> {
+        let mut decision = self.check(request)?;
+        decision.drain_headers_into(sink, self.options.deterministic_header_order);
+        Ok(decision)
+    }
+
+    fn check_normalized(
+        &self,
+        normalized: &NormalizedRequest<'_>,
+    ) -> Result<CorsDecision, CorsError> {
+        let request = normalized.request();
+        if let Some(decision) = self.strict_preflight_decision(request) {
+            return Ok(decision);
+        }
+        if (request.origin.is_none() && self.options.default_origin_when_missing.is_some())
+            || (request.access_control_request_headers.is_some()
+                && self.options.request_header_rewriter.is_some())
+        {
+            // The caller's normalization predates the substituted origin or the
+            // rewritten request headers.
+            return self.check(request);
+        }
+        if self.is_same_origin(request) {
+            return Ok(CorsDecision::NotApplicable);
+        }
+        self.dispatch(normalized, true)
+    }
+
+    fn dispatch(
+        &self,
+        normalized_request: &NormalizedRequest<'_>,
+        learn: bool,
+    ) -> Result<CorsDecision, CorsError> {
+        let request = normalized_request.request();
         let normalized_ctx = normalized_request.as_context();
 
         if normalized_request.is_options() {
-            self.process_preflight(request, &normalized_ctx)
+            self.process_preflight(request, &normalized_ctx, learn)
         } else {
-            self.process_simple(request, &normalized_ctx)
+            self.process_simple(request, &normalized_ctx, learn)
         }
     }
 
+    fn resolve_origin(&self, request: &RequestContext<'_>) -> OriginDecision {
+        self.resolve_origin_with(request, true)
+    }
+
+    fn resolve_origin_with(&self, request: &RequestContext<'_>, learn: bool) -> OriginDecision {
+        let request = &self.with_default_origin(request);
+        if self.is_same_origin(request) {
+            return OriginDecision::Skip;
+        }
+        let normalized_request = NormalizedRequest::new(request);
+        let normalized_ctx = normalized_request.as_context();
+
+        self.header_builder(request, &normalized_ctx, learn)
+            .resolve_origin(request, &normalized_ctx)
+    }
+
+    /// Returns a builder bound to [`CorsOptions::credentialed_origins`] when that
+    /// policy accepts the request, otherwise one bound to [`CorsOptions::origin`].
+    /// Without `learn` the builder ignores [`CorsOptions::learning_mode`].
+    fn header_builder(
+        &self,
+        original: &RequestContext<'_>,
+        normalized: &RequestContext<'_>,
+        learn: bool,
+    ) -> HeaderBuilder<'_> {
+        let mut builder =
+            HeaderBuilder::new(&self.options).with_precomputed(self.precomputed.as_ref());
+        if !learn {
+            builder = builder.without_learning();
+        }
+        if self.options.credentialed_origins.is_some() {
+            let credentialed = builder.for_credentialed_origins();
+            if matches!(
+                credentialed.resolve_origin(original, normalized),
+                OriginDecision::Exact(_) | OriginDecision::Mirror
+            ) {
+                return credentialed;
+            }
+        }
+        builder
+    }
+
+    /// Converts collected headers into the response map, dropping `Vary` when
+    /// [`CorsOptions::emit_vary`] is disabled and applying
+    /// [`CorsOptions::max_vary_values`].
+    fn finish_headers(&self, mut headers: HeaderCollection) -> Headers {
+        if !self.options.emit_vary {
+            headers.clear_vary();
+        } else if let Some(max) = self.options.max_vary_values {
+            headers.cap_vary(max);
+        }
+        headers.into_headers()
+    }
+
+    /// Decides an `OPTIONS` request without a preflight method up front when
+    /// [`CorsOptions::strict_preflight`] is enabled: one carrying `Origin` is a
+    /// malformed preflight, any other is a plain `OPTIONS`.
+    fn strict_preflight_decision(&self, request: &RequestContext<'_>) -> Option<CorsDecision> {
+        if !self.options.strict_preflight
+            || !request.method.trim().eq_ignore_ascii_case("OPTIONS")
+            || request.is_preflight()
+        {
+            return None;
+        }
+        if request
+            .origin
+            .is_some_and(|origin| !origin.trim().is_empty())
+        {
+            return Some(CorsDecision::PreflightRejected(PreflightRejection {
+                status: self.options.rejection_status,
+                headers: Headers::new(),
+                reason: PreflightRejectionReason::RequestMethodMissing,
+            }));
+        }
+        Some(CorsDecision::NotApplicable)
+    }
+
+    /// Substitutes [`CorsOptions::default_origin_when_missing`] for an absent
+    /// `Origin` header.
+    fn with_default_origin<'r>(&'r self, request: &RequestContext<'r>) -> RequestContext<'r> {
+        let mut request = request.clone();
+        if request.origin.is_none() {
+            request.origin = self.options.default_origin_when_missing.as_deref();
+        }
+        request
+    }
+
+    fn check_websocket(&self, request: &RequestContext<'_>) -> WebSocketDecision {
+        let request = &self.with_default_origin(request);
+        let allow_origin = match self.resolve_origin(request) {
+            OriginDecision::Disallow => {
+                return WebSocketDecision {
+                    allowed: false,
+                    allow_origin: None,
+                };
+            }
+            OriginDecision::Skip => None,
+            OriginDecision::Any => Some("*".to_string()),
+            OriginDecision::Exact(value) => Some(value),
+            OriginDecision::Mirror => request.origin.map(|origin| {
+                let origin = origin.trim();
+                if self.options.normalize_trailing_slash {
+                    strip_trailing_slash(origin).to_string()
+                } else {
+                    origin.to_string()
+                }
+            }),
+        };
+
+        WebSocketDecision {
+            allowed: true,
+            allow_origin,
+        }
+    }
+
+    fn cache_key_parts(&self, request: &RequestContext<'_>) -> Vec<String> {
+        let mut parts: Vec<String> = match self.evaluate(request, false) {
+            Ok(decision) => decision
+                .headers()
+                .and_then(|headers| headers.get(header::VARY))
+                .map(|vary| {
+                    vary.split(',')
+                        .map(str::trim)
+                        .filter(|name| !name.is_empty())
+                        .map(str::to_string)
+                        .collect()
+                })
+                .unwrap_or_default(),
+            Err(_) => vec![header::ORIGIN.to_string()],
+        };
+        let normalized = NormalizedRequest::new(request);
+        if let Some(origin) = normalized.as_context().origin {
+            parts.push(origin.to_string());
+        }
+        parts
+    }
+
+    fn may_emit_credentials(&self) -> bool {
+        self.options.credentials || self.options.credentialed_origins.is_some()
+    }
+
+    #[cfg(feature = "serde")]
+    fn to_json_description(&self) -> Value {
+        const DYNAMIC: &str = "dynamic";
+
+        let options = &self.options;
+        let origins = match &options.origin {
+            Origin::Any => json!("*"),
+            Origin::Exact(value) => json!([value]),
+            Origin::List(list) => list
+                .iter()
+                .filter_map(|matcher| match matcher {
+                    OriginMatcher::Exact(value) | OriginMatcher::ExactWwwInsensitive(value) => {
+                        Some(json!(value))
+                    }
+                    #[cfg(feature = "idna")]
+                    OriginMatcher::ExactIdn(value) => Some(json!(value)),
+                    OriginMatcher::Null => Some(json!("null")),
+                    OriginMatcher::Bool(true) => Some(json!("*")),
+                    OriginMatcher::Bool(false) => None,
+                    _ => Some(json!(DYNAMIC)),
+                })
+                .collect(),
+            Origin::Predicate(_) | Origin::PredicateValue(_) | Origin::Custom(_) => {
+                json!(DYNAMIC)
+            }
+        };
+        let allowed_headers = match &options.allowed_headers {
+            AllowedHeaders::Any => json!("*"),
+            AllowedHeaders::List(list) | AllowedHeaders::ListEchoCase(list) => {
+                json!(list.values())
+            }
+            AllowedHeaders::MirrorRequest | AllowedHeaders::EchoRequest => json!(DYNAMIC),
+        };
+        let exposed_headers = match &options.exposed_headers {
+            ExposedHeaders::Any => json!("*"),
+            ExposedHeaders::AnyOr(_) if !options.credentials => json!("*"),
+            exposed => exposed
+                .header_value_for(options.credentials)
+                .map(|value| value.split(',').map(str::to_string).collect())
+                .unwrap_or_else(|| json!([])),
+        };
+
+        json!({
+            "origins": origins,
+            "methods": options.methods.as_slice(),
+            "allowed_headers": allowed_headers,
+            "exposed_headers": exposed_headers,
+            "credentials": options.credentials,
+            "max_age": options.max_age,
+        })
+    }
+
+    fn audit(&self, allowed: &[&str], blocked: &[&str]) -> AuditReport {
+        let is_allowed = |origin: &str| {
+            let request = RequestContext {
+                method: method::GET,
+                origin: Some(origin),
+                access_control_request_method: None,
+                access_control_request_headers: None,
+                access_control_request_private_network: false,
+                sec_fetch_site: None,
+                host: None,
+            };
+            matches!(
+                self.resolve_origin_with(&request, false),
+                OriginDecision::Any | OriginDecision::Exact(_) | OriginDecision::Mirror
+            )
+        };
+
+        AuditReport {
+            unexpectedly_blocked: allowed
+                .iter()
+                .filter(|origin| !is_allowed(origin))
+                .map(|origin| origin.to_string())
+                .collect(),
+            unexpectedly_allowed: blocked
+                .iter()
+                .filter(|origin| is_allowed(origin))
+                .map(|origin| origin.to_string())
+                .collect(),
+        }
+    }
+
+    fn is_same_origin(&self, request: &RequestContext<'_>) -> bool {
+        match (&self.options.self_origin, request.origin) {
+            (Some(self_origin), Some(origin)) => equals_ignore_case(self_origin, origin.trim()),
+            _ => false,
+        }
+    }
+
+    fn preview_allowed_headers(&self, requested: &str) -> Option<String> {
+        let rewritten = self.options.rewrite_requested_headers(requested);
+        let requested = rewritten.as_deref().unwrap_or(requested);
+        let normalized = normalize_lower(requested.trim());
+        if !normalized.is_empty() && !self.options.allowed_headers.allows_headers(&normalized) {
+            return None;
+        }
+        if matches!(
+            self.options.allowed_headers,
+            AllowedHeaders::MirrorRequest | AllowedHeaders::EchoRequest
+        ) && let Some(max) = self.options.max_reflected_headers_len
+            && requested.trim().len() > max
+        {
+            return None;
+        }
+
+        let request = RequestContext {
+            method: method::OPTIONS,
+            origin: None,
+            access_control_request_method: None,
+            access_control_request_headers: Some(requested),
+            access_control_request_private_network: false,
+            sec_fetch_site: None,
+            host: None,
+        };
+        HeaderBuilder::new(&self.options)
+            .with_precomputed(self.precomputed.as_ref())
+            .build_allowed_headers(&request)
+            .into_headers()
+            .remove(header::ACCESS_CONTROL_ALLOW_HEADERS)
+    }
+
     fn process_preflight(
         &self,
         original: &RequestContext<'_>,
         normalized: &RequestContext<'_>,
+        learn: bool,
     ) -> Result<CorsDecision, CorsError> {
         // Steps through the CORS preflight algorithm. We follow the WHATWG
         // reference flow: verify request metadata, emit allow headers, and
@@ -62,70 +627,148 @@ impl Cors {
         else {
             return Ok(CorsDecision::NotApplicable);
         };
-        let builder = HeaderBuilder::new(&self.options);
+        if self.options.require_origin_on_preflight && normalized.origin.is_none() {
+            return Ok(CorsDecision::PreflightRejected(PreflightRejection {
+                status: self.options.rejection_status,
+                headers: Headers::new(),
+                reason: PreflightRejectionReason::OriginMissing,
+            }));
+        }
+        let builder = self.header_builder(original, normalized, learn);
         let (mut headers, decision) = builder.build_origin_headers(original, normalized)?;
 
         match decision {
             OriginDecision::Skip => return Ok(CorsDecision::NotApplicable),
             OriginDecision::Disallow => {
                 return Ok(CorsDecision::PreflightRejected(PreflightRejection {
-                    headers: headers.into_headers(),
+                    status: self.options.rejection_status,
+                    headers: self.finish_headers(headers),
                     reason: PreflightRejectionReason::OriginNotAllowed,
                 }));
             }
             OriginDecision::Any | OriginDecision::Mirror | OriginDecision::Exact(_) => {}
         }
 
-        if !self.options.methods.allows_method(requested_method) {
+        let mut failures = self.preflight_failures(original, normalized, requested_method);
+        if !failures.is_empty() {
+            let reason = if failures.len() == 1 {
+                failures.remove(0)
+            } else {
+                PreflightRejectionReason::Multiple(failures)
+            };
             return Ok(CorsDecision::PreflightRejected(PreflightRejection {
-                headers: headers.into_headers(),
-                reason: PreflightRejectionReason::MethodNotAllowed {
-                    requested_method: requested_method.to_string(),
-                },
+                status: self.options.rejection_status,
+                headers: self.finish_headers(headers),
+                reason,
             }));
         }
-        if let Some(requested_headers) = normalized.access_control_request_headers
-            && !self
-                .options
-                .allowed_headers
-                .allows_headers(requested_headers)
-        {
-            return Ok(CorsDecision::PreflightRejected(PreflightRejection {
-                headers: headers.into_headers(),
-                reason: PreflightRejectionReason::HeadersNotAllowed {
-                    requested_headers: requested_headers.to_string(),
-                },
-            }));
+        headers.extend(builder.build_preflight_credentials_header());
+        if !(self.options.omit_methods_for_simple && is_cors_safelisted_method(requested_method)) {
+            headers.extend(builder.build_methods_header());
         }
-        headers.extend(builder.build_credentials_header());
-        headers.extend(builder.build_methods_header());
-        headers.extend(builder.build_allowed_headers());
+        headers.extend(builder.build_allowed_headers(original));
         headers.extend(builder.build_private_network_header(original));
-        headers.extend(builder.build_max_age_header());
+        if !(self.options.max_age_only_for_exact_origin && matches!(decision, OriginDecision::Any))
+        {
+            headers.extend(builder.build_max_age_header());
+        }
+        headers.extend(builder.build_content_length_header());
+        headers.extend(builder.build_extra_headers());
 
+        let headers = self.finish_headers(headers);
+        let granted = PreflightGrants::from_headers(&headers);
+        let matched_origin = builder.matched_origin(original, normalized, &decision);
         Ok(CorsDecision::PreflightAccepted {
-            headers: headers.into_headers(),
+            headers,
+            empty_body: true,
+            granted,
+            matched_origin,
         })
     }
 
+    /// Runs the method and header checks of an origin-approved preflight.
+    ///
+    /// Stops at the first failure unless [`CorsOptions::aggregate_rejections`]
+    /// is enabled, in which case every failed check is reported.
+    fn preflight_failures(
+        &self,
+        original: &RequestContext<'_>,
+        normalized: &RequestContext<'_>,
+        requested_method: &str,
+    ) -> Vec<PreflightRejectionReason> {
+        let aggregate = self.options.aggregate_rejections;
+        let mut failures = Vec::new();
+
+        if !self.options.is_token(requested_method) {
+            failures.push(PreflightRejectionReason::MalformedRequestMethod {
+                requested_method: requested_method.to_string(),
+            });
+        } else if !self.options.methods.allows_method(requested_method) {
+            failures.push(PreflightRejectionReason::MethodNotAllowed {
+                requested_method: requested_method.to_string(),
+            });
+        }
+        if !failures.is_empty() && !aggregate {
+            return failures;
+        }
+
+        let allowed_headers = self
+            .options
+            .method_allowed_headers(requested_method)
+            .unwrap_or(&self.options.allowed_headers);
+        if let Some(requested_headers) = normalized.access_control_request_headers
+            && !allowed_headers.allows_headers(requested_headers)
+        {
+            failures.push(PreflightRejectionReason::HeadersNotAllowed {
+                requested_headers: requested_headers.to_string(),
+            });
+            if !aggregate {
+                return failures;
+            }
+        }
+        if matches!(
+            allowed_headers,
+            AllowedHeaders::MirrorRequest | AllowedHeaders::EchoRequest
+        ) && let Some(max) = self.options.max_reflected_headers_len
+            && let Some(requested_headers) = original.access_control_request_headers
+        {
+            let length = requested_headers.trim().len();
+            if length > max {
+                failures.push(PreflightRejectionReason::HeadersTooLarge { length, max });
+            }
+        }
+
+        failures
+    }
+
     fn process_simple(
         &self,
         original: &RequestContext<'_>,
         normalized: &RequestContext<'_>,
+        learn: bool,
     ) -> Result<CorsDecision, CorsError> {
         // Handles non-preflight requests. This path intentionally mirrors the
         // same origin resolution logic as `process_preflight`, but limits the
         // emitted headers to those allowed on "simple" requests. Returning
         // [`CorsDecision::NotApplicable`] allows upstream orchestration layers
         // to fall back to default behaviour for requests that never needed CORS.
-        let builder = HeaderBuilder::new(&self.options);
+        let builder = self.header_builder(original, normalized, learn);
         let (mut headers, decision) = builder.build_origin_headers(original, normalized)?;
 
         match decision {
-            OriginDecision::Skip => return Ok(CorsDecision::NotApplicable),
+            OriginDecision::Skip => {
+                if normalized.origin.is_none_or(str::is_empty)
+                    && matches!(builder.origin_policy(), Origin::Any)
+                    && self.options.methods.allows_method(normalized.method)
+                {
+                    return Ok(CorsDecision::AllowedNoHeaders);
+                }
+                return Ok(CorsDecision::NotApplicable);
+            }
             OriginDecision::Disallow => {
                 return Ok(CorsDecision::SimpleRejected(SimpleRejection {
-                    headers: headers.into_headers(),
+                    status: self.options.rejection_status,
+                    headers: self.finish_headers(headers),
                     reason: SimpleRejectionReason::OriginNotAllowed,
                 }));
             }
@@ -133,15 +776,28 @@ impl Cors {
         }
 
         if !self.options.methods.allows_method(normalized.method) {
+            if self.options.reject_disallowed_simple_method {
+                return Ok(CorsDecision::SimpleRejected(SimpleRejection {
+                    status: self.options.rejection_status,
+                    headers: self.finish_headers(headers),
+                    reason: SimpleRejectionReason::MethodNotAllowed {
+                        requested_method: normalized.method.to_string(),
+                    },
+                }));
+            }
             return Ok(CorsDecision::NotApplicable);
         }
-        headers.extend(builder.build_credentials_header());
+        headers.extend(builder.build_simple_credentials_header());
         headers.extend(builder.build_private_network_header(original));
         headers.extend(builder.build_exposed_headers());
         headers.extend(builder.build_timing_allow_origin_header());
+        headers.extend(builder.build_extra_headers());
 
+        let headers = self.finish_headers(headers);
+        let matched_origin = builder.matched_origin(original, normalized, &decision);
         Ok(CorsDecision::SimpleAccepted {
-            headers: headers.into_headers(),
+            headers,
+            matched_origin,
         })
     }
 }
@@ -10,7 +10,7 @@ use crate::options::{CorsOptions, ValidationError};
 use crate::origin::{Origin, OriginDecision};
 use crate::result::{
     CorsDecision, CorsError, PreflightRejection, PreflightRejectionReason, SimpleRejection,
-    SimpleRejectionReason,
+    SimpleRejectionReason, WebSocketDecision,
 };
 use crate::timing_allow_origin::TimingAllowOrigin;
 
@@ -27,6 +27,8 @@ fn build_request(
         access_control_request_method: acrm,
         access_control_request_headers: acrh,
         access_control_request_private_network: private_network,
+        sec_fetch_site: None,
+        host: None,
     }
 }
 
@@ -54,7 +56,9 @@ fn preflight_decision(
 ) -> Result<CorsDecision, CorsError> {
     let normalized_request = NormalizedRequest::new(request);
     let normalized = normalized_request.as_context();
-    cors.process_preflight(request, &normalized)
+    cors.state
+        .load()
+        .process_preflight(request, &normalized, true)
 }
 
 fn simple_decision(
@@ -63,7 +67,7 @@ fn simple_decision(
 ) -> Result<CorsDecision, CorsError> {
     let normalized_request = NormalizedRequest::new(request);
     let normalized = normalized_request.as_context();
-    cors.process_simple(request, &normalized)
+    cors.state.load().process_simple(request, &normalized, true)
 }
 
 fn cors_with(options: CorsOptions) -> Cors {
@@ -77,7 +81,7 @@ fn cors_with(options: CorsOptions) -> Cors {
 
 fn expect_preflight_accepted(result: Result<CorsDecision, CorsError>) -> Headers {
     match result.expect("preflight evaluation should succeed") {
-        CorsDecision::PreflightAccepted { headers } => headers,
+        CorsDecision::PreflightAccepted { headers, .. } => headers,
         other => panic!("expected preflight acceptance, got {:?}", other),
     }
 }
@@ -91,7 +95,7 @@ fn expect_preflight_rejected(result: Result<CorsDecision, CorsError>) -> Preflig
 
 fn expect_simple_accepted(result: Result<CorsDecision, CorsError>) -> Headers {
     match result.expect("simple evaluation should succeed") {
-        CorsDecision::SimpleAccepted { headers } => headers,
+        CorsDecision::SimpleAccepted { headers, .. } => headers,
         other => panic!("expected simple acceptance, got {:?}", other),
     }
 }
@@ -137,6 +141,47 @@ mod new {
     }
 }
 
+mod new_with_reporter {
+    use super::*;
+    use std::sync::{Arc, Mutex};
+
+    fn recording_reporter() -> (Arc<Mutex<Vec<ValidationError>>>, Arc<ValidationReporterFn>) {
+        let reported = Arc::new(Mutex::new(Vec::new()));
+        let sink = Arc::clone(&reported);
+        let reporter: Arc<ValidationReporterFn> = Arc::new(move |error: &ValidationError| {
+            sink.lock().expect("reporter lock").push(error.clone());
+        });
+        (reported, reporter)
+    }
+
+    #[test]
+    fn should_report_exact_error_when_options_invalid_then_return_same_error() {
+        let (reported, reporter) = recording_reporter();
+        let options = CorsOptions::new().origin(Origin::any()).credentials(true);
+
+        let result = Cors::new_with_reporter(options, reporter);
+
+        assert!(matches!(
+            result,
+            Err(ValidationError::CredentialsRequireSpecificOrigin)
+        ));
+        assert_eq!(
+            *reported.lock().expect("reporter lock"),
+            vec![ValidationError::CredentialsRequireSpecificOrigin]
+        );
+    }
+
+    #[test]
+    fn should_not_report_when_options_valid_then_build_instance() {
+        let (reported, reporter) = recording_reporter();
+
+        let result = Cors::new_with_reporter(CorsOptions::new(), reporter);
+
+        assert!(result.is_ok());
+        assert!(reported.lock().expect("reporter lock").is_empty());
+    }
+}
+
 mod check {
     use super::*;
 
@@ -266,6 +311,34 @@ mod process_preflight {
         );
     }
 
+    #[test]
+    fn should_return_malformed_request_method_when_method_contains_space_then_reject_before_allow_check()
+     {
+        let cors =
+            Cors::new(CorsOptions::new().origin(Origin::any())).expect("valid CORS configuration");
+        let request = request("OPTIONS", Some("https://allowed.test"), Some("GE T"), None);
+
+        let rejection = expect_preflight_rejected(preflight_decision(&cors, &request));
+
+        assert_eq!(
+            rejection.reason,
+            PreflightRejectionReason::MalformedRequestMethod {
+                requested_method: "ge t".to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn should_accept_preflight_when_request_method_is_valid_token_then_skip_malformed_rejection() {
+        let cors =
+            Cors::new(CorsOptions::new().origin(Origin::any())).expect("valid CORS configuration");
+        let request = request("OPTIONS", Some("https://allowed.test"), Some("GET"), None);
+
+        let headers = expect_preflight_accepted(preflight_decision(&cors, &request));
+
+        assert!(headers.contains_key(header::ACCESS_CONTROL_ALLOW_METHODS));
+    }
+
     #[test]
     fn should_return_headers_not_allowed_when_request_headers_disallowed_then_report_headers() {
         let cors = Cors::new(
@@ -330,9 +403,150 @@ mod process_preflight {
             Some(&"true".to_string())
         );
     }
+
+    #[test]
+    fn should_reflect_requested_headers_when_mirror_within_limit_then_accept_preflight() {
+        let cors = Cors::new(
+            CorsOptions::new()
+                .origin(Origin::list(["https://allowed.test"]))
+                .allowed_headers(AllowedHeaders::MirrorRequest)
+                .max_reflected_headers_len(32),
+        )
+        .expect("valid CORS configuration");
+        let request = request(
+            "OPTIONS",
+            Some("https://allowed.test"),
+            Some("GET"),
+            Some("X-Trace, X-Auth"),
+        );
+
+        let headers = expect_preflight_accepted(preflight_decision(&cors, &request));
+
+        assert_eq!(
+            headers.get(header::ACCESS_CONTROL_ALLOW_HEADERS),
+            Some(&"X-Trace, X-Auth".to_string())
+        );
+    }
+
+    #[test]
+    fn should_reject_preflight_when_mirrored_headers_exceed_limit_then_return_headers_too_large() {
+        let cors = Cors::new(
+            CorsOptions::new()
+                .origin(Origin::list(["https://allowed.test"]))
+                .allowed_headers(AllowedHeaders::MirrorRequest)
+                .max_reflected_headers_len(8),
+        )
+        .expect("valid CORS configuration");
+        let request = request(
+            "OPTIONS",
+            Some("https://allowed.test"),
+            Some("GET"),
+            Some("X-Trace, X-Auth"),
+        );
+
+        let rejection = expect_preflight_rejected(preflight_decision(&cors, &request));
+
+        assert_eq!(
+            rejection.reason,
+            PreflightRejectionReason::HeadersTooLarge { length: 15, max: 8 }
+        );
+        assert!(
+            !rejection
+                .headers
+                .contains_key(header::ACCESS_CONTROL_ALLOW_HEADERS)
+        );
+    }
+
+    #[test]
+    fn should_reflect_any_length_when_mirror_without_limit_then_accept_preflight() {
+        let cors = Cors::new(
+            CorsOptions::new()
+                .origin(Origin::list(["https://allowed.test"]))
+                .allowed_headers(AllowedHeaders::MirrorRequest),
+        )
+        .expect("valid CORS configuration");
+        let request = request(
+            "OPTIONS",
+            Some("https://allowed.test"),
+            Some("GET"),
+            Some("X-Trace, X-Auth, X-Very-Long-Custom-Header-Name"),
+        );
+
+        let headers = expect_preflight_accepted(preflight_decision(&cors, &request));
+
+        assert_eq!(
+            headers.get(header::ACCESS_CONTROL_ALLOW_HEADERS),
+            Some(&"X-Trace, X-Auth, X-Very-Long-Custom-Header-Name".to_string())
+        );
+    }
+
+    #[test]
+    fn should_omit_methods_header_when_safelisted_method_requested_then_skip_allow_methods() {
+        let cors = Cors::new(
+            CorsOptions::new()
+                .methods(AllowedMethods::list(["GET", "PUT"]))
+                .omit_methods_for_simple(true),
+        )
+        .expect("valid CORS configuration");
+        let request = request("OPTIONS", Some("https://allowed.test"), Some("GET"), None);
+
+        let headers = expect_preflight_accepted(preflight_decision(&cors, &request));
+
+        assert!(!headers.contains_key(header::ACCESS_CONTROL_ALLOW_METHODS));
+    }
+
+    #[test]
+    fn should_emit_methods_header_when_non_safelisted_method_requested_then_include_allow_methods()
+    {
+        let cors = Cors::new(
+            CorsOptions::new()
+                .methods(AllowedMethods::list(["GET", "PUT"]))
+                .omit_methods_for_simple(true),
+        )
+        .expect("valid CORS configuration");
+        let request = request("OPTIONS", Some("https://allowed.test"), Some("PUT"), None);
+
+        let headers = expect_preflight_accepted(preflight_decision(&cors, &request));
+
+        assert_eq!(
+            headers.get(header::ACCESS_CONTROL_ALLOW_METHODS),
+            Some(&"GET,PUT".to_string())
+        );
+    }
+
+    #[test]
+    fn should_emit_content_length_when_preflight_content_length_enabled_then_include_zero() {
+        let cors = Cors::new(CorsOptions::new().set_preflight_content_length(true))
+            .expect("valid CORS configuration");
+        let request = request("OPTIONS", Some("https://allowed.test"), Some("GET"), None);
+
+        let decision = preflight_decision(&cors, &request);
+
+        assert!(matches!(
+            decision,
+            Ok(CorsDecision::PreflightAccepted {
+                empty_body: true,
+                ..
+            })
+        ));
+        let headers = expect_preflight_accepted(decision);
+        assert_eq!(headers.get(header::CONTENT_LENGTH), Some(&"0".to_string()));
+    }
+
+    #[test]
+    fn should_omit_content_length_when_preflight_content_length_disabled_then_leave_headers_unchanged()
+     {
+        let cors = Cors::new(CorsOptions::new()).expect("valid CORS configuration");
+        let request = request("OPTIONS", Some("https://allowed.test"), Some("GET"), None);
+
+        let headers = expect_preflight_accepted(preflight_decision(&cors, &request));
+
+        assert!(!headers.contains_key(header::CONTENT_LENGTH));
+    }
 }
 
 mod process_simple {
+
     use super::*;
 
     #[test]
@@ -420,3 +634,628 @@ mod process_simple {
         );
     }
 }
+
+mod compile {
+    use super::*;
+
+    #[test]
+    fn should_match_uncompiled_headers_when_compiled_then_reuse_precomputed_values() {
+        let options = CorsOptions::new()
+            .origin(Origin::list(["https://allowed.test"]))
+            .methods(AllowedMethods::list(["GET", "PUT"]))
+            .allowed_headers(AllowedHeaders::list(["X-Test"]))
+            .exposed_headers(ExposedHeaders::list(["X-Trace"]))
+            .timing_allow_origin(TimingAllowOrigin::list(["https://allowed.test"]))
+            .max_age(120);
+        let cors = Cors::new(options.clone()).expect("valid CORS configuration");
+        let compiled = Cors::new(options)
+            .expect("valid CORS configuration")
+            .compile();
+        let preflight = request(
+            "OPTIONS",
+            Some("https://allowed.test"),
+            Some("PUT"),
+            Some("X-Test"),
+        );
+        let simple = request("GET", Some("https://allowed.test"), None, None);
+
+        let expected_preflight = expect_preflight_accepted(cors.check(&preflight));
+        let actual_preflight = expect_preflight_accepted(compiled.check(&preflight));
+        let expected_simple = expect_simple_accepted(cors.check(&simple));
+        let actual_simple = expect_simple_accepted(compiled.check(&simple));
+
+        assert_eq!(actual_preflight, expected_preflight);
+        assert_eq!(actual_simple, expected_simple);
+    }
+}
+
+mod resolve_origin {
+    use super::*;
+
+    fn resolve(origin: Origin, request: &RequestContext<'static>) -> OriginDecision {
+        Cors::new(CorsOptions::new().origin(origin))
+            .expect("valid CORS configuration")
+            .resolve_origin(request)
+    }
+
+    #[test]
+    fn should_return_any_when_origin_any_then_match_wildcard_check() {
+        let request = request("GET", Some("https://any.test"), None, None);
+        let cors = Cors::new(CorsOptions::new()).expect("valid CORS configuration");
+
+        let decision = cors.resolve_origin(&request);
+        let headers = expect_simple_accepted(cors.check(&request));
+
+        assert!(matches!(decision, OriginDecision::Any));
+        assert_eq!(
+            headers.get(header::ACCESS_CONTROL_ALLOW_ORIGIN),
+            Some(&"*".to_string())
+        );
+    }
+
+    #[test]
+    fn should_return_exact_when_origin_matches_exact_then_carry_configured_value() {
+        let request = request("GET", Some("https://EXACT.test"), None, None);
+
+        let decision = resolve(Origin::exact("https://exact.test"), &request);
+
+        assert!(matches!(decision, OriginDecision::Exact(value) if value == "https://exact.test"));
+    }
+
+    #[test]
+    fn should_return_mirror_when_list_matches_then_align_with_accepted_check() {
+        let request = request("GET", Some("https://allowed.test"), None, None);
+        let cors = Cors::new(CorsOptions::new().origin(Origin::list(["https://allowed.test"])))
+            .expect("valid CORS configuration");
+
+        let decision = cors.resolve_origin(&request);
+
+        assert!(matches!(decision, OriginDecision::Mirror));
+        expect_simple_accepted(cors.check(&request));
+    }
+
+    #[test]
+    fn should_return_disallow_when_list_misses_then_align_with_rejected_check() {
+        let request = request("GET", Some("https://blocked.test"), None, None);
+        let cors = Cors::new(CorsOptions::new().origin(Origin::list(["https://allowed.test"])))
+            .expect("valid CORS configuration");
+
+        let decision = cors.resolve_origin(&request);
+
+        assert!(matches!(decision, OriginDecision::Disallow));
+        expect_simple_rejected(cors.check(&request));
+    }
+
+    #[test]
+    fn should_return_skip_when_origin_missing_then_align_with_allowed_no_headers_check() {
+        let request = request("GET", None, None, None);
+        let cors = Cors::new(CorsOptions::new()).expect("valid CORS configuration");
+
+        let decision = cors.resolve_origin(&request);
+
+        assert!(matches!(decision, OriginDecision::Skip));
+        assert!(matches!(
+            cors.check(&request),
+            Ok(CorsDecision::AllowedNoHeaders)
+        ));
+    }
+
+    #[test]
+    fn should_return_disallow_when_null_origin_not_allowed_then_apply_null_policy() {
+        let request = request("GET", Some("null"), None, None);
+
+        let decision = resolve(Origin::any(), &request);
+
+        assert!(matches!(decision, OriginDecision::Disallow));
+    }
+
+    #[test]
+    fn should_return_disallow_when_origin_exceeds_length_limit_then_apply_limit() {
+        let long_origin: &'static str =
+            Box::leak(format!("https://{}.test", "a".repeat(5_000)).into_boxed_str());
+        let request = request("GET", Some(long_origin), None, None);
+
+        let decision = resolve(Origin::any(), &request);
+
+        assert!(matches!(decision, OriginDecision::Disallow));
+    }
+}
+
+mod reload {
+    use super::*;
+
+    #[test]
+    fn should_apply_new_options_when_reload_valid_then_evaluate_with_new_policy() {
+        let cors = Cors::new(CorsOptions::new().origin(Origin::exact("https://old.test")))
+            .expect("valid CORS configuration");
+        let request = request("GET", Some("https://new.test"), None, None);
+        expect_simple_rejected(cors.check(&request));
+
+        cors.reload(CorsOptions::new().origin(Origin::exact("https://new.test")))
+            .expect("reload succeeds");
+
+        let headers = expect_simple_accepted(cors.check(&request));
+        assert_eq!(
+            headers.get(header::ACCESS_CONTROL_ALLOW_ORIGIN),
+            Some(&"https://new.test".to_string())
+        );
+    }
+
+    #[test]
+    fn should_keep_previous_options_when_reload_invalid_then_return_validation_error() {
+        let cors = Cors::new(CorsOptions::new().origin(Origin::exact("https://old.test")))
+            .expect("valid CORS configuration");
+
+        let result = cors.reload(CorsOptions::new().credentials(true));
+
+        assert_eq!(
+            result,
+            Err(ValidationError::CredentialsRequireSpecificOrigin)
+        );
+        let request = request("GET", Some("https://old.test"), None, None);
+        expect_simple_accepted(cors.check(&request));
+    }
+}
+
+mod preview_allowed_headers {
+    use super::*;
+
+    #[test]
+    fn should_return_configured_list_when_requested_headers_allowed_then_preview_value() {
+        let cors = Cors::new(
+            CorsOptions::new().allowed_headers(AllowedHeaders::list(["X-Trace", "X-Auth"])),
+        )
+        .expect("valid CORS configuration");
+
+        assert_eq!(
+            cors.preview_allowed_headers("x-trace"),
+            Some("X-Trace,X-Auth".to_string())
+        );
+        assert_eq!(cors.preview_allowed_headers("X-Other"), None);
+    }
+
+    #[test]
+    fn should_return_wildcard_when_any_headers_allowed_then_preview_star() {
+        let cors = Cors::new(CorsOptions::new().allowed_headers(AllowedHeaders::Any))
+            .expect("valid CORS configuration");
+
+        assert_eq!(
+            cors.preview_allowed_headers("X-Anything"),
+            Some("*".to_string())
+        );
+    }
+
+    #[test]
+    fn should_echo_request_when_mirror_request_configured_then_respect_length_cap() {
+        let cors = Cors::new(
+            CorsOptions::new()
+                .allowed_headers(AllowedHeaders::MirrorRequest)
+                .max_reflected_headers_len(16),
+        )
+        .expect("valid CORS configuration");
+
+        assert_eq!(
+            cors.preview_allowed_headers("  X-Trace, X-Auth "),
+            Some("X-Trace, X-Auth".to_string())
+        );
+        assert_eq!(
+            cors.preview_allowed_headers("X-Very-Long-Custom-Header"),
+            None
+        );
+        assert_eq!(cors.preview_allowed_headers(""), None);
+    }
+
+    #[test]
+    fn should_match_compiled_preview_when_cors_compiled_then_return_same_value() {
+        let options = CorsOptions::new().allowed_headers(AllowedHeaders::list(["X-Trace"]));
+        let cors = Cors::new(options.clone()).expect("valid CORS configuration");
+        let compiled = Cors::new(options)
+            .expect("valid CORS configuration")
+            .compile();
+
+        assert_eq!(
+            compiled.preview_allowed_headers("X-Trace"),
+            cors.preview_allowed_headers("X-Trace")
+        );
+    }
+}
+
+mod check_websocket {
+    use super::*;
+
+    fn websocket_cors() -> Cors {
+        Cors::new(
+            CorsOptions::new()
+                .origin(Origin::list(["https://chat.test"]))
+                .methods(AllowedMethods::list(["POST"])),
+        )
+        .expect("valid CORS configuration")
+    }
+
+    #[test]
+    fn should_allow_upgrade_when_origin_allowed_then_report_request_origin() {
+        let cors = websocket_cors();
+        let request = request("GET", Some("https://chat.test"), None, None);
+
+        let decision = cors.check_websocket(&request);
+
+        assert_eq!(
+            decision,
+            WebSocketDecision {
+                allowed: true,
+                allow_origin: Some("https://chat.test".to_string()),
+            }
+        );
+    }
+
+    #[test]
+    fn should_reject_upgrade_when_origin_disallowed_then_omit_allow_origin() {
+        let cors = websocket_cors();
+        let request = request("GET", Some("https://evil.test"), None, None);
+
+        let decision = cors.check_websocket(&request);
+
+        assert_eq!(
+            decision,
+            WebSocketDecision {
+                allowed: false,
+                allow_origin: None,
+            }
+        );
+    }
+
+    #[test]
+    fn should_allow_upgrade_when_origin_missing_then_skip_policy() {
+        let cors = websocket_cors();
+        let request = request("GET", None, None, None);
+
+        let decision = cors.check_websocket(&request);
+
+        assert!(decision.allowed);
+        assert_eq!(decision.allow_origin, None);
+    }
+}
+
+mod check_normalized {
+    use super::*;
+
+    fn decision_parts(decision: CorsDecision) -> (crate::DecisionKind, Headers) {
+        let kind = decision.kind();
+        let headers = match decision {
+            CorsDecision::PreflightAccepted { headers, .. }
+            | CorsDecision::SimpleAccepted { headers, .. } => headers,
+            CorsDecision::PreflightRejected(rejection) => rejection.headers,
+            CorsDecision::SimpleRejected(rejection) => rejection.headers,
+            CorsDecision::AllowedNoHeaders | CorsDecision::NotApplicable => Headers::new(),
+        };
+        (kind, headers)
+    }
+
+    #[test]
+    fn should_match_check_when_normalization_shared_across_instances_then_return_same_decisions() {
+        let public = Cors::new(
+            CorsOptions::new()
+                .origin(Origin::list(["https://app.test"]))
+                .allowed_headers(AllowedHeaders::list(["X-Trace"])),
+        )
+        .expect("valid configuration");
+        let admin = Cors::new(
+            CorsOptions::new()
+                .origin(Origin::list(["https://admin.test"]))
+                .credentials(true),
+        )
+        .expect("valid configuration");
+        let requests = [
+            request(
+                "OPTIONS",
+                Some("HTTPS://APP.TEST"),
+                Some("POST"),
+                Some("X-Trace"),
+            ),
+            request("GET", Some("https://Admin.test"), None, None),
+            request("GET", None, None, None),
+        ];
+
+        for ctx in &requests {
+            let normalized = NormalizedRequest::new(ctx);
+            for cors in [&public, &admin] {
+                let shared = cors
+                    .check_normalized(&normalized)
+                    .expect("evaluation should succeed");
+                let direct = cors.check(ctx).expect("evaluation should succeed");
+
+                assert_eq!(decision_parts(shared), decision_parts(direct));
+            }
+        }
+    }
+
+    #[test]
+    fn should_match_check_when_buffers_reused_across_loop_requests_then_return_same_decisions() {
+        let cors = Cors::new(CorsOptions::new().origin(Origin::list(["https://app.test"])))
+            .expect("valid configuration");
+        let mut buffers = crate::NormalizationBuffers::new();
+
+        for origin in ["HTTPS://APP.TEST", "https://other.test", "https://App.test"] {
+            let origin = origin.to_string();
+            let ctx = RequestContext {
+                origin: Some(&origin),
+                ..request("GET", None, None, None)
+            };
+
+            let normalized = buffers.normalize(&ctx);
+            let shared = cors
+                .check_normalized(&normalized)
+                .expect("evaluation should succeed");
+            let direct = cors.check(&ctx).expect("evaluation should succeed");
+
+            assert_eq!(decision_parts(shared), decision_parts(direct));
+        }
+    }
+
+    #[test]
+    fn should_renormalize_when_default_origin_substituted_then_apply_default() {
+        let cors = Cors::new(
+            CorsOptions::new()
+                .origin(Origin::list(["https://internal.test"]))
+                .default_origin_when_missing("https://internal.test"),
+        )
+        .expect("valid configuration");
+        let ctx = request("GET", None, None, None);
+        let normalized = NormalizedRequest::new(&ctx);
+
+        let decision = cors
+            .check_normalized(&normalized)
+            .expect("evaluation should succeed");
+
+        assert!(matches!(decision, CorsDecision::SimpleAccepted { .. }));
+    }
+}
+
+mod cache_key_parts {
+    use super::*;
+
+    #[test]
+    fn should_include_origin_name_and_normalized_value_when_exact_policy_then_key_on_origin() {
+        let cors = Cors::new(CorsOptions::new().origin(Origin::exact("https://app.test")))
+            .expect("valid CORS configuration");
+        let request = request("GET", Some(" HTTPS://App.Test "), None, None);
+
+        let parts = cors.cache_key_parts(&request);
+
+        assert_eq!(
+            parts,
+            vec![header::ORIGIN.to_string(), "https://app.test".to_string()]
+        );
+        assert_eq!(cors.compile().cache_key_parts(&request), parts);
+    }
+
+    #[test]
+    fn should_include_request_header_names_when_preflight_mirrors_then_list_every_vary_token() {
+        let cors = Cors::new(
+            CorsOptions::new()
+                .origin(Origin::exact("https://app.test"))
+                .allowed_headers(AllowedHeaders::MirrorRequest),
+        )
+        .expect("valid CORS configuration");
+        let request = request(
+            "OPTIONS",
+            Some("https://app.test"),
+            Some("POST"),
+            Some("X-Test"),
+        );
+
+        let parts = cors.cache_key_parts(&request);
+
+        assert!(parts.contains(&header::ORIGIN.to_string()));
+        assert!(parts.contains(&header::ACCESS_CONTROL_REQUEST_HEADERS.to_string()));
+        assert_eq!(parts.last().map(String::as_str), Some("https://app.test"));
+    }
+
+    #[test]
+    fn should_return_only_vary_names_when_origin_missing_then_omit_origin_value() {
+        let cors = Cors::new(CorsOptions::new()).expect("valid CORS configuration");
+        let request = request("GET", None, None, None);
+
+        assert!(cors.cache_key_parts(&request).is_empty());
+    }
+}
+
+mod may_emit_credentials {
+    use super::*;
+
+    #[test]
+    fn should_return_true_when_credentials_enabled_then_report_credentialed_config() {
+        let cors = Cors::new(
+            CorsOptions::new()
+                .origin(Origin::exact("https://app.test"))
+                .credentials(true),
+        )
+        .expect("valid CORS configuration");
+
+        assert!(cors.may_emit_credentials());
+        assert!(cors.compile().may_emit_credentials());
+    }
+
+    #[test]
+    fn should_return_false_when_plain_config_then_report_no_credentials() {
+        let cors = Cors::new(CorsOptions::new()).expect("valid CORS configuration");
+
+        assert!(!cors.may_emit_credentials());
+        assert!(!cors.compile().may_emit_credentials());
+    }
+
+    #[test]
+    fn should_return_true_when_credentialed_origins_configured_then_report_credentialed_subset() {
+        let cors = Cors::new(
+            CorsOptions::new()
+                .origin(Origin::list(["https://app.test", "https://admin.test"]))
+                .credentialed_origins(Origin::exact("https://admin.test")),
+        )
+        .expect("valid CORS configuration");
+
+        assert!(cors.may_emit_credentials());
+    }
+}
+
+mod matched_origin {
+    use super::*;
+
+    #[test]
+    fn should_report_mirrored_origin_when_exact_origin_accepted_then_match_allow_origin_header() {
+        let cors = cors_with(CorsOptions::new().origin(Origin::exact("https://allowed.test")));
+        let request = request("GET", Some("https://allowed.test"), None, None);
+
+        let decision = simple_decision(&cors, &request);
+
+        assert!(matches!(
+            decision,
+            Ok(CorsDecision::SimpleAccepted { matched_origin: Some(ref origin), .. })
+                if origin == "https://allowed.test"
+        ));
+    }
+
+    #[test]
+    fn should_report_wildcard_when_any_origin_accepts_preflight_then_return_star() {
+        let cors = cors_with(CorsOptions::new().origin(Origin::Any));
+        let request = request("OPTIONS", Some("https://allowed.test"), Some("GET"), None);
+
+        let decision = preflight_decision(&cors, &request);
+
+        assert!(matches!(
+            decision,
+            Ok(CorsDecision::PreflightAccepted { matched_origin: Some(ref origin), .. })
+                if origin == "*"
+        ));
+    }
+}
+
+#[cfg(feature = "timing")]
+mod check_timed {
+    use super::*;
+
+    #[test]
+    fn should_report_elapsed_time_when_check_timed_then_match_check_decision() {
+        let cors = Cors::new(
+            CorsOptions::new()
+                .origin(Origin::predicate(|origin, _| {
+                    std::thread::sleep(std::time::Duration::from_millis(1));
+                    origin == "https://allowed.test"
+                }))
+                .max_age(60),
+        )
+        .expect("valid CORS configuration");
+        let request = request("OPTIONS", Some("https://allowed.test"), Some("GET"), None);
+
+        let (timed, elapsed) = cors.check_timed(&request);
+
+        assert!(!elapsed.is_zero());
+        assert_eq!(
+            expect_preflight_accepted(timed),
+            expect_preflight_accepted(cors.check(&request))
+        );
+    }
+
+    #[test]
+    fn should_report_elapsed_time_when_compiled_check_timed_then_match_check_decision() {
+        let cors = Cors::new(CorsOptions::new().origin(Origin::list(["https://allowed.test"])))
+            .expect("valid CORS configuration")
+            .compile();
+        let request = request("GET", Some("https://allowed.test"), None, None);
+
+        let (timed, _) = cors.check_timed(&request);
+
+        assert_eq!(
+            expect_simple_accepted(timed),
+            expect_simple_accepted(cors.check(&request))
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+mod to_json_description {
+    use super::*;
+    use crate::origin::OriginMatcher;
+    use serde_json::json;
+
+    #[test]
+    fn should_describe_methods_and_credentials_when_policy_configured_then_render_json() {
+        let cors = Cors::new(
+            CorsOptions::new()
+                .origin(Origin::list([
+                    OriginMatcher::exact("https://app.test"),
+                    OriginMatcher::exact("https://admin.test"),
+                ]))
+                .methods(AllowedMethods::list(["GET", "POST"]))
+                .allowed_headers(AllowedHeaders::list(["X-Test"]))
+                .exposed_headers(ExposedHeaders::list(["X-Trace"]))
+                .credentials(true)
+                .max_age(600),
+        )
+        .expect("valid CORS configuration");
+
+        let description = cors.to_json_description();
+
+        assert_eq!(
+            description,
+            json!({
+                "origins": ["https://app.test", "https://admin.test"],
+                "methods": ["GET", "POST"],
+                "allowed_headers": ["X-Test"],
+                "exposed_headers": ["X-Trace"],
+                "credentials": true,
+                "max_age": 600,
+            })
+        );
+        assert_eq!(cors.compile().to_json_description(), description);
+    }
+
+    #[cfg(feature = "regex")]
+    #[test]
+    fn should_render_dynamic_when_origin_list_has_pattern_then_keep_exact_entries() {
+        let cors = Cors::new(CorsOptions::new().origin(Origin::list([
+            OriginMatcher::exact("https://app.test"),
+            OriginMatcher::pattern_str(r"^https://.*\.app\.test$").expect("valid pattern"),
+        ])))
+        .expect("valid CORS configuration");
+
+        let description = cors.to_json_description();
+
+        assert_eq!(
+            description["origins"],
+            json!(["https://app.test", "dynamic"])
+        );
+    }
+
+    #[test]
+    fn should_render_dynamic_when_origin_custom_then_hide_callback() {
+        let cors = Cors::new(
+            CorsOptions::new()
+                .origin(Origin::custom(|_, _| OriginDecision::Mirror))
+                .allowed_headers(AllowedHeaders::MirrorRequest),
+        )
+        .expect("valid CORS configuration");
+
+        let description = cors.to_json_description();
+
+        assert_eq!(description["origins"], json!("dynamic"));
+        assert_eq!(description["allowed_headers"], json!("dynamic"));
+        assert_eq!(description["credentials"], json!(false));
+        assert_eq!(description["max_age"], json!(null));
+    }
+
+    #[test]
+    fn should_render_wildcards_when_policy_open_then_emit_star() {
+        let cors = Cors::new(
+            CorsOptions::new()
+                .allowed_headers(AllowedHeaders::Any)
+                .exposed_headers(ExposedHeaders::Any),
+        )
+        .expect("valid CORS configuration");
+
+        let description = cors.to_json_description();
+
+        assert_eq!(description["origins"], json!("*"));
+        assert_eq!(description["allowed_headers"], json!("*"));
+        assert_eq!(description["exposed_headers"], json!("*"));
+    }
+}
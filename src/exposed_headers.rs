@@ -3,10 +3,22 @@ use std::collections::HashSet;
 use std::ops::Deref;
 
 /// Configuration mirror of the `Access-Control-Expose-Headers` response header.
+///
+/// [`ExposedHeaders::AnyOr`] emits `*` for non-credentialed responses and falls
+/// back to its explicit list when the response carries credentials, since
+/// browsers treat `*` literally on credentialed requests. The choice is made per
+/// response, following [`CorsOptions::credentialed_origins`](crate::CorsOptions::credentialed_origins)
+/// and [`CorsOptions::credentials_scope`](crate::CorsOptions::credentials_scope).
+///
+/// [`ExposedHeaders::AnyWith`] emits `*` followed by its explicit names, so the
+/// names stay exposed even to clients that treat `*` literally. Like
+/// [`ExposedHeaders::Any`], it requires credentials to be disabled.
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub enum ExposedHeaders {
     List(ExposedHeaderList),
     Any,
+    AnyOr(ExposedHeaderList),
+    AnyWith(ExposedHeaderList),
 }
 
 impl Default for ExposedHeaders {
@@ -23,21 +35,7 @@ impl ExposedHeaders {
         I: IntoIterator<Item = S>,
         S: Into<String>,
     {
-        let mut seen = HashSet::new();
-        let mut deduped: Vec<String> = Vec::new();
-
-        for value in values.into_iter() {
-            let trimmed = value.into().trim().to_string();
-            let key = if trimmed.is_empty() {
-                "".to_string()
-            } else {
-                normalize_lower(&trimmed)
-            };
-
-            if seen.insert(key) {
-                deduped.push(trimmed);
-            }
-        }
+        let deduped = dedupe(values);
 
         if deduped.len() == 1 && deduped[0] == "*" {
             return Self::Any;
@@ -46,12 +44,68 @@ impl ExposedHeaders {
         Self::List(ExposedHeaderList::new(deduped))
     }
 
+    /// Builds a wildcard configuration that degrades to the provided list when
+    /// credentials are enabled. Values are trimmed and deduplicated like
+    /// [`ExposedHeaders::list`].
+    pub fn any_or<I, S>(values: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        Self::AnyOr(ExposedHeaderList::new(dedupe(values)))
+    }
+
+    /// Builds a wildcard configuration that also names the provided headers,
+    /// serialized as `*,X-Explicit`. Values are trimmed and deduplicated like
+    /// [`ExposedHeaders::list`]; a `*` entry is dropped because the wildcard is
+    /// always emitted first.
+    pub fn any_with<I, S>(values: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        let mut values = dedupe(values);
+        values.retain(|value| value != "*");
+        Self::AnyWith(ExposedHeaderList::new(values))
+    }
+
+    /// Builds an explicit list from typed [`http::HeaderName`]s, such as the keys
+    /// of the `HeaderMap` a handler populates.
+    ///
+    /// Header names are valid HTTP tokens by construction and already
+    /// lowercase; duplicates are removed like [`ExposedHeaders::list`].
+    #[cfg(feature = "http")]
+    pub fn from_header_names<'a, I>(names: I) -> Self
+    where
+        I: IntoIterator<Item = &'a http::HeaderName>,
+    {
+        Self::list(names.into_iter().map(http::HeaderName::as_str))
+    }
+
     /// Serializes the configuration into a header-ready value.
+    ///
+    /// [`Self::AnyOr`] serializes as `*`; use [`Self::header_value_for`] to take
+    /// credentials into account.
     pub fn header_value(&self) -> Option<String> {
+        self.header_value_for(false)
+    }
+
+    /// Serializes the configuration for a response with the given credentials
+    /// mode.
+    pub fn header_value_for(&self, credentials: bool) -> Option<String> {
         match self {
             Self::List(values) if values.is_empty() => None,
             Self::List(values) => Some(values.join(",")),
             Self::Any => Some("*".to_string()),
+            Self::AnyOr(_) if !credentials => Some("*".to_string()),
+            Self::AnyOr(values) if values.is_empty() => None,
+            Self::AnyOr(values) => Some(values.join(",")),
+            Self::AnyWith(values) => Some(
+                std::iter::once("*")
+                    .chain(values.iter().map(String::as_str))
+                    .collect::<Vec<_>>()
+                    .join(","),
+            ),
         }
     }
 
@@ -59,14 +113,42 @@ impl ExposedHeaders {
     ///
     /// When configured as [`Self::Any`], the iterator is empty because "*" is
     /// represented via the header value rather than as an explicit element.
+    /// [`Self::AnyOr`] yields its credentialed fallback list and
+    /// [`Self::AnyWith`] the names emitted after the wildcard.
     pub fn iter(&self) -> ExposedHeadersIter<'_> {
         match self {
-            Self::List(values) => ExposedHeadersIter::List(values.values.iter()),
+            Self::List(values) | Self::AnyOr(values) | Self::AnyWith(values) => {
+                ExposedHeadersIter::List(values.values.iter())
+            }
             Self::Any => ExposedHeadersIter::Empty,
         }
     }
 }
 
+fn dedupe<I, S>(values: I) -> Vec<String>
+where
+    I: IntoIterator<Item = S>,
+    S: Into<String>,
+{
+    let mut seen = HashSet::new();
+    let mut deduped: Vec<String> = Vec::new();
+
+    for value in values.into_iter() {
+        let trimmed = value.into().trim().to_string();
+        let key = if trimmed.is_empty() {
+            "".to_string()
+        } else {
+            normalize_lower(&trimmed)
+        };
+
+        if seen.insert(key) {
+            deduped.push(trimmed);
+        }
+    }
+
+    deduped
+}
+
 /// Iterator type returned by [`ExposedHeaders::iter`].
 pub enum ExposedHeadersIter<'a> {
     Empty,
@@ -56,6 +56,54 @@ mod list {
     }
 }
 
+mod any_with {
+    use super::*;
+
+    #[test]
+    fn given_duplicate_and_wildcard_values_when_any_with_called_then_keeps_explicit_names() {
+        let headers = ExposedHeaders::any_with([" X-Trace ", "*", "x-trace", "X-Span"]);
+
+        let collected: Vec<_> = headers.iter().cloned().collect();
+        assert!(matches!(&headers, ExposedHeaders::AnyWith(_)));
+        assert_eq!(collected, vec!["X-Trace".to_string(), "X-Span".to_string()]);
+    }
+}
+
+#[cfg(feature = "http")]
+mod from_header_names {
+    use super::*;
+    use http::{HeaderMap, HeaderName, HeaderValue};
+
+    #[test]
+    fn should_build_list_when_header_names_provided_then_emit_exposed_value() {
+        let names = [
+            HeaderName::from_static("x-request-id"),
+            http::header::ETAG,
+            HeaderName::from_static("x-request-id"),
+        ];
+
+        let exposed = ExposedHeaders::from_header_names(names.iter());
+
+        assert_eq!(
+            exposed.header_value(),
+            Some("x-request-id,etag".to_string())
+        );
+    }
+
+    #[test]
+    fn should_build_list_when_header_map_keys_provided_then_expose_each_name() {
+        let mut headers = HeaderMap::new();
+        headers.insert(http::header::ETAG, HeaderValue::from_static("\"v1\""));
+        headers.insert("x-trace", HeaderValue::from_static("abc"));
+
+        let exposed = ExposedHeaders::from_header_names(headers.keys());
+
+        let mut values: Vec<_> = exposed.iter().cloned().collect();
+        values.sort();
+        assert_eq!(values, vec!["etag".to_string(), "x-trace".to_string()]);
+    }
+}
+
 mod header_value {
     use super::*;
 
@@ -82,9 +130,43 @@ mod header_value {
 
         assert_eq!(value.as_deref(), Some("*"));
     }
+
+    #[test]
+    fn given_any_or_variant_when_credentials_toggled_then_switches_between_wildcard_and_list() {
+        let headers = ExposedHeaders::any_or(["X-Trace", "X-Span"]);
+
+        assert_eq!(headers.header_value().as_deref(), Some("*"));
+        assert_eq!(headers.header_value_for(false).as_deref(), Some("*"));
+        assert_eq!(
+            headers.header_value_for(true).as_deref(),
+            Some("X-Trace,X-Span")
+        );
+    }
+
+    #[test]
+    fn given_any_with_variant_when_header_value_requested_then_returns_wildcard_then_names() {
+        let headers = ExposedHeaders::any_with(["X-Trace", "X-Span"]);
+
+        assert_eq!(headers.header_value().as_deref(), Some("*,X-Trace,X-Span"));
+    }
+
+    #[test]
+    fn given_empty_any_with_variant_when_header_value_requested_then_returns_wildcard() {
+        let headers = ExposedHeaders::any_with(std::iter::empty::<&str>());
+
+        assert_eq!(headers.header_value().as_deref(), Some("*"));
+    }
+
+    #[test]
+    fn given_empty_any_or_variant_when_credentials_enabled_then_returns_none() {
+        let headers = ExposedHeaders::any_or(std::iter::empty::<&str>());
+
+        assert!(headers.header_value_for(true).is_none());
+    }
 }
 
 mod iter {
+
     use super::*;
 
     #[test]
@@ -102,6 +184,14 @@ mod iter {
 
         assert!(collected.is_empty());
     }
+
+    #[test]
+    fn given_any_or_variant_when_iter_called_then_yields_fallback_list() {
+        let headers = ExposedHeaders::any_or(["X-Trace", "x-trace", "X-Span"]);
+        let collected: Vec<_> = headers.iter().cloned().collect();
+
+        assert_eq!(collected, vec!["X-Trace".to_string(), "X-Span".to_string()]);
+    }
 }
 
 mod exposed_header_list {
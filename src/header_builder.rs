@@ -3,42 +3,199 @@ use crate::constants::header;
 use crate::context::RequestContext;
 use crate::exposed_headers::ExposedHeaders;
 use crate::headers::HeaderCollection;
-use crate::options::CorsOptions;
-use crate::origin::OriginDecision;
+use crate::options::{CorsOptions, NullOriginMode};
+use crate::origin::{NULL_ORIGIN, Origin, OriginDecision};
 use crate::result::CorsError;
+use crate::util::{starts_with_ignore_ascii_case, strip_default_port, strip_trailing_slash};
+use std::borrow::Cow;
+use std::collections::HashSet;
 
+#[derive(Clone, Copy)]
 pub(crate) struct HeaderBuilder<'a> {
     options: &'a CorsOptions,
+    precomputed: Option<&'a PrecomputedHeaders>,
+    credentialed: bool,
+    learning: bool,
 }
 
 impl<'a> HeaderBuilder<'a> {
     pub(crate) fn new(options: &'a CorsOptions) -> Self {
-        Self { options }
+        Self {
+            options,
+            precomputed: None,
+            credentialed: false,
+            learning: true,
+        }
     }
 
-    pub(crate) fn build_origin_headers(
+    /// Ignores [`CorsOptions::learning_mode`], so disallowed origins stay
+    /// disallowed and the callback is not invoked.
+    pub(crate) fn without_learning(mut self) -> Self {
+        self.learning = false;
+        self
+    }
+
+    /// Switches origin resolution to [`CorsOptions::credentialed_origins`] and
+    /// emits credentials regardless of [`CorsOptions::credentials`].
+    pub(crate) fn for_credentialed_origins(mut self) -> Self {
+        self.credentialed = true;
+        self
+    }
+
+    pub(crate) fn origin_policy(&self) -> &'a Origin {
+        match &self.options.credentialed_origins {
+            Some(policy) if self.credentialed => policy,
+            _ => &self.options.origin,
+        }
+    }
+
+    fn credentials(&self) -> bool {
+        self.credentialed || self.options.credentials
+    }
+
+    pub(crate) fn with_precomputed(mut self, precomputed: Option<&'a PrecomputedHeaders>) -> Self {
+        self.precomputed = precomputed;
+        self
+    }
+
+    pub(crate) fn resolve_origin(
         &self,
         original: &RequestContext<'_>,
         normalized: &RequestContext<'_>,
-    ) -> Result<(HeaderCollection, OriginDecision), CorsError> {
+    ) -> OriginDecision {
+        let decision = self.resolve_policy_origin(original, normalized);
+        // The credentialed set falls back to the main policy, which learns instead.
+        match (&self.options.learning_mode, decision) {
+            (Some(record), OriginDecision::Disallow) if self.learning && !self.credentialed => {
+                match original.origin {
+                    Some(origin) if !origin.is_empty() => {
+                        record(origin);
+                        OriginDecision::Mirror
+                    }
+                    _ => OriginDecision::Disallow,
+                }
+            }
+            (_, decision) => decision,
+        }
+    }
+
+    fn resolve_policy_origin(
+        &self,
+        original: &RequestContext<'_>,
+        normalized: &RequestContext<'_>,
+    ) -> OriginDecision {
         let normalized_origin = normalized.origin;
-        if let Some(origin) = normalized_origin
-            && origin.eq_ignore_ascii_case("null")
-            && !self.options.allow_null_origin
+        if let (Some(check), Some(origin), Some(host)) = (
+            &self.options.reject_origin_host_mismatch,
+            normalized_origin,
+            normalized.host,
+        ) && check(origin, host)
         {
-            let mut headers = HeaderCollection::with_estimate(1);
-            headers.add_vary(header::ORIGIN);
-            return Ok((headers, OriginDecision::Disallow));
+            return OriginDecision::Disallow;
+        }
+        if normalized_origin == Some(NULL_ORIGIN) {
+            if self.options.strict_null_origin && original.origin != Some(NULL_ORIGIN) {
+                return OriginDecision::Disallow;
+            }
+            if !self.options.allow_null_origin && !self.origin_policy().allows_null_matcher() {
+                return OriginDecision::Disallow;
+            }
         }
 
-        let request_origin = normalized_origin.filter(|origin| !origin.is_empty());
+        let request_origin = normalized_origin
+            .map(|origin| self.canonical_origin(origin))
+            .map(|origin| {
+                if self.options.normalize_default_ports {
+                    strip_default_port(origin)
+                } else {
+                    origin
+                }
+            })
+            .filter(|origin| !origin.is_empty());
+
+        if self.options.require_https
+            && let Some(origin) = request_origin
+            && !origin.eq_ignore_ascii_case("null")
+            && !starts_with_ignore_ascii_case(origin, "https://")
+        {
+            return OriginDecision::Disallow;
+        }
+
+        let canonicalized = match (&self.options.origin_canonicalizer, request_origin) {
+            (Some(canonicalize), Some(origin)) => Some(canonicalize(origin)),
+            _ => None,
+        };
+        let rewritten = matches!(canonicalized, Some(Cow::Owned(_)));
+        let request_origin = canonicalized.as_deref().or(request_origin);
+
+        let policy = self.origin_policy();
+        match policy.resolve(request_origin, normalized) {
+            // A canonical `Origin::Exact` value never matches what the browser
+            // sent, so reflect the request's own origin instead.
+            OriginDecision::Exact(_) if rewritten && matches!(policy, Origin::Exact(_)) => {
+                self.mirror_original(original)
+            }
+            OriginDecision::Mirror => self.mirror_original(original),
+            OriginDecision::Any
+                if normalized_origin == Some(NULL_ORIGIN)
+                    && self.options.null_origin_mode == NullOriginMode::Literal =>
+            {
+                self.mirror_original(original)
+            }
+            // Credentialed responses cannot fall back to `*`, so a null origin
+            // admitted by `allow_null_origin` is reflected even when the list
+            // omits it. The credentialed set keeps deciding through its own
+            // policy.
+            OriginDecision::Disallow
+                if normalized_origin == Some(NULL_ORIGIN)
+                    && self.options.allow_null_origin
+                    && self.options.credentials
+                    && !self.credentialed =>
+            {
+                self.mirror_original(original)
+            }
+            decision => decision,
+        }
+    }
+
+    fn mirror_original(&self, original: &RequestContext<'_>) -> OriginDecision {
+        match original.origin {
+            Some(origin) if !origin.is_empty() => OriginDecision::Mirror,
+            _ => OriginDecision::Disallow,
+        }
+    }
 
-        match self.options.origin.resolve(request_origin, normalized) {
+    fn canonical_origin<'o>(&self, origin: &'o str) -> &'o str {
+        if self.options.normalize_trailing_slash {
+            strip_trailing_slash(origin)
+        } else {
+            origin
+        }
+    }
+
+    pub(crate) fn build_origin_headers(
+        &self,
+        original: &RequestContext<'_>,
+        normalized: &RequestContext<'_>,
+    ) -> Result<(HeaderCollection, OriginDecision), CorsError> {
+        let decision = match self.resolve_origin(original, normalized) {
+            OriginDecision::Any
+                if self.credentials() && self.options.downgrade_wildcard_for_credentials =>
+            {
+                self.mirror_original(original)
+            }
+            decision => decision,
+        };
+        match decision {
             OriginDecision::Any => {
-                if self.options.credentials {
+                if self.credentials() {
                     return Err(CorsError::InvalidOriginAnyWithCredentials);
                 }
                 let mut headers = HeaderCollection::with_estimate(1);
+                // The credentialed set answers some origins differently.
+                if self.options.credentialed_origins.is_some() {
+                    headers.add_vary(header::ORIGIN);
+                }
                 headers.push(
                     header::ACCESS_CONTROL_ALLOW_ORIGIN.to_string(),
                     "*".to_string(),
@@ -55,23 +212,15 @@ impl<'a> HeaderBuilder<'a> {
                 Ok((headers, OriginDecision::Exact(value)))
             }
             OriginDecision::Mirror => {
-                let has_origin = matches!(original.origin, Some(origin) if !origin.is_empty());
-                let capacity = if has_origin { 2 } else { 1 };
-                let mut headers = HeaderCollection::with_estimate(capacity);
+                let mut headers = HeaderCollection::with_estimate(2);
                 headers.add_vary(header::ORIGIN);
-                if let Some(origin) = original.origin {
-                    if origin.is_empty() {
-                        Ok((headers, OriginDecision::Disallow))
-                    } else {
-                        headers.push(
-                            header::ACCESS_CONTROL_ALLOW_ORIGIN.to_string(),
-                            origin.to_string(),
-                        );
-                        Ok((headers, OriginDecision::Mirror))
-                    }
-                } else {
-                    Ok((headers, OriginDecision::Disallow))
+                if let Some(origin) = self.mirrored_origin(original, normalized) {
+                    let value = self
+                        .multi_origin_value(origin)
+                        .unwrap_or_else(|| origin.to_string());
+                    headers.push(header::ACCESS_CONTROL_ALLOW_ORIGIN.to_string(), value);
                 }
+                Ok((headers, OriginDecision::Mirror))
             }
             OriginDecision::Disallow => {
                 let mut headers = HeaderCollection::with_estimate(1);
@@ -82,18 +231,62 @@ impl<'a> HeaderBuilder<'a> {
         }
     }
 
-    pub(crate) fn build_methods_header(&self) -> HeaderCollection {
-        if let Some(value) = self.options.methods.header_value() {
-            let mut headers = HeaderCollection::with_estimate(1);
-            headers.push(header::ACCESS_CONTROL_ALLOW_METHODS.to_string(), value);
-            headers
-        } else {
-            HeaderCollection::new()
+    /// The request origin a mirrored response grants. The opaque origin is
+    /// reflected in its canonical spelling.
+    fn mirrored_origin<'r>(
+        &self,
+        original: &'r RequestContext<'_>,
+        normalized: &RequestContext<'_>,
+    ) -> Option<&'r str> {
+        let origin = original.origin?;
+        if normalized.origin == Some(NULL_ORIGIN) {
+            return Some(NULL_ORIGIN);
         }
+        Some(self.canonical_origin(origin))
+    }
+
+    /// The origin an accepted response grants to the requester: `*`, the fixed
+    /// value, or the reflected origin, even when
+    /// [`CorsOptions::allow_origin_multi`] emits the whole cache group.
+    pub(crate) fn matched_origin(
+        &self,
+        original: &RequestContext<'_>,
+        normalized: &RequestContext<'_>,
+        decision: &OriginDecision,
+    ) -> Option<String> {
+        match decision {
+            OriginDecision::Any => Some("*".to_string()),
+            OriginDecision::Exact(value) => Some(value.clone()),
+            OriginDecision::Mirror => self
+                .mirrored_origin(original, normalized)
+                .map(str::to_string),
+            OriginDecision::Disallow | OriginDecision::Skip => None,
+        }
+    }
+
+    /// Space-separated cache group emitted instead of the reflected origin
+    /// under [`CorsOptions::allow_origin_multi`] when `origin` is a member.
+    fn multi_origin_value(&self, origin: &str) -> Option<String> {
+        let group = self.options.allow_origin_multi.as_deref()?;
+        if self.credentials() || origin == NULL_ORIGIN {
+            return None;
+        }
+        group
+            .iter()
+            .any(|member| member.eq_ignore_ascii_case(origin))
+            .then(|| group.join(" "))
+    }
+
+    pub(crate) fn build_methods_header(&self) -> HeaderCollection {
+        let value = match self.precomputed {
+            Some(precomputed) => precomputed.methods.clone(),
+            None => methods_value(self.options),
+        };
+        single_header(header::ACCESS_CONTROL_ALLOW_METHODS, value)
     }
 
     pub(crate) fn build_credentials_header(&self) -> HeaderCollection {
-        if self.options.credentials {
+        if self.credentials() {
             let mut headers = HeaderCollection::with_estimate(1);
             headers.push(
                 header::ACCESS_CONTROL_ALLOW_CREDENTIALS.to_string(),
@@ -105,27 +298,82 @@ impl<'a> HeaderBuilder<'a> {
         }
     }
 
-    pub(crate) fn build_allowed_headers(&self) -> HeaderCollection {
-        match &self.options.allowed_headers {
-            AllowedHeaders::List(values) if values.is_empty() => HeaderCollection::new(),
-            AllowedHeaders::List(values) => {
-                let mut headers = HeaderCollection::with_estimate(1);
+    pub(crate) fn build_preflight_credentials_header(&self) -> HeaderCollection {
+        if self.options.credentials_scope.includes_preflight() {
+            self.build_credentials_header()
+        } else {
+            HeaderCollection::new()
+        }
+    }
+
+    pub(crate) fn build_simple_credentials_header(&self) -> HeaderCollection {
+        if self.options.credentials_scope.includes_simple() {
+            self.build_credentials_header()
+        } else {
+            HeaderCollection::new()
+        }
+    }
+
+    pub(crate) fn build_allowed_headers(&self, request: &RequestContext<'_>) -> HeaderCollection {
+        let mut headers = self.build_policy_allowed_headers(request);
+        if !self.options.allowed_headers_by_method.is_empty() {
+            headers.add_vary(header::ACCESS_CONTROL_REQUEST_METHOD);
+        }
+        headers
+    }
+
+    fn build_policy_allowed_headers(&self, request: &RequestContext<'_>) -> HeaderCollection {
+        let by_method = request
+            .access_control_request_method
+            .and_then(|method| self.options.method_allowed_headers(method));
+        let allowed_headers = by_method.unwrap_or(&self.options.allowed_headers);
+
+        if matches!(allowed_headers, AllowedHeaders::EchoRequest) {
+            let mut headers = HeaderCollection::with_estimate(2);
+            headers.add_vary(header::ACCESS_CONTROL_REQUEST_HEADERS);
+            let valid = valid_requested_headers(
+                request.access_control_request_headers.unwrap_or_default(),
+                |token| self.options.is_token(token),
+            );
+            if !valid.is_empty() {
+                headers.push(header::ACCESS_CONTROL_ALLOW_HEADERS.to_string(), valid);
+            }
+            return headers;
+        }
+
+        if matches!(allowed_headers, AllowedHeaders::MirrorRequest) {
+            let mut headers = HeaderCollection::with_estimate(2);
+            headers.add_vary(header::ACCESS_CONTROL_REQUEST_HEADERS);
+            if let Some(value) = request
+                .access_control_request_headers
+                .map(str::trim)
+                .filter(|value| !value.is_empty())
+            {
                 headers.push(
                     header::ACCESS_CONTROL_ALLOW_HEADERS.to_string(),
-                    values.join(","),
+                    dedup_requested_headers(value).into_owned(),
                 );
-                headers
             }
+            return headers;
+        }
 
-            AllowedHeaders::Any => {
-                let mut headers = HeaderCollection::with_estimate(1);
+        if let AllowedHeaders::ListEchoCase(values) = allowed_headers {
+            let mut headers = HeaderCollection::with_estimate(2);
+            headers.add_vary(header::ACCESS_CONTROL_REQUEST_HEADERS);
+            if !values.is_empty() || self.options.emit_empty_allow_headers {
                 headers.push(
                     header::ACCESS_CONTROL_ALLOW_HEADERS.to_string(),
-                    "*".to_string(),
+                    echo_requested_casing(values, request.access_control_request_headers),
                 );
-                headers
             }
+            return headers;
         }
+
+        let value = match (self.precomputed, by_method) {
+            (Some(precomputed), None) => precomputed.allowed_headers.clone(),
+            _ => allowed_headers_value(self.options, allowed_headers),
+        };
+        single_header(header::ACCESS_CONTROL_ALLOW_HEADERS, value)
     }
 
     pub(crate) fn build_private_network_header(
@@ -134,7 +382,7 @@ impl<'a> HeaderBuilder<'a> {
     ) -> HeaderCollection {
         let is_preflight = request.method.eq_ignore_ascii_case("OPTIONS");
         if self.options.allow_private_network
-            && is_preflight
+            && (is_preflight || self.options.private_network_on_simple)
             && request.access_control_request_private_network
         {
             let mut headers = HeaderCollection::with_estimate(1);
@@ -148,59 +396,213 @@ impl<'a> HeaderBuilder<'a> {
     }
 
     pub(crate) fn build_exposed_headers(&self) -> HeaderCollection {
-        match &self.options.exposed_headers {
-            ExposedHeaders::Any => {
-                let mut headers = HeaderCollection::with_estimate(1);
-                headers.push(
-                    header::ACCESS_CONTROL_EXPOSE_HEADERS.to_string(),
-                    "*".to_string(),
-                );
-                headers
-            }
-            ExposedHeaders::List(values) if values.is_empty() => HeaderCollection::new(),
-            ExposedHeaders::List(values) => {
-                let entries = values
-                    .iter()
-                    .map(|entry| entry.trim())
-                    .filter(|entry| !entry.is_empty())
-                    .collect::<Vec<_>>();
-
-                if entries.is_empty() {
-                    HeaderCollection::new()
-                } else {
-                    let value = entries.join(",");
-                    let mut headers = HeaderCollection::with_estimate(1);
-                    headers.push(header::ACCESS_CONTROL_EXPOSE_HEADERS.to_string(), value);
-                    headers
-                }
-            }
-        }
+        let value = match self.precomputed {
+            Some(precomputed) if !self.credentialed => precomputed.exposed_headers.clone(),
+            _ => exposed_headers_value(
+                self.options,
+                self.credentials() && self.options.credentials_scope.includes_simple(),
+            ),
+        };
+        single_header(header::ACCESS_CONTROL_EXPOSE_HEADERS, value)
     }
 
     pub(crate) fn build_max_age_header(&self) -> HeaderCollection {
-        if let Some(value) = self.options.max_age {
+        let value = match self.precomputed {
+            Some(precomputed) => precomputed.max_age.clone(),
+            None => max_age_value(self.options),
+        };
+        single_header(header::ACCESS_CONTROL_MAX_AGE, value)
+    }
+
+    pub(crate) fn build_content_length_header(&self) -> HeaderCollection {
+        if self.options.preflight_content_length {
             let mut headers = HeaderCollection::with_estimate(1);
-            headers.push(
-                header::ACCESS_CONTROL_MAX_AGE.to_string(),
-                value.to_string(),
-            );
-            return headers;
+            headers.push(header::CONTENT_LENGTH.to_string(), "0".to_string());
+            headers
+        } else {
+            HeaderCollection::new()
         }
-        HeaderCollection::new()
+    }
+
+    pub(crate) fn build_extra_headers(&self) -> HeaderCollection {
+        let extra = &self.options.extra_response_headers;
+        let mut headers = HeaderCollection::with_estimate(extra.len());
+        for (name, value) in extra {
+            headers.push(name.clone(), value.clone());
+        }
+        headers
     }
 
     pub(crate) fn build_timing_allow_origin_header(&self) -> HeaderCollection {
-        if let Some(config) = &self.options.timing_allow_origin
-            && let Some(value) = config.header_value()
-        {
+        let value = match self.precomputed {
+            Some(precomputed) => precomputed.timing_allow_origin.clone(),
+            None => timing_allow_origin_value(self.options),
+        };
+        single_header(header::TIMING_ALLOW_ORIGIN, value)
+    }
+}
+
+/// Header values that depend only on [`CorsOptions`], rendered once by
+/// [`Cors::compile`](crate::Cors::compile) instead of on every request.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct PrecomputedHeaders {
+    methods: Option<String>,
+    allowed_headers: Option<String>,
+    exposed_headers: Option<String>,
+    max_age: Option<String>,
+    timing_allow_origin: Option<String>,
+}
+
+impl PrecomputedHeaders {
+    pub(crate) fn new(options: &CorsOptions) -> Self {
+        Self {
+            methods: methods_value(options),
+            allowed_headers: allowed_headers_value(options, &options.allowed_headers),
+            // Exposed headers only appear on simple responses, so `AnyOr` keeps
+            // `*` when credentials are scoped to preflights.
+            exposed_headers: exposed_headers_value(
+                options,
+                options.credentials && options.credentials_scope.includes_simple(),
+            ),
+            max_age: max_age_value(options),
+            timing_allow_origin: timing_allow_origin_value(options),
+        }
+    }
+}
+
+fn single_header(name: &str, value: Option<String>) -> HeaderCollection {
+    match value {
+        Some(value) => {
             let mut headers = HeaderCollection::with_estimate(1);
-            headers.push(header::TIMING_ALLOW_ORIGIN.to_string(), value);
-            return headers;
+            headers.push(name.to_string(), value);
+            headers
         }
-        HeaderCollection::new()
+        None => HeaderCollection::new(),
     }
 }
 
+fn methods_value(options: &CorsOptions) -> Option<String> {
+    options.methods.header_value()
+}
+
+fn allowed_headers_value(
+    options: &CorsOptions,
+    allowed_headers: &AllowedHeaders,
+) -> Option<String> {
+    match allowed_headers {
+        AllowedHeaders::List(values) | AllowedHeaders::ListEchoCase(values)
+            if values.is_empty() =>
+        {
+            options.emit_empty_allow_headers.then(String::new)
+        }
+        AllowedHeaders::List(values) | AllowedHeaders::ListEchoCase(values) => {
+            Some(values.join(","))
+        }
+        AllowedHeaders::Any => Some("*".to_string()),
+        AllowedHeaders::MirrorRequest | AllowedHeaders::EchoRequest => None,
+    }
+}
+
+/// Keeps the requested tokens accepted by `is_token`, dropping repeats
+/// (ignoring ASCII case), joined with `", "`.
+fn valid_requested_headers(requested: &str, is_token: impl Fn(&str) -> bool) -> String {
+    let mut seen = HashSet::new();
+    requested
+        .split(',')
+        .map(str::trim)
+        .filter(|token| !token.is_empty() && is_token(token))
+        .filter(|token| seen.insert(token.to_ascii_lowercase()))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Drops repeated tokens (ignoring ASCII case) from a requested header list,
+/// keeping the first spelling. Lists without repeats are returned unchanged.
+fn dedup_requested_headers(requested: &str) -> Cow<'_, str> {
+    let mut seen = HashSet::new();
+    let mut tokens = Vec::new();
+    let mut repeated = false;
+    for token in requested.split(',').map(str::trim) {
+        if token.is_empty() {
+            continue;
+        }
+        if seen.insert(token.to_ascii_lowercase()) {
+            tokens.push(token);
+        } else {
+            repeated = true;
+        }
+    }
+
+    if repeated {
+        Cow::Owned(tokens.join(", "))
+    } else {
+        Cow::Borrowed(requested)
+    }
+}
+
+fn echo_requested_casing(values: &[String], requested: Option<&str>) -> String {
+    let requested: Vec<&str> = requested
+        .unwrap_or_default()
+        .split(',')
+        .map(str::trim)
+        .filter(|token| !token.is_empty())
+        .collect();
+
+    values
+        .iter()
+        .map(|value| {
+            requested
+                .iter()
+                .find(|token| token.eq_ignore_ascii_case(value))
+                .copied()
+                .unwrap_or(value.as_str())
+        })
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+fn exposed_headers_value(options: &CorsOptions, credentials: bool) -> Option<String> {
+    match &options.exposed_headers {
+        ExposedHeaders::Any => Some("*".to_string()),
+        ExposedHeaders::AnyOr(_) if !credentials => Some("*".to_string()),
+        ExposedHeaders::AnyWith(values) => Some(
+            std::iter::once("*")
+                .chain(
+                    values
+                        .iter()
+                        .map(|entry| entry.trim())
+                        .filter(|entry| !entry.is_empty()),
+                )
+                .collect::<Vec<_>>()
+                .join(","),
+        ),
+        ExposedHeaders::List(values) | ExposedHeaders::AnyOr(values) => {
+            let entries = values
+                .iter()
+                .map(|entry| entry.trim())
+                .filter(|entry| !entry.is_empty())
+                .collect::<Vec<_>>();
+
+            if entries.is_empty() {
+                None
+            } else {
+                Some(entries.join(","))
+            }
+        }
+    }
+}
+
+fn max_age_value(options: &CorsOptions) -> Option<String> {
+    options.max_age.map(|value| value.to_string())
+}
+
+fn timing_allow_origin_value(options: &CorsOptions) -> Option<String> {
+    options
+        .timing_allow_origin
+        .as_ref()
+        .and_then(|config| config.header_value())
+}
+
 #[cfg(test)]
 #[path = "header_builder_test.rs"]
 mod header_builder_test;
@@ -22,6 +22,8 @@ fn build_request(
         access_control_request_method: optional(acrm),
         access_control_request_headers: optional(acrh),
         access_control_request_private_network: private_network,
+        sec_fetch_site: None,
+        host: None,
     }
 }
 
@@ -183,6 +185,48 @@ mod build_origin_headers {
         assert_eq!(error, CorsError::InvalidOriginAnyWithCredentials);
     }
 
+    #[test]
+    fn should_mirror_origin_when_downgrade_enabled_and_callback_returns_any_then_allow_credentials()
+    {
+        let mut options = options_with_origin(Origin::custom(|_, _| OriginDecision::Any));
+        options.credentials = true;
+        options.downgrade_wildcard_for_credentials = true;
+        let builder = HeaderBuilder::new(&options);
+        let ctx = request("GET", Some("https://wild.test"), "", "");
+
+        let (headers, decision) = builder
+            .build_origin_headers(&ctx, &ctx)
+            .expect("expected mirrored origin");
+        let map = headers.into_headers();
+
+        assert!(matches!(decision, OriginDecision::Mirror));
+        assert_eq!(
+            map.get(header::ACCESS_CONTROL_ALLOW_ORIGIN),
+            Some(&"https://wild.test".to_string())
+        );
+        assert_eq!(map.get(header::VARY), Some(&"Origin".to_string()));
+    }
+
+    #[test]
+    fn should_keep_wildcard_when_downgrade_enabled_without_credentials_then_emit_star() {
+        let mut options = options_with_origin(Origin::custom(|_, _| OriginDecision::Any));
+        options.downgrade_wildcard_for_credentials = true;
+        let builder = HeaderBuilder::new(&options);
+        let ctx = request("GET", Some("https://wild.test"), "", "");
+
+        let (headers, decision) = builder
+            .build_origin_headers(&ctx, &ctx)
+            .expect("expected wildcard origin");
+
+        assert!(matches!(decision, OriginDecision::Any));
+        assert_eq!(
+            headers
+                .into_headers()
+                .get(header::ACCESS_CONTROL_ALLOW_ORIGIN),
+            Some(&"*".to_string())
+        );
+    }
+
     #[test]
     fn should_emit_vary_only_when_origin_disallowed_then_deny_request() {
         let options = options_with_origin(Origin::list(["https://allowed.test"]));
@@ -330,7 +374,9 @@ mod build_allowed_headers {
             CorsOptions::new().allowed_headers(AllowedHeaders::list(["X-Trace", "X-Auth"]));
         let builder = HeaderBuilder::new(&options);
 
-        let map = builder.build_allowed_headers().into_headers();
+        let map = builder
+            .build_allowed_headers(&request("OPTIONS", None, "", ""))
+            .into_headers();
 
         assert_eq!(
             map.get(header::ACCESS_CONTROL_ALLOW_HEADERS),
@@ -345,7 +391,9 @@ mod build_allowed_headers {
             CorsOptions::new().allowed_headers(AllowedHeaders::list(Vec::<String>::new()));
         let builder = HeaderBuilder::new(&options);
 
-        let map = builder.build_allowed_headers().into_headers();
+        let map = builder
+            .build_allowed_headers(&request("OPTIONS", None, "", ""))
+            .into_headers();
 
         assert!(map.is_empty());
     }
@@ -356,7 +404,9 @@ mod build_allowed_headers {
             CorsOptions::new().allowed_headers(AllowedHeaders::list(["X-Test", "X-Trace"]));
         let builder = HeaderBuilder::new(&options);
 
-        let map = builder.build_allowed_headers().into_headers();
+        let map = builder
+            .build_allowed_headers(&request("OPTIONS", None, "", ""))
+            .into_headers();
 
         assert_eq!(
             map.get(header::ACCESS_CONTROL_ALLOW_HEADERS),
@@ -370,7 +420,9 @@ mod build_allowed_headers {
         let options = CorsOptions::new().allowed_headers(AllowedHeaders::list(["X-Test"]));
         let builder = HeaderBuilder::new(&options);
 
-        let map = builder.build_allowed_headers().into_headers();
+        let map = builder
+            .build_allowed_headers(&request("OPTIONS", None, "", ""))
+            .into_headers();
 
         assert_eq!(
             map.get(header::ACCESS_CONTROL_ALLOW_HEADERS),
@@ -384,16 +436,66 @@ mod build_allowed_headers {
         let options = CorsOptions::new().allowed_headers(AllowedHeaders::Any);
         let builder = HeaderBuilder::new(&options);
 
-        let map = builder.build_allowed_headers().into_headers();
+        let map = builder
+            .build_allowed_headers(&request("OPTIONS", None, "", ""))
+            .into_headers();
 
         assert_eq!(
             map.get(header::ACCESS_CONTROL_ALLOW_HEADERS),
             Some(&"*".to_string())
         );
     }
+
+    #[test]
+    fn should_echo_request_headers_when_mirror_request_then_add_vary() {
+        let options = CorsOptions::new().allowed_headers(AllowedHeaders::MirrorRequest);
+        let builder = HeaderBuilder::new(&options);
+
+        let map = builder
+            .build_allowed_headers(&request("OPTIONS", None, "GET", "X-Trace, X-Auth"))
+            .into_headers();
+
+        assert_eq!(
+            map.get(header::ACCESS_CONTROL_ALLOW_HEADERS),
+            Some(&"X-Trace, X-Auth".to_string())
+        );
+        assert_eq!(
+            map.get(header::VARY),
+            Some(&header::ACCESS_CONTROL_REQUEST_HEADERS.to_string())
+        );
+    }
+
+    #[test]
+    fn should_reflect_single_token_when_mirror_request_repeats_header_then_keep_first_spelling() {
+        let options = CorsOptions::new().allowed_headers(AllowedHeaders::MirrorRequest);
+        let builder = HeaderBuilder::new(&options);
+
+        let map = builder
+            .build_allowed_headers(&request("OPTIONS", None, "GET", "X-Test, x-test,X-Auth"))
+            .into_headers();
+
+        assert_eq!(
+            map.get(header::ACCESS_CONTROL_ALLOW_HEADERS),
+            Some(&"X-Test, X-Auth".to_string())
+        );
+    }
+
+    #[test]
+    fn should_skip_allow_headers_when_mirror_request_without_request_headers_then_only_vary() {
+        let options = CorsOptions::new().allowed_headers(AllowedHeaders::MirrorRequest);
+        let builder = HeaderBuilder::new(&options);
+
+        let map = builder
+            .build_allowed_headers(&request("OPTIONS", None, "GET", ""))
+            .into_headers();
+
+        assert!(!map.contains_key(header::ACCESS_CONTROL_ALLOW_HEADERS));
+        assert!(map.contains_key(header::VARY));
+    }
 }
 
 mod build_exposed_headers {
+
     use super::*;
 
     #[test]
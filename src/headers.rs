@@ -2,6 +2,8 @@ use crate::constants::header;
 use std::cell::RefCell;
 use std::collections::HashMap;
 use std::mem;
+#[cfg(feature = "pool-metrics")]
+use std::sync::atomic::{AtomicU64, Ordering};
 
 #[cfg(debug_assertions)]
 #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
@@ -38,6 +40,14 @@ fn header_stats_record_release() {
     });
 }
 
+#[cfg(not(debug_assertions))]
+#[inline(always)]
+fn header_stats_record_acquire() {}
+
+#[cfg(not(debug_assertions))]
+#[inline(always)]
+fn header_stats_record_release() {}
+
 #[cfg(all(test, debug_assertions))]
 pub(crate) fn header_pool_stats() -> PoolStats {
     HEADER_POOL_STATS.with(|stats| *stats.borrow())
@@ -48,11 +58,224 @@ pub(crate) fn header_pool_reset() {
     HEADER_POOL_STATS.with(|stats| *stats.borrow_mut() = PoolStats::default());
 }
 
+/// Snapshot of the header buffer pool counters.
+///
+/// Available with the `pool-metrics` feature. Counters are process-wide and
+/// monotonically increasing, so callers should diff successive snapshots.
+#[cfg(feature = "pool-metrics")]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct PoolMetrics {
+    /// Acquisitions served by a previously released buffer.
+    pub hits: u64,
+    /// Acquisitions that had to allocate a fresh buffer.
+    pub misses: u64,
+}
+
+#[cfg(feature = "pool-metrics")]
+static HEADER_POOL_HITS: AtomicU64 = AtomicU64::new(0);
+#[cfg(feature = "pool-metrics")]
+static HEADER_POOL_MISSES: AtomicU64 = AtomicU64::new(0);
+
+/// Returns the header buffer pool hit and miss counts recorded so far.
+#[cfg(feature = "pool-metrics")]
+pub fn header_pool_metrics() -> PoolMetrics {
+    PoolMetrics {
+        hits: HEADER_POOL_HITS.load(Ordering::Relaxed),
+        misses: HEADER_POOL_MISSES.load(Ordering::Relaxed),
+    }
+}
+
 /// Canonical map type used for returning header modifications to callers.
 pub type Headers = HashMap<String, String>;
 
+/// Helpers for [`Headers`] produced by a decision: HTTP/1 serialization and
+/// merging with headers set by other layers.
+pub trait HeadersExt {
+    /// Renders every header as a `Name: Value\r\n` line so the whole block can be
+    /// written in a single call.
+    ///
+    /// `Vary` is emitted first and the remaining headers follow in case-insensitive
+    /// name order, keeping the output stable despite the unordered backing map. The
+    /// terminating blank line is not included.
+    fn to_http_block(&self) -> String;
+
+    /// Merges external `Vary` tokens into this map's `Vary` entry.
+    ///
+    /// Every value may hold a comma-separated list. Tokens are trimmed, empty
+    /// ones dropped, and duplicates removed case-insensitively with the first
+    /// spelling kept. Existing entries stay first and new tokens follow in the
+    /// order given, matching the `Vary` lists this crate emits, and the result
+    /// is written back as a single `Vary` header.
+    fn merge_vary_from(&mut self, tokens: &[&str]);
+
+    /// Replaces the `Access-Control-Expose-Headers` entry of an already-built
+    /// result with `names`, so middleware can finalize the exposed set after the
+    /// handler runs.
+    ///
+    /// Every value may hold a comma-separated list. Names are trimmed, empty
+    /// ones dropped, and duplicates removed case-insensitively with the first
+    /// spelling kept; an empty set removes the header. Other headers are left
+    /// untouched. `*` is written as given and is taken literally by browsers on
+    /// credentialed responses.
+    fn set_exposed(&mut self, names: &[&str]);
+}
+
+/// Joins comma-separated `Vary` values into one list in insertion order,
+/// trimming tokens, dropping empty ones, and keeping the first spelling of
+/// case-insensitive duplicates. Returns `None` when no token remains.
+fn merge_vary_tokens<'v>(values: impl IntoIterator<Item = &'v str>) -> Option<String> {
+    let mut merged: Vec<&str> = Vec::new();
+    for token in values
+        .into_iter()
+        .flat_map(|value| value.split(','))
+        .map(str::trim)
+    {
+        if !token.is_empty() && !merged.iter().any(|seen| seen.eq_ignore_ascii_case(token)) {
+            merged.push(token);
+        }
+    }
+
+    if merged.is_empty() {
+        None
+    } else {
+        Some(merged.join(", "))
+    }
+}
+
+/// Orders `Vary` first and the remaining names case-insensitively.
+fn header_order(a: &str, b: &str) -> std::cmp::Ordering {
+    let a_vary = a.eq_ignore_ascii_case(header::VARY);
+    let b_vary = b.eq_ignore_ascii_case(header::VARY);
+    b_vary
+        .cmp(&a_vary)
+        .then_with(|| a.to_ascii_lowercase().cmp(&b.to_ascii_lowercase()))
+}
+
+impl HeadersExt for Headers {
+    fn to_http_block(&self) -> String {
+        let mut entries: Vec<(&String, &String)> = self.iter().collect();
+        entries.sort_by(|(a, _), (b, _)| header_order(a, b));
+
+        let capacity = entries
+            .iter()
+            .map(|(name, value)| name.len() + value.len() + 4)
+            .sum();
+        let mut block = String::with_capacity(capacity);
+        for (name, value) in entries {
+            block.push_str(name);
+            block.push_str(": ");
+            block.push_str(value);
+            block.push_str("\r\n");
+        }
+
+        block
+    }
+
+    fn merge_vary_from(&mut self, tokens: &[&str]) {
+        let key = self
+            .keys()
+            .find(|name| name.eq_ignore_ascii_case(header::VARY))
+            .cloned()
+            .unwrap_or_else(|| header::VARY.to_string());
+        let existing = self.remove(&key);
+
+        let merged = merge_vary_tokens(
+            existing
+                .iter()
+                .map(String::as_str)
+                .chain(tokens.iter().copied()),
+        );
+        if let Some(value) = merged {
+            self.insert(key, value);
+        }
+    }
+
+    fn set_exposed(&mut self, names: &[&str]) {
+        let key = self
+            .keys()
+            .find(|name| name.eq_ignore_ascii_case(header::ACCESS_CONTROL_EXPOSE_HEADERS))
+            .cloned()
+            .unwrap_or_else(|| header::ACCESS_CONTROL_EXPOSE_HEADERS.to_string());
+        self.remove(&key);
+
+        let mut exposed: Vec<&str> = Vec::new();
+        for name in names
+            .iter()
+            .flat_map(|value| value.split(','))
+            .map(str::trim)
+        {
+            if !name.is_empty() && !exposed.iter().any(|seen| seen.eq_ignore_ascii_case(name)) {
+                exposed.push(name);
+            }
+        }
+
+        if exposed.is_empty() {
+            return;
+        }
+        self.insert(key, exposed.join(","));
+    }
+}
+
+/// Destination for the headers of a decision, fed by
+/// [`Cors::check_with_sink`](crate::Cors::check_with_sink).
+///
+/// Implement it to hand the finished headers to a framework's own response
+/// type without cloning them. [`Headers`] implements it by inserting each pair.
+pub trait HeaderSink {
+    /// Receives one response header.
+    fn push(&mut self, name: String, value: String);
+}
+
+impl HeaderSink for Headers {
+    fn push(&mut self, name: String, value: String) {
+        self.insert(name, value);
+    }
+}
+
+/// Position of each CORS header under
+/// [`CorsOptions::deterministic_header_order`](crate::CorsOptions::deterministic_header_order).
+/// Names not listed follow in [`header_order`].
+const CANONICAL_HEADER_ORDER: [&str; 9] = [
+    header::VARY,
+    header::ACCESS_CONTROL_ALLOW_ORIGIN,
+    header::ACCESS_CONTROL_ALLOW_CREDENTIALS,
+    header::ACCESS_CONTROL_ALLOW_METHODS,
+    header::ACCESS_CONTROL_ALLOW_HEADERS,
+    header::ACCESS_CONTROL_MAX_AGE,
+    header::ACCESS_CONTROL_EXPOSE_HEADERS,
+    header::ACCESS_CONTROL_ALLOW_PRIVATE_NETWORK,
+    header::TIMING_ALLOW_ORIGIN,
+];
+
+fn canonical_header_order(a: &str, b: &str) -> std::cmp::Ordering {
+    let rank = |name: &str| {
+        CANONICAL_HEADER_ORDER
+            .iter()
+            .position(|canonical| canonical.eq_ignore_ascii_case(name))
+            .unwrap_or(CANONICAL_HEADER_ORDER.len())
+    };
+    rank(a).cmp(&rank(b)).then_with(|| header_order(a, b))
+}
+
+/// Moves every header into `sink`, `Vary` first and the rest in
+/// case-insensitive name order like [`HeadersExt::to_http_block`]. With
+/// `canonical`, the CORS headers follow their fixed canonical order instead.
+pub(crate) fn drain_into_sink(headers: Headers, sink: &mut impl HeaderSink, canonical: bool) {
+    let mut entries: Vec<(String, String)> = headers.into_iter().collect();
+    if canonical {
+        entries.sort_by(|(a, _), (b, _)| canonical_header_order(a, b));
+    } else {
+        entries.sort_by(|(a, _), (b, _)| header_order(a, b));
+    }
+    for (name, value) in entries {
+        sink.push(name, value);
+    }
+}
+
+#[cfg(feature = "buffer-pool")]
 const HEADER_BUFFER_POOL_LIMIT: usize = 64;
 
+#[cfg(feature = "buffer-pool")]
 thread_local! {
     static HEADER_BUFFER_POOL: RefCell<Vec<Vec<(String, String)>>> = const { RefCell::new(Vec::new()) };
 }
@@ -60,17 +283,26 @@ thread_local! {
 fn acquire_entries(estimate: usize) -> Vec<(String, String)> {
     let capacity = estimate.max(4);
 
+    #[cfg(not(feature = "buffer-pool"))]
+    let entries = Vec::with_capacity(capacity);
+    #[cfg(feature = "buffer-pool")]
     let entries = HEADER_BUFFER_POOL.with(|pool| {
         let mut pool = pool.borrow_mut();
         match pool.pop() {
             Some(mut entries) => {
+                #[cfg(feature = "pool-metrics")]
+                HEADER_POOL_HITS.fetch_add(1, Ordering::Relaxed);
                 let required = capacity.saturating_sub(entries.len());
                 if required > 0 {
                     entries.reserve(required);
                 }
                 entries
             }
-            None => Vec::with_capacity(capacity),
+            None => {
+                #[cfg(feature = "pool-metrics")]
+                HEADER_POOL_MISSES.fetch_add(1, Ordering::Relaxed);
+                Vec::with_capacity(capacity)
+            }
         }
     });
 
@@ -88,6 +320,7 @@ fn release_entries(mut entries: Vec<(String, String)>) {
 
     header_stats_record_release();
 
+    #[cfg(feature = "buffer-pool")]
     HEADER_BUFFER_POOL.with(|pool| {
         let mut pool = pool.borrow_mut();
         if pool.len() < HEADER_BUFFER_POOL_LIMIT {
@@ -130,41 +363,32 @@ impl HeaderCollection {
     }
 
     pub(crate) fn add_vary<S: Into<String>>(&mut self, value: S) {
-        let mut entries: Vec<String> = self
-            .vary
-            .as_ref()
-            .map(|existing| {
-                existing
-                    .split(',')
-                    .map(|part| part.trim().to_string())
-                    .filter(|part| !part.is_empty())
-                    .collect()
-            })
-            .unwrap_or_default();
-
-        let incoming = value.into().trim().to_string();
-        if !incoming.is_empty() {
-            entries.push(incoming);
-        }
+        let incoming = value.into();
+        self.vary = merge_vary_tokens(self.vary.as_deref().into_iter().chain([incoming.as_str()]));
+    }
 
-        if entries.is_empty() {
-            self.vary = None;
-            return;
-        }
+    pub(crate) fn clear_vary(&mut self) {
+        self.vary = None;
+    }
 
-        let mut deduped: Vec<String> = Vec::with_capacity(entries.len());
-        for entry in entries {
-            if deduped
-                .iter()
-                .any(|existing| existing.eq_ignore_ascii_case(&entry))
-            {
-                continue;
-            }
-            deduped.push(entry);
+    /// Keeps only the first `max` `Vary` tokens in case-insensitive sorted
+    /// order when there are more than `max`; otherwise leaves `Vary` as is.
+    pub(crate) fn cap_vary(&mut self, max: usize) {
+        let Some(vary) = self.vary.as_deref() else {
+            return;
+        };
+        let mut entries: Vec<&str> = vary.split(',').map(str::trim).collect();
+        if entries.len() <= max {
+            return;
         }
 
-        let value = deduped.join(", ");
-        self.vary = Some(value);
+        entries.sort_by_key(|entry| entry.to_ascii_lowercase());
+        entries.truncate(max);
+        self.vary = if entries.is_empty() {
+            None
+        } else {
+            Some(entries.join(", "))
+        };
     }
 
     pub(crate) fn extend(&mut self, mut other: HeaderCollection) {
@@ -205,6 +205,51 @@ mod extend {
     }
 }
 
+mod cap_vary {
+    use super::*;
+
+    #[test]
+    fn should_keep_first_sorted_entries_when_vary_exceeds_cap_then_truncate() {
+        let mut collection = HeaderCollection::new();
+        collection.add_vary("Origin");
+        collection.add_vary("Access-Control-Request-Headers");
+        collection.add_vary("Accept-Encoding");
+
+        collection.cap_vary(2);
+
+        let headers = collection.into_headers();
+        assert_eq!(
+            headers.get(header::VARY),
+            Some(&"Accept-Encoding, Access-Control-Request-Headers".to_string())
+        );
+    }
+
+    #[test]
+    fn should_keep_original_order_when_vary_within_cap_then_leave_untouched() {
+        let mut collection = HeaderCollection::new();
+        collection.add_vary("Origin");
+        collection.add_vary("Access-Control-Request-Headers");
+
+        collection.cap_vary(2);
+
+        let headers = collection.into_headers();
+        assert_eq!(
+            headers.get(header::VARY),
+            Some(&"Origin, Access-Control-Request-Headers".to_string())
+        );
+    }
+
+    #[test]
+    fn should_drop_vary_when_cap_zero_then_omit_header() {
+        let mut collection = HeaderCollection::new();
+        collection.add_vary("Origin");
+
+        collection.cap_vary(0);
+
+        assert!(!collection.into_headers().contains_key(header::VARY));
+    }
+}
+
 mod into_headers {
     use super::*;
 
@@ -257,6 +302,7 @@ mod pool_instrumentation {
     }
 }
 
+#[cfg(feature = "buffer-pool")]
 mod capacity_management {
     use super::*;
 
@@ -376,3 +422,297 @@ mod capacity_management {
         });
     }
 }
+
+mod to_http_block {
+    use super::*;
+    use crate::allowed_headers::AllowedHeaders;
+    use crate::allowed_methods::AllowedMethods;
+    use crate::constants::method;
+    use crate::context::RequestContext;
+    use crate::cors::Cors;
+    use crate::options::CorsOptions;
+    use crate::origin::Origin;
+    use crate::result::CorsDecision;
+
+    #[test]
+    fn should_render_vary_first_then_sorted_lines_when_preflight_accepted() {
+        let cors = Cors::new(
+            CorsOptions::new()
+                .origin(Origin::exact("https://app.example"))
+                .methods(AllowedMethods::list([method::GET, method::POST]))
+                .allowed_headers(AllowedHeaders::list(["X-Trace"]))
+                .max_age(600),
+        )
+        .expect("valid configuration");
+        let request = RequestContext {
+            method: method::OPTIONS,
+            origin: Some("https://app.example"),
+            access_control_request_method: Some(method::POST),
+            access_control_request_headers: Some("x-trace"),
+            access_control_request_private_network: false,
+            sec_fetch_site: None,
+            host: None,
+        };
+
+        let headers = match cors.check(&request).expect("evaluation succeeds") {
+            CorsDecision::PreflightAccepted { headers, .. } => headers,
+            other => panic!("expected preflight acceptance, got {:?}", other),
+        };
+
+        assert_eq!(
+            headers.to_http_block(),
+            "Vary: Origin\r\n\
+             Access-Control-Allow-Headers: X-Trace\r\n\
+             Access-Control-Allow-Methods: GET,POST\r\n\
+             Access-Control-Allow-Origin: https://app.example\r\n\
+             Access-Control-Max-Age: 600\r\n"
+        );
+    }
+
+    #[test]
+    fn should_return_empty_string_when_headers_empty() {
+        let headers = Headers::new();
+
+        assert_eq!(headers.to_http_block(), "");
+    }
+}
+
+mod merge_vary_from {
+    use super::*;
+
+    #[test]
+    fn should_append_new_tokens_after_existing_when_external_tokens_overlap_existing_vary() {
+        let mut headers = Headers::new();
+        headers.insert(header::VARY.to_string(), "Origin".to_string());
+
+        headers.merge_vary_from(&["origin", "Accept-Encoding"]);
+
+        assert_eq!(headers.len(), 1);
+        assert_eq!(
+            headers.get(header::VARY).map(String::as_str),
+            Some("Origin, Accept-Encoding")
+        );
+    }
+
+    #[test]
+    fn should_match_collection_vary_when_same_tokens_added_in_same_order() {
+        let tokens = [
+            "Origin",
+            "access-control-request-headers, Accept-Encoding",
+            " ",
+            "origin",
+            "Access-Control-Request-Method",
+        ];
+        let mut collection = HeaderCollection::new();
+        for token in tokens {
+            collection.add_vary(token);
+        }
+        let mut headers = Headers::new();
+
+        headers.merge_vary_from(&tokens);
+
+        assert_eq!(
+            headers.get(header::VARY),
+            collection.into_headers().get(header::VARY)
+        );
+        assert_eq!(
+            headers.get(header::VARY).map(String::as_str),
+            Some(
+                "Origin, access-control-request-headers, Accept-Encoding, Access-Control-Request-Method"
+            )
+        );
+    }
+
+    #[test]
+    fn should_reuse_existing_key_spelling_when_vary_stored_lowercase() {
+        let mut headers = Headers::new();
+        headers.insert(
+            "vary".to_string(),
+            "Origin, Access-Control-Request-Headers".to_string(),
+        );
+
+        headers.merge_vary_from(&["Accept-Encoding, origin", " "]);
+
+        assert_eq!(
+            headers.get("vary").map(String::as_str),
+            Some("Origin, Access-Control-Request-Headers, Accept-Encoding")
+        );
+        assert!(!headers.contains_key(header::VARY));
+    }
+
+    #[test]
+    fn should_leave_headers_untouched_when_no_tokens_merged() {
+        let mut headers = Headers::new();
+
+        headers.merge_vary_from(&[]);
+
+        assert!(headers.is_empty());
+    }
+}
+
+mod drain_into_sink {
+    use super::*;
+
+    #[test]
+    fn should_push_vary_first_then_sorted_names_when_draining_into_vec_sink() {
+        struct Pairs(Vec<(String, String)>);
+        impl HeaderSink for Pairs {
+            fn push(&mut self, name: String, value: String) {
+                self.0.push((name, value));
+            }
+        }
+        let mut headers = Headers::new();
+        headers.insert("x-b".to_string(), "2".to_string());
+        headers.insert(header::VARY.to_string(), "Origin".to_string());
+        headers.insert("X-A".to_string(), "1".to_string());
+        let mut sink = Pairs(Vec::new());
+
+        drain_into_sink(headers, &mut sink, false);
+
+        let names: Vec<_> = sink.0.iter().map(|(name, _)| name.as_str()).collect();
+        assert_eq!(names, vec![header::VARY, "X-A", "x-b"]);
+    }
+
+    #[test]
+    fn should_push_canonical_cors_order_when_canonical_then_place_other_names_last() {
+        struct Names<'a>(&'a mut Vec<String>);
+        impl HeaderSink for Names<'_> {
+            fn push(&mut self, name: String, _value: String) {
+                self.0.push(name);
+            }
+        }
+        let mut headers = Headers::new();
+        for name in [
+            header::CONTENT_LENGTH,
+            header::ACCESS_CONTROL_EXPOSE_HEADERS,
+            header::ACCESS_CONTROL_MAX_AGE,
+            header::ACCESS_CONTROL_ALLOW_ORIGIN,
+            header::VARY,
+        ] {
+            headers.insert(name.to_string(), "v".to_string());
+        }
+        let mut sink = Vec::new();
+
+        drain_into_sink(headers, &mut Names(&mut sink), true);
+
+        assert_eq!(
+            sink,
+            vec![
+                header::VARY,
+                header::ACCESS_CONTROL_ALLOW_ORIGIN,
+                header::ACCESS_CONTROL_MAX_AGE,
+                header::ACCESS_CONTROL_EXPOSE_HEADERS,
+                header::CONTENT_LENGTH,
+            ]
+        );
+    }
+
+    #[test]
+    fn should_insert_pairs_when_headers_used_as_sink_then_collect_map() {
+        let mut source = Headers::new();
+        source.insert(
+            header::ACCESS_CONTROL_ALLOW_ORIGIN.to_string(),
+            "*".to_string(),
+        );
+        let mut sink = Headers::new();
+
+        drain_into_sink(source.clone(), &mut sink, false);
+
+        assert_eq!(sink, source);
+    }
+}
+
+mod set_exposed {
+    use super::*;
+
+    #[test]
+    fn should_replace_exposed_value_when_names_set_then_leave_other_headers_intact() {
+        let mut headers = Headers::new();
+        headers.insert(
+            header::ACCESS_CONTROL_ALLOW_ORIGIN.to_string(),
+            "https://app.test".to_string(),
+        );
+        headers.insert(
+            header::ACCESS_CONTROL_EXPOSE_HEADERS.to_string(),
+            "X-Old".to_string(),
+        );
+
+        headers.set_exposed(&["X-Trace, x-trace", " X-Span ", ""]);
+
+        assert_eq!(headers.len(), 2);
+        assert_eq!(
+            headers
+                .get(header::ACCESS_CONTROL_EXPOSE_HEADERS)
+                .map(String::as_str),
+            Some("X-Trace,X-Span")
+        );
+        assert_eq!(
+            headers
+                .get(header::ACCESS_CONTROL_ALLOW_ORIGIN)
+                .map(String::as_str),
+            Some("https://app.test")
+        );
+    }
+
+    #[test]
+    fn should_reuse_existing_key_spelling_when_exposed_stored_lowercase() {
+        let mut headers = Headers::new();
+        headers.insert(
+            "access-control-expose-headers".to_string(),
+            "X-Old".to_string(),
+        );
+
+        headers.set_exposed(&["X-New"]);
+
+        assert_eq!(
+            headers
+                .get("access-control-expose-headers")
+                .map(String::as_str),
+            Some("X-New")
+        );
+        assert_eq!(headers.len(), 1);
+    }
+
+    #[test]
+    fn should_remove_exposed_header_when_names_empty_then_drop_entry() {
+        let mut headers = Headers::new();
+        headers.insert(
+            header::ACCESS_CONTROL_EXPOSE_HEADERS.to_string(),
+            "X-Old".to_string(),
+        );
+
+        headers.set_exposed(&[" "]);
+
+        assert!(headers.is_empty());
+    }
+}
+
+#[cfg(feature = "pool-metrics")]
+mod header_pool_metrics {
+    use super::*;
+
+    #[test]
+    fn should_increment_hits_when_released_buffer_reused_then_report_hit() {
+        HEADER_BUFFER_POOL.with(|pool| pool.borrow_mut().clear());
+        release_entries(Vec::with_capacity(4));
+        let before = header_pool_metrics();
+
+        let entries = acquire_entries(4);
+        let after = header_pool_metrics();
+
+        assert!(after.hits > before.hits);
+        release_entries(entries);
+    }
+
+    #[test]
+    fn should_increment_misses_when_pool_empty_then_report_miss() {
+        HEADER_BUFFER_POOL.with(|pool| pool.borrow_mut().clear());
+        let before = header_pool_metrics();
+
+        let entries = acquire_entries(4);
+        let after = header_pool_metrics();
+
+        assert!(after.misses > before.misses);
+        release_entries(entries);
+    }
+}
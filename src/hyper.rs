@@ -0,0 +1,26 @@
+//! Optional integration helpers for [hyper](https://hyper.rs) and other users of
+//! the [`http`] crate types.
+//!
+//! Enabled through the `hyper` cargo feature.
+
+use crate::headers::Headers;
+use http::header::{HeaderName, HeaderValue};
+use http::response::Builder;
+
+/// Appends the headers produced by a [`CorsDecision`](crate::CorsDecision) to a
+/// response builder.
+///
+/// Headers are appended rather than replaced, so a `Vary` already present on the
+/// builder is kept alongside ours. Entries that are not valid HTTP header names
+/// or values are skipped.
+pub fn apply_to_response_builder(mut builder: Builder, headers: &Headers) -> Builder {
+    for (name, value) in headers.iter() {
+        if let (Ok(name), Ok(value)) = (
+            HeaderName::try_from(name.as_str()),
+            HeaderValue::from_str(value),
+        ) {
+            builder = builder.header(name, value);
+        }
+    }
+    builder
+}
@@ -1,3 +1,5 @@
+#[cfg(feature = "actix")]
+pub mod actix;
 mod allowed_headers;
 mod allowed_methods;
 pub mod constants;
@@ -6,30 +8,45 @@ mod cors;
 mod exposed_headers;
 mod header_builder;
 mod headers;
+#[cfg(feature = "hyper")]
+pub mod hyper;
 mod normalized_request;
 mod options;
 mod origin;
 mod result;
 mod timing_allow_origin;
-mod util;
+pub mod util;
+#[cfg(feature = "watch")]
+mod watched_origin;
 
 pub use allowed_headers::AllowedHeaders;
 pub use allowed_methods::AllowedMethods;
 pub use context::RequestContext;
-pub use cors::Cors;
+pub use cors::{CompiledCors, Cors};
 pub use exposed_headers::ExposedHeaders;
-pub use headers::Headers;
-pub use options::{CorsOptions, ValidationError};
+pub use headers::{HeaderSink, Headers, HeadersExt};
+#[cfg(feature = "pool-metrics")]
+pub use headers::{PoolMetrics, header_pool_metrics};
+pub use normalized_request::{NormalizationBuffers, NormalizedRequest};
+pub use options::{
+    CorsLists, CorsOptions, CredentialsScope, Lint, NullOriginMode, RequestHeaderRewriterFn,
+    TokenValidatorFn, ValidationError, ValidationReporterFn,
+};
+#[cfg(feature = "regex")]
+pub use origin::RegexSizeLimits;
 pub use origin::{
-    Origin, OriginCallbackFn, OriginDecision, OriginMatcher, OriginPredicateFn, PatternError,
+    Origin, OriginCallbackFn, OriginCanonicalizerFn, OriginDecision, OriginEnvError,
+    OriginHostMismatchFn, OriginKind, OriginLearningFn, OriginMatcher, OriginPredicateFn,
+    OriginPredicateValueFn, PatternError,
 };
 pub use result::{
-    CorsDecision, CorsError, PreflightRejection, PreflightRejectionReason, SimpleRejection,
-    SimpleRejectionReason,
+    AuditReport, CorsDecision, CorsError, DecisionKind, PreflightGrants, PreflightRejection,
+    PreflightRejectionReason, SimpleRejection, SimpleRejectionReason, WebSocketDecision,
 };
 pub use timing_allow_origin::TimingAllowOrigin;
+#[cfg(feature = "watch")]
+pub use watched_origin::{OriginFileError, WatchedOriginFile};
 
-#[doc(hidden)]
-pub use normalized_request::NormalizedRequest;
+// Kept at the crate root for existing callers; documented under `util`.
 #[doc(hidden)]
 pub use util::{equals_ignore_case, normalize_lower};
@@ -39,6 +39,14 @@ fn normalization_stats_record_release() {
     });
 }
 
+#[cfg(not(debug_assertions))]
+#[inline(always)]
+fn normalization_stats_record_acquire() {}
+
+#[cfg(not(debug_assertions))]
+#[inline(always)]
+fn normalization_stats_record_release() {}
+
 #[cfg(all(test, debug_assertions))]
 pub(crate) fn normalization_pool_stats() -> PoolStats {
     NORMALIZATION_POOL_STATS.with(|stats| *stats.borrow())
@@ -49,13 +57,18 @@ pub(crate) fn normalization_pool_reset() {
     NORMALIZATION_POOL_STATS.with(|stats| *stats.borrow_mut() = PoolStats::default());
 }
 
+#[cfg(feature = "buffer-pool")]
 const NORMALIZATION_BUFFER_POOL_LIMIT: usize = 16;
 
+#[cfg(feature = "buffer-pool")]
 thread_local! {
     static NORMALIZATION_BUFFER_POOL: RefCell<Vec<String>> = const { RefCell::new(Vec::new()) };
 }
 
 fn acquire_buffer(min_capacity: usize) -> String {
+    #[cfg(not(feature = "buffer-pool"))]
+    let buffer = String::with_capacity(min_capacity);
+    #[cfg(feature = "buffer-pool")]
     let buffer = NORMALIZATION_BUFFER_POOL.with(|pool| {
         let mut pool = pool.borrow_mut();
         if let Some(mut buffer) = pool.pop() {
@@ -73,6 +86,12 @@ fn acquire_buffer(min_capacity: usize) -> String {
     buffer
 }
 
+#[cfg(not(feature = "buffer-pool"))]
+fn release_buffer(_buffer: String) {
+    normalization_stats_record_release();
+}
+
+#[cfg(feature = "buffer-pool")]
 fn release_buffer(mut buffer: String) {
     normalization_stats_record_release();
 
@@ -85,19 +104,28 @@ fn release_buffer(mut buffer: String) {
     });
 }
 
-#[doc(hidden)]
+/// Lowercased, trimmed view of a [`RequestContext`] used for policy matching.
+///
+/// Build one with [`NormalizedRequest::new`] and pass it to
+/// [`Cors::check_normalized`](crate::Cors::check_normalized) to share a single
+/// normalization across several policies. It keeps the request it was built
+/// from, so the two cannot be paired up wrongly.
 pub struct NormalizedRequest<'a> {
+    request: &'a RequestContext<'a>,
     method: Cow<'a, str>,
     origin: Option<Cow<'a, str>>,
     access_control_request_method: Option<Cow<'a, str>>,
     access_control_request_headers: Option<Cow<'a, str>>,
     access_control_request_private_network: bool,
+    sec_fetch_site: Option<Cow<'a, str>>,
+    host: Option<Cow<'a, str>>,
 }
 
 impl<'a> NormalizedRequest<'a> {
-    #[doc(hidden)]
+    /// Normalizes `request`, borrowing components that are already lowercase.
     pub fn new(request: &'a RequestContext<'a>) -> Self {
         Self {
+            request,
             method: Self::normalize_component(request.method),
             origin: Self::normalize_optional_component(request.origin),
             access_control_request_method: Self::normalize_optional_component(
@@ -107,6 +135,8 @@ impl<'a> NormalizedRequest<'a> {
                 request.access_control_request_headers,
             ),
             access_control_request_private_network: request.access_control_request_private_network,
+            sec_fetch_site: Self::normalize_optional_component(request.sec_fetch_site),
+            host: Self::normalize_optional_component(request.host),
         }
     }
 
@@ -125,7 +155,6 @@ impl<'a> NormalizedRequest<'a> {
                 .position(|byte| byte.is_ascii_uppercase())
             {
                 let mut owned = acquire_buffer(value.len());
-                owned.clear();
                 owned.push_str(value);
                 // SAFETY: `index` lies within the string bounds and `make_ascii_lowercase`
                 // operates in-place without altering the slice length.
@@ -148,7 +177,12 @@ impl<'a> NormalizedRequest<'a> {
         }
     }
 
-    #[doc(hidden)]
+    /// Returns the request this normalization was built from.
+    pub fn request(&self) -> &'a RequestContext<'a> {
+        self.request
+    }
+
+    /// Returns the normalized components as a [`RequestContext`].
     pub fn as_context(&self) -> RequestContext<'_> {
         RequestContext {
             method: self.method.as_ref(),
@@ -162,19 +196,117 @@ impl<'a> NormalizedRequest<'a> {
                 .as_ref()
                 .map(|value| value.as_ref()),
             access_control_request_private_network: self.access_control_request_private_network,
+            sec_fetch_site: self.sec_fetch_site.as_ref().map(|value| value.as_ref()),
+            host: self.host.as_ref().map(|value| value.as_ref()),
         }
     }
 
-    #[doc(hidden)]
+    /// Returns `true` when the normalized method is `options`.
     pub fn is_options(&self) -> bool {
         self.method.as_ref() == "options"
     }
 }
 
+/// Reusable storage for normalizing a stream of requests, such as those on one
+/// connection, without cycling buffers through the shared pool.
+///
+/// Components that need lowercasing are written into these buffers and the
+/// resulting [`NormalizedRequest`] borrows them, so drop it before normalizing
+/// the next request. The requests themselves may be short-lived.
+///
+/// The storage lives here rather than inside a reusable `NormalizedRequest`
+/// because a value that lends out its own buffers cannot also be refilled in
+/// place while those borrows are alive. As a result every component produced by
+/// [`normalize`](Self::normalize) is borrowed, from either these buffers or the
+/// request, whereas [`NormalizedRequest::new`] owns the components it had to
+/// lowercase.
+#[derive(Debug, Default)]
+pub struct NormalizationBuffers {
+    method: String,
+    origin: String,
+    access_control_request_method: String,
+    access_control_request_headers: String,
+    sec_fetch_site: String,
+    host: String,
+}
+
+impl NormalizationBuffers {
+    /// Creates empty buffers; they grow to fit the requests they normalize.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Normalizes `request` like [`NormalizedRequest::new`], reusing these
+    /// buffers for components that need lowercasing.
+    pub fn normalize<'r>(&'r mut self, request: &'r RequestContext<'r>) -> NormalizedRequest<'r> {
+        NormalizedRequest {
+            request,
+            method: normalize_into(&mut self.method, request.method),
+            origin: normalize_optional_into(&mut self.origin, request.origin),
+            access_control_request_method: normalize_optional_into(
+                &mut self.access_control_request_method,
+                request.access_control_request_method,
+            ),
+            access_control_request_headers: normalize_optional_into(
+                &mut self.access_control_request_headers,
+                request.access_control_request_headers,
+            ),
+            access_control_request_private_network: request.access_control_request_private_network,
+            sec_fetch_site: normalize_optional_into(
+                &mut self.sec_fetch_site,
+                request.sec_fetch_site,
+            ),
+            host: normalize_optional_into(&mut self.host, request.host),
+        }
+    }
+}
+
+fn normalize_optional_into<'r>(
+    buffer: &'r mut String,
+    value: Option<&'r str>,
+) -> Option<Cow<'r, str>> {
+    value
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+        .map(|value| normalize_into(buffer, value))
+}
+
+/// Lowercases `value` into `buffer` when it has uppercase characters, borrowing
+/// whichever of the two holds the normalized form.
+fn normalize_into<'r>(buffer: &'r mut String, value: &'r str) -> Cow<'r, str> {
+    let lowered = if value.is_ascii() {
+        match value.bytes().position(|byte| byte.is_ascii_uppercase()) {
+            Some(index) => {
+                buffer.clear();
+                buffer.push_str(value);
+                buffer[index..].make_ascii_lowercase();
+                true
+            }
+            None => false,
+        }
+    } else {
+        lowercase_unicode_into(value, buffer)
+    };
+
+    if lowered {
+        let buffer: &'r String = buffer;
+        Cow::Borrowed(buffer.as_str())
+    } else {
+        Cow::Borrowed(value)
+    }
+}
+
+fn take_owned(target: &mut Cow<'_, str>) -> Option<String> {
+    match mem::replace(target, Cow::Borrowed("")) {
+        Cow::Owned(buffer) => Some(buffer),
+        Cow::Borrowed(_) => None,
+    }
+}
+
 impl<'a> Drop for NormalizedRequest<'a> {
     fn drop(&mut self) {
         fn release<'a>(target: &mut Cow<'a, str>) {
-            if let Cow::Owned(buffer) = mem::replace(target, Cow::Borrowed("")) {
+            if let Some(buffer) = take_owned(target) {
                 release_buffer(buffer);
             }
         }
@@ -189,6 +321,8 @@ impl<'a> Drop for NormalizedRequest<'a> {
         release_optional(&mut self.origin);
         release_optional(&mut self.access_control_request_method);
         release_optional(&mut self.access_control_request_headers);
+        release_optional(&mut self.sec_fetch_site);
+        release_optional(&mut self.host);
     }
 }
 
@@ -14,6 +14,8 @@ fn request(
         access_control_request_method: acrm,
         access_control_request_headers: acrh,
         access_control_request_private_network: false,
+        sec_fetch_site: None,
+        host: None,
     }
 }
 
@@ -61,6 +63,33 @@ mod new {
         ));
     }
 
+    #[test]
+    fn should_own_lowercased_copy_when_components_mixed_case_then_leave_request_untouched() {
+        let ctx = request(
+            "Options",
+            Some("https://Api.Test"),
+            Some("Post"),
+            Some("X-Custom"),
+        );
+
+        let normalized = NormalizedRequest::new(&ctx);
+
+        assert!(matches!(&normalized.method, Cow::Owned(method) if method == "options"));
+        assert!(matches!(
+            &normalized.origin,
+            Some(Cow::Owned(origin)) if origin == "https://api.test"
+        ));
+        assert!(matches!(
+            &normalized.access_control_request_method,
+            Some(Cow::Owned(method)) if method == "post"
+        ));
+        assert!(matches!(
+            &normalized.access_control_request_headers,
+            Some(Cow::Owned(headers)) if headers == "x-custom"
+        ));
+        assert_eq!(ctx.origin, Some("https://Api.Test"));
+    }
+
     #[test]
     fn should_lowercase_unicode_uppercase_origin_then_normalize_non_ascii() {
         let ctx = request(
@@ -112,6 +141,76 @@ mod new {
     }
 }
 
+mod normalization_buffers {
+    use super::*;
+
+    #[test]
+    fn should_borrow_buffers_when_request_has_uppercase_then_normalize_fields() {
+        let upper = request(
+            "OPTIONS",
+            Some("HTTPS://API.TEST"),
+            Some("POST"),
+            Some("X-CUSTOM"),
+        );
+        let mut buffers = NormalizationBuffers::new();
+
+        let normalized = buffers.normalize(&upper);
+
+        assert!(matches!(normalized.method, Cow::Borrowed("options")));
+        assert!(matches!(
+            normalized.origin,
+            Some(Cow::Borrowed("https://api.test"))
+        ));
+        assert!(matches!(
+            normalized.access_control_request_method,
+            Some(Cow::Borrowed("post"))
+        ));
+        assert!(matches!(
+            normalized.access_control_request_headers,
+            Some(Cow::Borrowed("x-custom"))
+        ));
+    }
+
+    #[test]
+    fn should_borrow_request_when_components_are_lowercase_then_leave_buffers_unused() {
+        let lower = request("get", Some("https://api.test"), None, None);
+        let mut buffers = NormalizationBuffers::new();
+
+        let normalized = buffers.normalize(&lower);
+
+        assert!(std::ptr::eq(normalized.method.as_ref(), lower.method));
+        assert!(std::ptr::eq(
+            normalized.origin.as_deref().unwrap(),
+            lower.origin.unwrap()
+        ));
+        assert!(normalized.access_control_request_headers.is_none());
+    }
+
+    #[test]
+    fn should_reuse_buffers_when_requests_created_in_loop_then_match_new() {
+        let mut buffers = NormalizationBuffers::new();
+
+        for index in 0..4 {
+            let origin = format!("HTTPS://APP-{index}.TEST");
+            let ctx = request("GET", None, None, None);
+            let ctx = RequestContext {
+                origin: Some(&origin),
+                ..ctx
+            };
+
+            let normalized = buffers.normalize(&ctx);
+            let expected = NormalizedRequest::new(&ctx);
+
+            assert_eq!(normalized.method, expected.method);
+            assert_eq!(normalized.origin, expected.origin);
+            assert_eq!(
+                normalized.origin.as_deref(),
+                Some(format!("https://app-{index}.test").as_str())
+            );
+        }
+    }
+}
+
 mod normalize_optional_component {
     use super::*;
 
@@ -175,6 +274,8 @@ mod as_context {
             access_control_request_method: Some("POST"),
             access_control_request_headers: Some("X-CUSTOM"),
             access_control_request_private_network: true,
+            sec_fetch_site: None,
+            host: None,
         };
         let normalized = NormalizedRequest::new(&ctx);
 
@@ -182,6 +283,46 @@ mod as_context {
 
         assert!(view.access_control_request_private_network);
     }
+
+    #[test]
+    fn should_lowercase_sec_fetch_site_when_header_present_then_expose_normalized_value() {
+        let ctx = RequestContext {
+            sec_fetch_site: Some(" Same-Origin "),
+            ..request("GET", Some("https://api.test"), None, None)
+        };
+        let normalized = NormalizedRequest::new(&ctx);
+
+        let view = normalized.as_context();
+
+        assert_eq!(view.sec_fetch_site, Some("same-origin"));
+    }
+
+    #[test]
+    fn should_lowercase_host_when_header_present_then_expose_normalized_value() {
+        let ctx = RequestContext {
+            host: Some(" API.Example.test "),
+            ..request("GET", Some("https://api.test"), None, None)
+        };
+        let normalized = NormalizedRequest::new(&ctx);
+
+        let view = normalized.as_context();
+
+        assert_eq!(view.host, Some("api.example.test"));
+    }
+}
+
+mod request {
+    use super::*;
+
+    #[test]
+    fn should_return_source_request_when_built_then_keep_original_spelling() {
+        let ctx = request("GET", Some("HTTPS://API.TEST"), None, None);
+
+        let normalized = NormalizedRequest::new(&ctx);
+
+        assert!(std::ptr::eq(normalized.request(), &ctx));
+        assert_eq!(normalized.request().origin, Some("HTTPS://API.TEST"));
+    }
 }
 
 mod is_options {
@@ -233,6 +374,21 @@ mod pool_instrumentation {
         assert!(stats.max_in_use >= 1);
     }
 
+    #[test]
+    fn should_skip_pool_when_normalizing_into_buffers_then_leave_stats_untouched() {
+        super::normalization_pool_reset();
+        let ctx = request("OPTIONS", Some("HTTPS://ONE.TEST"), None, None);
+        let mut buffers = NormalizationBuffers::new();
+
+        {
+            let normalized = buffers.normalize(&ctx);
+            assert_eq!(normalized.method, "options");
+        }
+
+        assert_eq!(super::normalization_pool_stats(), PoolStats::default());
+    }
+
+    #[cfg(feature = "buffer-pool")]
     #[test]
     fn should_discard_extra_buffers_when_pool_full_then_skip_reinsertion() {
         super::normalization_pool_reset();
@@ -1,15 +1,37 @@
 use crate::allowed_headers::AllowedHeaders;
 use crate::allowed_methods::AllowedMethods;
+use crate::constants::CORS_MANAGED_HEADERS;
 use crate::exposed_headers::ExposedHeaders;
-use crate::origin::Origin;
+use crate::origin::{
+    Origin, OriginCanonicalizerFn, OriginHostMismatchFn, OriginLearningFn, OriginMatcher,
+    PatternError, allow_list_matcher,
+};
 use crate::timing_allow_origin::TimingAllowOrigin;
-use crate::util::is_http_token;
+#[cfg(feature = "url")]
+use crate::util::strip_trailing_slash;
+use crate::util::{is_http_token, normalize_lower};
+use std::borrow::Cow;
+use std::collections::{HashMap, HashSet};
 use std::error::Error;
 use std::fmt::{self, Display};
+use std::sync::Arc;
+
+/// Convenience alias used for checks that replace the built-in HTTP token
+/// validation of method and header names.
+pub type TokenValidatorFn = dyn Fn(&str) -> bool + Send + Sync;
+
+/// Convenience alias used for callbacks that rewrite each requested header
+/// token before it is matched.
+pub type RequestHeaderRewriterFn = dyn Fn(&str) -> Cow<'_, str> + Send + Sync;
+
+/// Convenience alias used for callbacks that observe configuration validation
+/// failures.
+pub type ValidationReporterFn = dyn Fn(&ValidationError) + Send + Sync;
 
 /// Enumerates misconfigurations that prevent a [`CorsOptions`] instance from being
 /// used safely.
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
 pub enum ValidationError {
     /// Credentials can only be enabled when a specific origin is configured.
     CredentialsRequireSpecificOrigin,
@@ -43,6 +65,18 @@ pub enum ValidationError {
     TimingAllowOriginWildcardNotAllowedWithCredentials,
     /// Timing-Allow-Origin lists cannot contain empty values.
     TimingAllowOriginCannotContainEmptyValue,
+    /// Configured exact origins must be valid origin serializations. Only
+    /// reported with the `url` feature.
+    MalformedConfiguredOrigin,
+    /// Extra response headers cannot replace a header managed by the CORS engine.
+    ExtraResponseHeaderConflictsWithCors,
+    /// Max-age strings must be whole seconds, optionally suffixed with `s`, `m`, or `h`.
+    MalformedMaxAge,
+    /// The allowed methods list must name at least one method.
+    AllowedMethodsCannotBeEmpty,
+    /// A `re:` origin entry passed to [`CorsOptions::from_lists`] failed to
+    /// compile; `source` says why.
+    MalformedOriginPattern { source: Arc<PatternError> },
 }
 
 impl Display for ValidationError {
@@ -65,7 +99,7 @@ impl Display for ValidationError {
                     "Exposed headers wildcard (\"*\") can only be used when credentials are disabled.",
                 ),
             ValidationError::ExposeHeadersWildcardCannotBeCombined => f.write_str(
-                "The exposed headers wildcard (\"*\") cannot be combined with additional header names.",
+                "The exposed headers wildcard (\"*\") cannot be combined with additional header names; use ExposedHeaders::any_with instead.",
             ),
             ValidationError::ExposeHeadersListContainsInvalidToken => f.write_str(
                 "Exposed headers lists may only contain valid HTTP header field names.",
@@ -99,11 +133,122 @@ impl Display for ValidationError {
             ValidationError::TimingAllowOriginCannotContainEmptyValue => f.write_str(
                 "Timing-Allow-Origin lists cannot contain empty or whitespace-only entries.",
             ),
+            ValidationError::MalformedConfiguredOrigin => f.write_str(
+                "Configured exact origins must be valid origin serializations such as \"https://app.test\".",
+            ),
+            ValidationError::ExtraResponseHeaderConflictsWithCors => f.write_str(
+                "Extra response headers cannot include headers managed by the CORS engine, such as Access-Control-Allow-Origin or Vary.",
+            ),
+            ValidationError::MalformedMaxAge => f.write_str(
+                "Max-age must be a whole number of seconds, optionally suffixed with \"s\", \"m\", or \"h\" (for example \"90s\" or \"1h\").",
+            ),
+            ValidationError::AllowedMethodsCannotBeEmpty => f.write_str(
+                "Allowed methods lists must contain at least one method; an empty list rejects every preflight.",
+            ),
+            ValidationError::MalformedOriginPattern { source } => write!(
+                f,
+                "Origin entries prefixed with \"re:\" must be valid origin patterns: {}.",
+                source
+            ),
         }
     }
 }
 
-impl Error for ValidationError {}
+impl Error for ValidationError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ValidationError::MalformedOriginPattern { source } => Some(source.as_ref()),
+            _ => None,
+        }
+    }
+}
+
+/// Advisory findings reported by [`CorsOptions::validate_lint`].
+///
+/// Unlike [`ValidationError`], lints never prevent a configuration from being
+/// used; they flag entries that are most likely mistakes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Lint {
+    /// The origin list matcher at `index` follows an `OriginMatcher::Bool(true)`
+    /// entry that already allows every origin.
+    UnreachableOriginMatcher { index: usize },
+    /// The origin list has no matchers, so every origin is rejected.
+    EmptyOriginList,
+    /// The exact origin at `index` repeats an earlier entry, ignoring case.
+    DuplicateExactOrigin { index: usize, origin: String },
+}
+
+impl Display for Lint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Lint::UnreachableOriginMatcher { index } => write!(
+                f,
+                "Origin list entry {} is unreachable because an earlier entry allows every origin.",
+                index
+            ),
+            Lint::EmptyOriginList => {
+                f.write_str("Origin list is empty, so every cross-origin request is rejected.")
+            }
+            Lint::DuplicateExactOrigin { index, origin } => write!(
+                f,
+                "Origin list entry {} duplicates the exact origin \"{}\".",
+                index, origin
+            ),
+        }
+    }
+}
+
+/// Responses that carry `Access-Control-Allow-Credentials` when credentials are enabled.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CredentialsScope {
+    /// Only accepted preflight responses carry the header.
+    PreflightOnly,
+    /// Only accepted simple (actual) responses carry the header.
+    SimpleOnly,
+    /// Both preflight and simple responses carry the header.
+    #[default]
+    Both,
+}
+
+impl CredentialsScope {
+    /// Returns `true` when preflight responses should carry the header.
+    pub fn includes_preflight(self) -> bool {
+        matches!(
+            self,
+            CredentialsScope::PreflightOnly | CredentialsScope::Both
+        )
+    }
+
+    /// Returns `true` when simple responses should carry the header.
+    pub fn includes_simple(self) -> bool {
+        matches!(self, CredentialsScope::SimpleOnly | CredentialsScope::Both)
+    }
+}
+
+/// How an `Origin: null` request is answered when the origin policy allows
+/// any origin.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum NullOriginMode {
+    /// Leaves the answer to the origin policy, so [`Origin::Any`] emits `*`.
+    #[default]
+    Wildcard,
+    /// Reflects the literal `null` instead of `*` and varies on `Origin`.
+    Literal,
+}
+
+/// Plain string lists consumed by [`CorsOptions::from_lists`], typically
+/// deserialized from a configuration file.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CorsLists {
+    /// Allowed origins; entries prefixed with `re:` are compiled as patterns.
+    pub origins: Vec<String>,
+    /// Allowed methods.
+    pub methods: Vec<String>,
+    /// Allowed request headers.
+    pub allowed_headers: Vec<String>,
+    /// Exposed response headers.
+    pub exposed_headers: Vec<String>,
+}
 
 /// Configuration entry point for the CORS engine.
 ///
@@ -123,6 +268,8 @@ pub struct CorsOptions {
     pub exposed_headers: ExposedHeaders,
     /// Enables `Access-Control-Allow-Credentials` when set.
     pub credentials: bool,
+    /// Limits which responses carry `Access-Control-Allow-Credentials`.
+    pub credentials_scope: CredentialsScope,
     /// When present, sets the `Access-Control-Max-Age` header in seconds.
     pub max_age: Option<u64>,
     /// Allows treating the literal `Origin: null` as an allowed origin.
@@ -131,6 +278,79 @@ pub struct CorsOptions {
     pub allow_private_network: bool,
     /// Configures the `Timing-Allow-Origin` header.
     pub timing_allow_origin: Option<TimingAllowOrigin>,
+    /// Upper bound, in bytes, for request headers echoed by
+    /// [`AllowedHeaders::MirrorRequest`] or [`AllowedHeaders::EchoRequest`].
+    /// `None` disables the cap.
+    pub max_reflected_headers_len: Option<usize>,
+    /// Skips `Access-Control-Allow-Methods` on preflights for CORS-safelisted
+    /// methods (`GET`, `HEAD`, `POST`), which browsers allow regardless.
+    pub omit_methods_for_simple: bool,
+    /// Ignores a single trailing `/` on the request origin and on configured
+    /// exact origins when comparing them.
+    pub normalize_trailing_slash: bool,
+    /// Adds `Content-Length: 0` to accepted preflight responses.
+    pub preflight_content_length: bool,
+    /// Rejects every origin that does not use the `https` scheme.
+    pub require_https: bool,
+    /// Origin substituted when the request carries no `Origin` header.
+    pub default_origin_when_missing: Option<String>,
+    /// Maps the request origin onto the form matched against [`CorsOptions::origin`].
+    pub origin_canonicalizer: Option<Arc<OriginCanonicalizerFn>>,
+    /// The server's own origin; matching requests skip CORS processing.
+    pub self_origin: Option<String>,
+    /// Rejects simple requests whose method is not allowed instead of skipping them.
+    pub reject_disallowed_simple_method: bool,
+    /// Accepts only the exact lowercase `Origin: null`, without trimming.
+    pub strict_null_origin: bool,
+    /// Static headers appended to every accepted preflight and simple response.
+    pub extra_response_headers: Vec<(String, String)>,
+    /// Rejects preflights that carry no `Origin` header instead of skipping them.
+    pub require_origin_on_preflight: bool,
+    /// Origins that receive `Access-Control-Allow-Credentials: true`. Requests
+    /// it does not accept fall back to [`CorsOptions::origin`].
+    pub credentialed_origins: Option<Origin>,
+    /// Omits `Access-Control-Max-Age` when the origin resolved to `*`.
+    pub max_age_only_for_exact_origin: bool,
+    /// Disallows requests whose origin and host this check flags as mismatched.
+    pub reject_origin_host_mismatch: Option<Arc<OriginHostMismatchFn>>,
+    /// Emits the `Vary` header on CORS responses.
+    pub emit_vary: bool,
+    /// Reports every failed preflight check instead of only the first.
+    pub aggregate_rejections: bool,
+    /// Maximum number of `Vary` tokens emitted. `None` disables the cap.
+    pub max_vary_values: Option<usize>,
+    /// Replaces the RFC 7230 token check for method and header names.
+    pub token_validator: Option<Arc<TokenValidatorFn>>,
+    /// Cache group whose members, space-separated, replace the reflected origin
+    /// on allowed, non-credentialed responses to a member. Non-standard.
+    pub allow_origin_multi: Option<Vec<String>>,
+    /// Also answers the private-network flag on non-`OPTIONS` requests.
+    pub private_network_on_simple: bool,
+    /// Allows origins the policy would disallow and reports each to this
+    /// callback.
+    pub learning_mode: Option<Arc<OriginLearningFn>>,
+    /// Emits an empty `Access-Control-Allow-Headers` for an empty static list.
+    pub emit_empty_allow_headers: bool,
+    /// Chooses between `*` and the literal `null` for allowed null origins.
+    pub null_origin_mode: NullOriginMode,
+    /// Rewrites each `Access-Control-Request-Headers` token before matching.
+    pub request_header_rewriter: Option<Arc<RequestHeaderRewriterFn>>,
+    /// Reflects the request origin instead of failing when a credentialed
+    /// response would otherwise carry `*`.
+    pub downgrade_wildcard_for_credentials: bool,
+    /// Feeds headers to [`Cors::check_with_sink`](crate::Cors::check_with_sink)
+    /// in a fixed canonical CORS order. Only that sink path is affected.
+    pub deterministic_header_order: bool,
+    /// Strips a default port from the request origin before matching.
+    pub normalize_default_ports: bool,
+    /// Replaces [`CorsOptions::allowed_headers`] for preflights requesting one
+    /// of these methods.
+    pub allowed_headers_by_method: HashMap<String, AllowedHeaders>,
+    /// Rejects an `OPTIONS` with `Origin` but no `Access-Control-Request-Method`
+    /// before any other evaluation.
+    pub strict_preflight: bool,
+    /// Status code carried by preflight and simple rejections.
+    pub rejection_status: u16,
 }
 
 impl Default for CorsOptions {
@@ -141,10 +361,42 @@ impl Default for CorsOptions {
             allowed_headers: AllowedHeaders::default(),
             exposed_headers: ExposedHeaders::default(),
             credentials: false,
+            credentials_scope: CredentialsScope::Both,
             max_age: None,
             allow_null_origin: false,
             allow_private_network: false,
             timing_allow_origin: None,
+            max_reflected_headers_len: None,
+            omit_methods_for_simple: false,
+            normalize_trailing_slash: false,
+            preflight_content_length: false,
+            require_https: false,
+            default_origin_when_missing: None,
+            origin_canonicalizer: None,
+            self_origin: None,
+            reject_disallowed_simple_method: false,
+            strict_null_origin: false,
+            extra_response_headers: Vec::new(),
+            require_origin_on_preflight: false,
+            credentialed_origins: None,
+            max_age_only_for_exact_origin: false,
+            reject_origin_host_mismatch: None,
+            emit_vary: true,
+            aggregate_rejections: false,
+            max_vary_values: None,
+            token_validator: None,
+            allow_origin_multi: None,
+            private_network_on_simple: false,
+            learning_mode: None,
+            emit_empty_allow_headers: false,
+            null_origin_mode: NullOriginMode::Wildcard,
+            request_header_rewriter: None,
+            downgrade_wildcard_for_credentials: false,
+            deterministic_header_order: false,
+            normalize_default_ports: false,
+            allowed_headers_by_method: HashMap::new(),
+            strict_preflight: false,
+            rejection_status: 403,
         }
     }
 }
@@ -155,6 +407,38 @@ impl CorsOptions {
         Self::default()
     }
 
+    /// Builds a validated configuration from [`CorsLists`], leaving every
+    /// other option at its default.
+    ///
+    /// Origins follow the [`Origin::from_lines`] conventions: entries are
+    /// trimmed, empty ones skipped, and `re:` entries compiled with
+    /// [`OriginMatcher::pattern_str`]. The other lists go through
+    /// [`AllowedMethods::list`], [`AllowedHeaders::list`], and
+    /// [`ExposedHeaders::list`].
+    ///
+    /// Returns [`ValidationError::MalformedOriginPattern`] with the
+    /// [`PatternError`] when a pattern fails to compile, or the first error reported by [`CorsOptions::validate`].
+    pub fn from_lists(lists: CorsLists) -> Result<Self, ValidationError> {
+        let origins = lists
+            .origins
+            .iter()
+            .map(|entry| entry.trim())
+            .filter(|entry| !entry.is_empty())
+            .map(allow_list_matcher)
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|source| ValidationError::MalformedOriginPattern {
+                source: Arc::new(source),
+            })?;
+
+        let options = Self::new()
+            .origin(Origin::list(origins))
+            .methods(AllowedMethods::list(lists.methods))
+            .allowed_headers(AllowedHeaders::list(lists.allowed_headers))
+            .exposed_headers(ExposedHeaders::list(lists.exposed_headers));
+        options.validate()?;
+        Ok(options)
+    }
+
     /// Sets the allowed origin policy.
     pub fn origin(mut self, origin: Origin) -> Self {
         self.origin = origin;
@@ -173,6 +457,34 @@ impl CorsOptions {
         self
     }
 
+    /// Sets a separate allowed headers configuration per requested method.
+    ///
+    /// Preflights whose `Access-Control-Request-Method` matches a key (ASCII
+    /// case-insensitive) are checked and answered with that entry; other
+    /// methods fall back to [`CorsOptions::allowed_headers`]. Preflight
+    /// responses then vary on `Access-Control-Request-Method`. Each entry is
+    /// validated like the global configuration.
+    pub fn allowed_headers_by_method(
+        mut self,
+        allowed_headers: HashMap<String, AllowedHeaders>,
+    ) -> Self {
+        self.allowed_headers_by_method = allowed_headers;
+        self
+    }
+
+    /// Returns the [`CorsOptions::allowed_headers_by_method`] entry for
+    /// `method`, if any.
+    pub(crate) fn method_allowed_headers(&self, method: &str) -> Option<&AllowedHeaders> {
+        if self.allowed_headers_by_method.is_empty() {
+            return None;
+        }
+        let method = method.trim();
+        self.allowed_headers_by_method
+            .iter()
+            .find(|(key, _)| key.trim().eq_ignore_ascii_case(method))
+            .map(|(_, allowed_headers)| allowed_headers)
+    }
+
     /// Replaces the exposed headers configuration.
     pub fn exposed_headers(mut self, exposed_headers: ExposedHeaders) -> Self {
         self.exposed_headers = exposed_headers;
@@ -185,13 +497,36 @@ impl CorsOptions {
         self
     }
 
+    /// Restricts `Access-Control-Allow-Credentials` to preflight or simple responses.
+    ///
+    /// Has no effect unless [`CorsOptions::credentials`] is enabled. Validation
+    /// still treats the configuration as credentialed for every request.
+    pub fn credentials_scope(mut self, scope: CredentialsScope) -> Self {
+        self.credentials_scope = scope;
+        self
+    }
+
     /// Sets the `Access-Control-Max-Age` header to the provided number of seconds.
     pub fn max_age(mut self, value: u64) -> Self {
         self.max_age = Some(value);
         self
     }
 
+    /// Parses a max-age such as `"3600"`, `"90s"`, `"10m"`, or `"1h"` and sets
+    /// the `Access-Control-Max-Age` header to the resulting number of seconds.
+    ///
+    /// Returns [`ValidationError::MalformedMaxAge`] when the value is not a
+    /// whole number with an optional `s`, `m`, or `h` suffix, or overflows.
+    pub fn max_age_str(self, value: &str) -> Result<Self, ValidationError> {
+        let seconds = parse_max_age(value).ok_or(ValidationError::MalformedMaxAge)?;
+        Ok(self.max_age(seconds))
+    }
+
     /// Grants or revokes support for `Origin: null` requests.
+    ///
+    /// With [`CorsOptions::credentials`] enabled, admitted `null` origins are
+    /// answered with the literal `null` and credentials even when the origin
+    /// list does not include them.
     pub fn allow_null_origin(mut self, enabled: bool) -> Self {
         self.allow_null_origin = enabled;
         self
@@ -209,6 +544,443 @@ impl CorsOptions {
         self
     }
 
+    /// Caps the length of request headers reflected by
+    /// [`AllowedHeaders::MirrorRequest`] or [`AllowedHeaders::EchoRequest`];
+    /// longer values reject the preflight.
+    pub fn max_reflected_headers_len(mut self, value: usize) -> Self {
+        self.max_reflected_headers_len = Some(value);
+        self
+    }
+
+    /// Omits `Access-Control-Allow-Methods` when the preflight requests a
+    /// CORS-safelisted method.
+    pub fn omit_methods_for_simple(mut self, enabled: bool) -> Self {
+        self.omit_methods_for_simple = enabled;
+        self
+    }
+
+    /// Treats `https://app.test/` and `https://app.test` as the same origin.
+    ///
+    /// Both the request origin and configured exact origins (including exact
+    /// entries of [`Origin::list`]) are compared with one trailing slash removed.
+    /// Patterns, predicates, and callbacks see the stripped request origin.
+    /// Reflected `Access-Control-Allow-Origin` values always use the canonical,
+    /// slash-free form.
+    pub fn normalize_trailing_slash(mut self, enabled: bool) -> Self {
+        self.normalize_trailing_slash = enabled;
+        self
+    }
+
+    /// Includes `Content-Length: 0` in accepted preflight headers.
+    ///
+    /// Preflight responses never carry a body, but some frameworks omit the
+    /// length and leave keep-alive clients waiting. Enable this to have the
+    /// header applied together with the CORS headers.
+    pub fn set_preflight_content_length(mut self, enabled: bool) -> Self {
+        self.preflight_content_length = enabled;
+        self
+    }
+
+    /// Disallows any origin that does not start with `https://`, regardless of
+    /// the configured [`Origin`] policy (including [`Origin::Any`]).
+    ///
+    /// The check runs before the policy is consulted. `Origin: null` is left to
+    /// the usual null handling, and requests without an origin are unaffected.
+    pub fn require_https(mut self, enabled: bool) -> Self {
+        self.require_https = enabled;
+        self
+    }
+
+    /// Treats requests without an `Origin` header as if they came from `origin`.
+    ///
+    /// The value is substituted before the origin policy runs, so originless
+    /// requests are matched, reflected, and rejected exactly like requests that
+    /// sent it. Requests with an empty `Origin` header are left untouched.
+    pub fn default_origin_when_missing<S: Into<String>>(mut self, origin: S) -> Self {
+        self.default_origin_when_missing = Some(origin.into());
+        self
+    }
+
+    /// Rewrites the request origin before it is matched against the policy.
+    ///
+    /// Use this to treat several origins as one, e.g. mapping regional hosts
+    /// onto a single canonical list entry. The canonicalizer receives the
+    /// lowercased request origin (after [`CorsOptions::normalize_trailing_slash`]
+    /// when enabled). Matching uses the returned value, while
+    /// `Access-Control-Allow-Origin` reflects the origin the request actually
+    /// sent so browsers accept the response. Return [`Cow::Borrowed`](std::borrow::Cow::Borrowed) to leave
+    /// an origin unchanged.
+    pub fn origin_canonicalizer(mut self, canonicalizer: Arc<OriginCanonicalizerFn>) -> Self {
+        self.origin_canonicalizer = Some(canonicalizer);
+        self
+    }
+
+    /// Declares the origin the server itself is served from.
+    ///
+    /// Requests whose `Origin` equals it (case-insensitive) are same-origin, so
+    /// [`Cors::check`](crate::Cors::check) returns
+    /// [`CorsDecision::NotApplicable`](crate::CorsDecision::NotApplicable)
+    /// without evaluating the policy or emitting headers.
+    pub fn self_origin<S: Into<String>>(mut self, origin: S) -> Self {
+        self.self_origin = Some(origin.into());
+        self
+    }
+
+    /// Turns simple requests with a method outside [`CorsOptions::methods`] into
+    /// [`SimpleRejectionReason::MethodNotAllowed`](crate::SimpleRejectionReason::MethodNotAllowed)
+    /// rejections carrying the method, e.g. to answer with `405`.
+    ///
+    /// By default such requests yield
+    /// [`CorsDecision::NotApplicable`](crate::CorsDecision::NotApplicable).
+    pub fn reject_disallowed_simple_method(mut self, enabled: bool) -> Self {
+        self.reject_disallowed_simple_method = enabled;
+        self
+    }
+
+    /// Requires the opaque origin to arrive as exactly `null`.
+    ///
+    /// By default the request origin is trimmed and lowercased, so `Null` and
+    /// ` null ` are treated as `null`. With this enabled such variants are
+    /// rejected even when [`CorsOptions::allow_null_origin`] or
+    /// [`OriginMatcher::null`](crate::OriginMatcher::null) would accept `null`.
+    pub fn strict_null_origin(mut self, enabled: bool) -> Self {
+        self.strict_null_origin = enabled;
+        self
+    }
+
+    /// Appends static headers, such as `("X-CORS-Handled", "bunner")`, to every
+    /// accepted preflight and simple response. Rejections never carry them.
+    ///
+    /// Names that collide with a header the engine manages are rejected by
+    /// [`CorsOptions::validate`].
+    pub fn extra_response_headers<I, K, V>(mut self, headers: I) -> Self
+    where
+        I: IntoIterator<Item = (K, V)>,
+        K: Into<String>,
+        V: Into<String>,
+    {
+        self.extra_response_headers = headers
+            .into_iter()
+            .map(|(name, value)| (name.into(), value.into()))
+            .collect();
+        self
+    }
+
+    /// Turns preflights that carry `Access-Control-Request-Method` but no
+    /// `Origin` into [`PreflightRejectionReason::OriginMissing`](crate::PreflightRejectionReason::OriginMissing)
+    /// rejections.
+    ///
+    /// By default such requests yield
+    /// [`CorsDecision::NotApplicable`](crate::CorsDecision::NotApplicable).
+    pub fn require_origin_on_preflight(mut self, enabled: bool) -> Self {
+        self.require_origin_on_preflight = enabled;
+        self
+    }
+
+    /// Configures a second origin policy whose matches are answered with
+    /// `Access-Control-Allow-Credentials: true`.
+    ///
+    /// The credentialed policy is consulted first. Origins it does not accept
+    /// fall back to [`CorsOptions::origin`], which emits credentials only when
+    /// [`CorsOptions::credentials`] is enabled. Validation applies the
+    /// credentialed rules, so `Origin::Any` and wildcard headers are rejected.
+    pub fn credentialed_origins(mut self, origin: Origin) -> Self {
+        self.credentialed_origins = Some(origin);
+        self
+    }
+
+    /// Suppresses `Access-Control-Max-Age` on preflights answered with
+    /// `Access-Control-Allow-Origin: *`, so browsers only cache preflights for
+    /// origins the policy matched explicitly.
+    pub fn max_age_only_for_exact_origin(mut self, enabled: bool) -> Self {
+        self.max_age_only_for_exact_origin = enabled;
+        self
+    }
+
+    /// Installs a defense-in-depth check comparing the request origin against
+    /// [`RequestContext::host`](crate::RequestContext::host).
+    ///
+    /// The check receives the lowercased origin and host and runs only when
+    /// both are present. Returning `true` disallows the origin exactly as if the
+    /// origin policy had rejected it.
+    pub fn reject_origin_host_mismatch(mut self, check: Arc<OriginHostMismatchFn>) -> Self {
+        self.reject_origin_host_mismatch = Some(check);
+        self
+    }
+
+    /// Enables or disables the `Vary` header on every CORS response.
+    ///
+    /// Disable this only when a proxy or CDN computes `Vary` itself; caches
+    /// that honour our headers would otherwise mix responses across origins.
+    /// Origin decisions are unaffected.
+    pub fn emit_vary(mut self, enabled: bool) -> Self {
+        self.emit_vary = enabled;
+        self
+    }
+
+    /// Reports all failed preflight checks at once.
+    ///
+    /// When both the requested method and headers are rejected, the rejection
+    /// reason becomes [`PreflightRejectionReason::Multiple`](crate::PreflightRejectionReason::Multiple)
+    /// listing each failure. A single failure is still reported on its own.
+    pub fn aggregate_rejections(mut self, enabled: bool) -> Self {
+        self.aggregate_rejections = enabled;
+        self
+    }
+
+    /// Caps the number of `Vary` tokens on every CORS response.
+    ///
+    /// When exceeded, the tokens are sorted case-insensitively and only the
+    /// first `value` are kept. The engine emits at most a few tokens, so this
+    /// mainly guards configurations that seed additional ones.
+    pub fn max_vary_values(mut self, value: usize) -> Self {
+        self.max_vary_values = Some(value);
+        self
+    }
+
+    /// Replaces the RFC 7230 token check applied to the method, allowed header,
+    /// and exposed header lists, and to the method requested by a preflight.
+    ///
+    /// Use this only for legacy backends whose names fall outside the token
+    /// grammar; the validator is trusted as-is, so accepting separators or
+    /// whitespace can produce header values that clients fail to parse. Empty
+    /// entries and `*` are still rejected.
+    pub fn token_validator(mut self, validator: Arc<TokenValidatorFn>) -> Self {
+        self.token_validator = Some(validator);
+        self
+    }
+
+    /// Makes allowed responses to a member of `group` carry every member,
+    /// space-separated, instead of reflecting the request origin.
+    ///
+    /// This is **not** standard CORS: browsers reject an
+    /// `Access-Control-Allow-Origin` value holding more than one origin. Use it
+    /// only behind a cache that splits the value and answers each origin
+    /// itself. Every response to a member discloses the whole group, so list
+    /// only origins that may learn of each other. The group is separate from
+    /// [`CorsOptions::origin`]: a member must still be allowed by the policy,
+    /// and origins outside the group, the `null` origin, and credentialed
+    /// responses keep the single reflected value.
+    pub fn allow_origin_multi<I, S>(mut self, group: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.allow_origin_multi = Some(group.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// Emits `Access-Control-Allow-Private-Network: true` on accepted simple
+    /// responses whose request carried the private-network flag.
+    ///
+    /// Only takes effect together with [`CorsOptions::allow_private_network`].
+    /// By default the header is limited to preflights.
+    pub fn private_network_on_simple(mut self, enabled: bool) -> Self {
+        self.private_network_on_simple = enabled;
+        self
+    }
+
+    /// Turns on learning mode for migrations: origins the policy would disallow
+    /// are mirrored instead, and `record` receives each such origin as sent.
+    ///
+    /// Origins the policy already allows never reach `record`. The callback
+    /// runs on every admitted request, so deduplicate inside it. Learning mode
+    /// effectively allows every origin, with credentials when they are enabled;
+    /// never leave it on in production.
+    pub fn learning_mode(mut self, record: Arc<OriginLearningFn>) -> Self {
+        self.learning_mode = Some(record);
+        self
+    }
+
+    /// Emits `Access-Control-Allow-Headers` with an empty value on preflights
+    /// when the static allow-list is empty, signalling explicitly that no
+    /// request headers are allowed.
+    ///
+    /// Preflights requesting any header are still rejected. By default the
+    /// header is omitted for an empty list.
+    pub fn emit_empty_allow_headers(mut self, enabled: bool) -> Self {
+        self.emit_empty_allow_headers = enabled;
+        self
+    }
+
+    /// Chooses how `Origin: null` requests admitted by
+    /// [`CorsOptions::allow_null_origin`] are answered.
+    ///
+    /// Without credentials the origin policy still decides whether `null` is
+    /// allowed; the mode only applies when it answers with `*`, which
+    /// [`NullOriginMode::Literal`] replaces with the literal `null`. Policies
+    /// that reflect the origin, such as a list containing
+    /// [`OriginMatcher::null`], already emit `null`. With
+    /// [`CorsOptions::credentials`] on, a list or exact policy that rejects
+    /// `null` is overridden and the literal is reflected with credentials in
+    /// either mode.
+    pub fn null_origin_mode(mut self, mode: NullOriginMode) -> Self {
+        self.null_origin_mode = mode;
+        self
+    }
+
+    /// Rewrites each token of `Access-Control-Request-Headers` before it is
+    /// compared with the allow-list and before it is reflected, for example to
+    /// strip a vendor prefix.
+    ///
+    /// Tokens rewritten to an empty string are dropped. Requests whose tokens
+    /// are all returned borrowed keep their original header value.
+    pub fn request_header_rewriter(mut self, rewrite: Arc<RequestHeaderRewriterFn>) -> Self {
+        self.request_header_rewriter = Some(rewrite);
+        self
+    }
+
+    /// Reflects the request origin when an origin policy answers
+    /// [`OriginDecision::Any`](crate::OriginDecision::Any) for a credentialed
+    /// response, instead of failing with
+    /// [`CorsError::InvalidOriginAnyWithCredentials`](crate::CorsError::InvalidOriginAnyWithCredentials).
+    ///
+    /// Only custom origin callbacks can answer `Any` here; a static
+    /// [`Origin::Any`] combined with credentials is still rejected by
+    /// [`CorsOptions::validate`].
+    pub fn downgrade_wildcard_for_credentials(mut self, enabled: bool) -> Self {
+        self.downgrade_wildcard_for_credentials = enabled;
+        self
+    }
+
+    /// Makes [`Cors::check_with_sink`](crate::Cors::check_with_sink) push
+    /// headers in a fixed canonical order: `Vary`, `Allow-Origin`,
+    /// `Allow-Credentials`, `Allow-Methods`, `Allow-Headers`, `Max-Age`,
+    /// `Expose-Headers`, `Allow-Private-Network`, `Timing-Allow-Origin`, then
+    /// any other header by name. Useful for snapshot tests across versions.
+    ///
+    /// This only changes what the sink receives; by default it gets `Vary`
+    /// first and the rest sorted by name. [`Headers`](crate::Headers) is a
+    /// hash map with no order of its own, so the headers of a
+    /// [`CorsDecision`](crate::CorsDecision) from [`Cors::check`](crate::Cors::check),
+    /// as well as [`HeadersExt::to_http_block`](crate::HeadersExt::to_http_block)
+    /// and [`PreflightRejection::to_response_parts`](crate::PreflightRejection::to_response_parts),
+    /// are unaffected.
+    pub fn deterministic_header_order(mut self, enabled: bool) -> Self {
+        self.deterministic_header_order = enabled;
+        self
+    }
+
+    /// Treats `https://app.test:443` as `https://app.test` (and `:80` on
+    /// `http`) when matching, so portless patterns and exact origins accept
+    /// origins that spell out the default port.
+    ///
+    /// Only the value used for matching is rewritten; write configured origins
+    /// without default ports. Reflected origins keep the spelling the request
+    /// sent.
+    pub fn normalize_default_ports(mut self, enabled: bool) -> Self {
+        self.normalize_default_ports = enabled;
+        self
+    }
+
+    /// Requires `Access-Control-Request-Method` on every `OPTIONS` request that
+    /// carries `Origin`.
+    ///
+    /// By default an `OPTIONS` request without a non-empty
+    /// `Access-Control-Request-Method` yields
+    /// [`CorsDecision::NotApplicable`](crate::CorsDecision::NotApplicable)
+    /// whether or not it names an origin. With this enabled, one that carries
+    /// `Origin` is a malformed preflight and is rejected with
+    /// [`PreflightRejectionReason::RequestMethodMissing`](crate::PreflightRejectionReason::RequestMethodMissing),
+    /// while a bare `OPTIONS` without `Origin` stays `NotApplicable`. Both are
+    /// decided before [`CorsOptions::default_origin_when_missing`],
+    /// [`CorsOptions::self_origin`], and
+    /// [`CorsOptions::request_header_rewriter`] are consulted, so no callback
+    /// runs and no CORS header is emitted.
+    pub fn strict_preflight(mut self, enabled: bool) -> Self {
+        self.strict_preflight = enabled;
+        self
+    }
+
+    /// Sets the status code rejections carry, `403 Forbidden` by default.
+    ///
+    /// It is stored on every [`PreflightRejection`](crate::PreflightRejection)
+    /// and [`SimpleRejection`](crate::SimpleRejection) and returned by their
+    /// `to_response_parts`, so frameworks answer rejected requests with one
+    /// configured status.
+    pub fn rejection_status(mut self, status: u16) -> Self {
+        self.rejection_status = status;
+        self
+    }
+
+    /// Returns the requested header list with
+    /// [`CorsOptions::request_header_rewriter`] applied, or `None` when no
+    /// token changed.
+    pub(crate) fn rewrite_requested_headers(&self, requested: &str) -> Option<String> {
+        let rewrite = self.request_header_rewriter.as_ref()?;
+        let mut changed = false;
+        let mut tokens = Vec::new();
+        for token in requested.split(',').map(str::trim) {
+            if token.is_empty() {
+                continue;
+            }
+            let rewritten = rewrite(token);
+            changed |= matches!(rewritten, Cow::Owned(_));
+            if !rewritten.trim().is_empty() {
+                tokens.push(rewritten.trim().to_string());
+            }
+        }
+        changed.then(|| tokens.join(", "))
+    }
+
+    /// Checks `value` with [`CorsOptions::token_validator`], falling back to the
+    /// RFC 7230 token grammar.
+    pub(crate) fn is_token(&self, value: &str) -> bool {
+        match &self.token_validator {
+            Some(validator) => validator(value),
+            None => is_http_token(value),
+        }
+    }
+
+    /// Applies evaluation-time rewrites that depend on option flags, such as
+    /// stripping trailing slashes from configured exact origins.
+    pub(crate) fn prepared(mut self) -> Self {
+        if self.normalize_trailing_slash {
+            self.origin = self.origin.without_trailing_slashes();
+            self.credentialed_origins = self
+                .credentialed_origins
+                .map(|origin| origin.without_trailing_slashes());
+        }
+        self
+    }
+
+    /// Reports advisory findings about the configuration without failing.
+    ///
+    /// The returned lints cover origin list entries shadowed by an allow-all
+    /// matcher, empty origin lists, and duplicated exact origins. Run
+    /// [`CorsOptions::validate`] for hard errors.
+    pub fn validate_lint(&self) -> Vec<Lint> {
+        let mut lints = Vec::new();
+        let Origin::List(list) = &self.origin else {
+            return lints;
+        };
+
+        if list.is_empty() {
+            lints.push(Lint::EmptyOriginList);
+            return lints;
+        }
+
+        let mut allow_all_seen = false;
+        let mut seen_exact = HashSet::new();
+        for (index, matcher) in list.iter().enumerate() {
+            if allow_all_seen {
+                lints.push(Lint::UnreachableOriginMatcher { index });
+            }
+            match matcher {
+                OriginMatcher::Bool(true) => allow_all_seen = true,
+                OriginMatcher::Exact(value) if !seen_exact.insert(normalize_lower(value)) => {
+                    lints.push(Lint::DuplicateExactOrigin {
+                        index,
+                        origin: value.clone(),
+                    });
+                }
+                _ => {}
+            }
+        }
+
+        lints
+    }
+
     /// Ensures the configuration adheres to the CORS specification.
     ///
     /// The validation focuses on combinations that would otherwise produce
@@ -221,17 +993,21 @@ impl CorsOptions {
             }
             return Err(ValidationError::CredentialsRequireSpecificOrigin);
         }
-
-        if self.credentials && matches!(self.allowed_headers, AllowedHeaders::Any) {
-            return Err(ValidationError::AllowedHeadersAnyNotAllowedWithCredentials);
+        if matches!(self.credentialed_origins, Some(Origin::Any)) {
+            return Err(ValidationError::CredentialsRequireSpecificOrigin);
         }
 
-        if let AllowedHeaders::List(values) = &self.allowed_headers
-            && values.iter().any(|value| value == "*")
-        {
-            return Err(ValidationError::AllowedHeadersListCannotContainWildcard);
+        // A credentialed origin set makes some responses credentialed.
+        let credentials = self.credentials || self.credentialed_origins.is_some();
+
+        self.validate_allowed_headers(&self.allowed_headers, credentials)?;
+        for allowed_headers in self.allowed_headers_by_method.values() {
+            self.validate_allowed_headers(allowed_headers, credentials)?;
         }
 
+        if self.methods.is_empty() {
+            return Err(ValidationError::AllowedMethodsCannotBeEmpty);
+        }
         if self.methods.iter().any(|value| value.trim().is_empty()) {
             return Err(ValidationError::AllowedMethodsCannotContainEmptyToken);
         }
@@ -242,33 +1018,26 @@ impl CorsOptions {
             .methods
             .iter()
             .map(|value| value.trim())
-            .any(|value| !is_http_token(value))
+            .any(|value| !self.is_token(value))
         {
             return Err(ValidationError::AllowedMethodsListContainsInvalidToken);
         }
 
-        if let AllowedHeaders::List(values) = &self.allowed_headers
-            && values.iter().any(|value| value.trim().is_empty())
-        {
-            return Err(ValidationError::AllowedHeadersCannotContainEmptyToken);
-        }
-
-        if let AllowedHeaders::List(values) = &self.allowed_headers
-            && values
-                .iter()
-                .map(|value| value.trim())
-                .any(|value| !is_http_token(value))
-        {
-            return Err(ValidationError::AllowedHeadersListContainsInvalidToken);
-        }
-
         match &self.exposed_headers {
             ExposedHeaders::Any => {
-                if self.credentials {
+                if credentials {
                     return Err(ValidationError::ExposeHeadersWildcardRequiresCredentialsDisabled);
                 }
             }
-            ExposedHeaders::List(values) => {
+            ExposedHeaders::List(values)
+            | ExposedHeaders::AnyOr(values)
+            | ExposedHeaders::AnyWith(values) => {
+                // `AnyWith` emits `*`, which browsers take literally on
+                // credentialed responses.
+                if credentials && matches!(self.exposed_headers, ExposedHeaders::AnyWith(_)) {
+                    return Err(ValidationError::ExposeHeadersWildcardRequiresCredentialsDisabled);
+                }
+
                 if values.values().iter().any(|value| value.trim().is_empty()) {
                     return Err(ValidationError::ExposeHeadersCannotContainEmptyValue);
                 }
@@ -277,7 +1046,7 @@ impl CorsOptions {
                     .values()
                     .iter()
                     .map(|value| value.trim())
-                    .any(|value| !is_http_token(value))
+                    .any(|value| !self.is_token(value))
                 {
                     return Err(ValidationError::ExposeHeadersListContainsInvalidToken);
                 }
@@ -288,11 +1057,11 @@ impl CorsOptions {
             }
         }
 
-        if self.allow_private_network && !self.credentials {
+        if self.allow_private_network && !credentials {
             return Err(ValidationError::PrivateNetworkRequiresCredentials);
         }
 
-        if self.credentials && matches!(self.timing_allow_origin, Some(TimingAllowOrigin::Any)) {
+        if credentials && matches!(self.timing_allow_origin, Some(TimingAllowOrigin::Any)) {
             return Err(ValidationError::TimingAllowOriginWildcardNotAllowedWithCredentials);
         }
 
@@ -302,8 +1071,99 @@ impl CorsOptions {
             return Err(ValidationError::TimingAllowOriginCannotContainEmptyValue);
         }
 
+        if self.extra_response_headers.iter().any(|(name, _)| {
+            CORS_MANAGED_HEADERS
+                .iter()
+                .any(|managed| managed.eq_ignore_ascii_case(name.trim()))
+        }) {
+            return Err(ValidationError::ExtraResponseHeaderConflictsWithCors);
+        }
+
+        #[cfg(feature = "url")]
+        if !self.configured_origins_are_well_formed() {
+            return Err(ValidationError::MalformedConfiguredOrigin);
+        }
+
+        Ok(())
+    }
+
+    /// Checks an allowed headers configuration, either
+    /// [`CorsOptions::allowed_headers`] or an entry of
+    /// [`CorsOptions::allowed_headers_by_method`].
+    fn validate_allowed_headers(
+        &self,
+        allowed_headers: &AllowedHeaders,
+        credentials: bool,
+    ) -> Result<(), ValidationError> {
+        let values = match allowed_headers {
+            AllowedHeaders::Any if credentials => {
+                return Err(ValidationError::AllowedHeadersAnyNotAllowedWithCredentials);
+            }
+            AllowedHeaders::List(values) | AllowedHeaders::ListEchoCase(values) => values,
+            _ => return Ok(()),
+        };
+        if values.iter().any(|value| value == "*") {
+            return Err(ValidationError::AllowedHeadersListCannotContainWildcard);
+        }
+        if values.iter().any(|value| value.trim().is_empty()) {
+            return Err(ValidationError::AllowedHeadersCannotContainEmptyToken);
+        }
+        if values
+            .iter()
+            .map(|value| value.trim())
+            .any(|value| !self.is_token(value))
+        {
+            return Err(ValidationError::AllowedHeadersListContainsInvalidToken);
+        }
         Ok(())
     }
+
+    #[cfg(feature = "url")]
+    fn configured_origins_are_well_formed(&self) -> bool {
+        let is_well_formed = |value: &str| {
+            let value = if self.normalize_trailing_slash {
+                strip_trailing_slash(value)
+            } else {
+                value
+            };
+            value.eq_ignore_ascii_case(crate::origin::NULL_ORIGIN)
+                || url::Url::parse(value).is_ok_and(|parsed| {
+                    parsed
+                        .origin()
+                        .ascii_serialization()
+                        .eq_ignore_ascii_case(value)
+                })
+        };
+
+        let policy_is_well_formed = |origin: &Origin| match origin {
+            Origin::Exact(value) => is_well_formed(value),
+            Origin::List(list) => list.iter().all(|matcher| match matcher {
+                OriginMatcher::Exact(value) => is_well_formed(value),
+                _ => true,
+            }),
+            _ => true,
+        };
+
+        policy_is_well_formed(&self.origin)
+            && self
+                .credentialed_origins
+                .as_ref()
+                .is_none_or(policy_is_well_formed)
+    }
+}
+
+fn parse_max_age(value: &str) -> Option<u64> {
+    let value = value.trim();
+    let (digits, multiplier) = match value.as_bytes().last()? {
+        b's' | b'S' => (&value[..value.len() - 1], 1),
+        b'm' | b'M' => (&value[..value.len() - 1], 60),
+        b'h' | b'H' => (&value[..value.len() - 1], 3_600),
+        _ => (value, 1),
+    };
+    if digits.is_empty() || !digits.bytes().all(|byte| byte.is_ascii_digit()) {
+        return None;
+    }
+    digits.parse::<u64>().ok()?.checked_mul(multiplier)
 }
 
 #[cfg(test)]
@@ -24,6 +24,37 @@ mod default {
         assert!(!options.allow_null_origin);
         assert!(!options.allow_private_network);
         assert!(options.timing_allow_origin.is_none());
+        assert!(options.max_reflected_headers_len.is_none());
+        assert!(!options.omit_methods_for_simple);
+        assert!(!options.normalize_trailing_slash);
+        assert!(!options.preflight_content_length);
+        assert!(!options.require_https);
+        assert!(options.default_origin_when_missing.is_none());
+        assert!(options.origin_canonicalizer.is_none());
+        assert!(options.self_origin.is_none());
+        assert!(!options.reject_disallowed_simple_method);
+        assert_eq!(options.credentials_scope, CredentialsScope::Both);
+        assert!(!options.strict_null_origin);
+        assert!(options.extra_response_headers.is_empty());
+        assert!(!options.require_origin_on_preflight);
+        assert!(options.credentialed_origins.is_none());
+        assert!(!options.max_age_only_for_exact_origin);
+        assert!(options.reject_origin_host_mismatch.is_none());
+        assert!(options.emit_vary);
+        assert!(!options.aggregate_rejections);
+        assert!(options.max_vary_values.is_none());
+        assert!(options.token_validator.is_none());
+        assert!(options.allow_origin_multi.is_none());
+        assert!(!options.private_network_on_simple);
+        assert!(options.learning_mode.is_none());
+        assert!(!options.emit_empty_allow_headers);
+        assert_eq!(options.null_origin_mode, NullOriginMode::Wildcard);
+        assert!(options.request_header_rewriter.is_none());
+        assert!(!options.downgrade_wildcard_for_credentials);
+        assert!(!options.deterministic_header_order);
+        assert!(!options.normalize_default_ports);
+        assert!(options.allowed_headers_by_method.is_empty());
+        assert!(!options.strict_preflight);
     }
 
     #[test]
@@ -41,7 +72,7 @@ mod display {
 
     #[test]
     fn given_validation_errors_when_display_called_then_mentions_context() {
-        let cases: [(ValidationError, &str); 16] = [
+        let cases: [(ValidationError, &str); 21] = [
             (
                 ValidationError::CredentialsRequireSpecificOrigin,
                 "specific allowed origin",
@@ -106,6 +137,27 @@ mod display {
                 ValidationError::TimingAllowOriginCannotContainEmptyValue,
                 "cannot contain empty",
             ),
+            (
+                ValidationError::MalformedConfiguredOrigin,
+                "valid origin serializations",
+            ),
+            (
+                ValidationError::ExtraResponseHeaderConflictsWithCors,
+                "managed by the CORS engine",
+            ),
+            (ValidationError::MalformedMaxAge, "whole number of seconds"),
+            (
+                ValidationError::AllowedMethodsCannotBeEmpty,
+                "at least one method",
+            ),
+            (
+                ValidationError::MalformedOriginPattern {
+                    source: Arc::new(PatternError::Unsupported {
+                        pattern: "(".into(),
+                    }),
+                },
+                "valid origin patterns: origin pattern `(`",
+            ),
         ];
 
         for (error, phrase) in cases {
@@ -114,9 +166,229 @@ mod display {
     }
 }
 
+mod max_age_str {
+    use super::*;
+
+    #[test]
+    fn given_hour_suffix_when_max_age_str_called_then_sets_seconds() {
+        let options = CorsOptions::new().max_age_str("1h").unwrap();
+
+        assert_eq!(options.max_age, Some(3600));
+    }
+
+    #[test]
+    fn given_second_suffix_when_max_age_str_called_then_sets_seconds() {
+        let options = CorsOptions::new().max_age_str("90s").unwrap();
+
+        assert_eq!(options.max_age, Some(90));
+    }
+
+    #[test]
+    fn given_minute_suffix_or_bare_number_when_max_age_str_called_then_sets_seconds() {
+        assert_eq!(
+            CorsOptions::new().max_age_str("10m").unwrap().max_age,
+            Some(600)
+        );
+        assert_eq!(
+            CorsOptions::new().max_age_str(" 3600 ").unwrap().max_age,
+            Some(3600)
+        );
+    }
+
+    #[test]
+    fn given_invalid_values_when_max_age_str_called_then_returns_malformed_error() {
+        for value in [
+            "abc",
+            "",
+            "h",
+            "1.5h",
+            "-5s",
+            "10d",
+            "1h30m",
+            "99999999999999999999h",
+        ] {
+            let result = CorsOptions::new().max_age_str(value);
+
+            assert!(
+                matches!(result, Err(ValidationError::MalformedMaxAge)),
+                "{value:?} should be rejected"
+            );
+        }
+    }
+}
+
+mod rewrite_requested_headers {
+    use super::*;
+    use std::borrow::Cow;
+
+    fn rewriting_options() -> CorsOptions {
+        CorsOptions::new().request_header_rewriter(Arc::new(|token: &str| {
+            match token.strip_prefix("x-vendor-") {
+                Some("drop") => Cow::Owned(String::new()),
+                Some(rest) => Cow::Owned(rest.to_string()),
+                None => Cow::Borrowed(token),
+            }
+        }))
+    }
+
+    #[test]
+    fn given_prefixed_tokens_when_rewritten_then_joins_rewritten_and_drops_empty_tokens() {
+        let options = rewriting_options();
+
+        let rewritten = options.rewrite_requested_headers("x-vendor-x-test, x-vendor-drop,,accept");
+
+        assert_eq!(rewritten.as_deref(), Some("x-test, accept"));
+    }
+
+    #[test]
+    fn given_unchanged_tokens_when_rewritten_then_returns_none() {
+        let options = rewriting_options();
+
+        assert!(
+            options
+                .rewrite_requested_headers("x-test, accept")
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn given_no_rewriter_when_rewritten_then_returns_none() {
+        assert!(
+            CorsOptions::new()
+                .rewrite_requested_headers("x-vendor-x-test")
+                .is_none()
+        );
+    }
+}
+
+mod from_lists {
+    use super::*;
+
+    fn strings(values: &[&str]) -> Vec<String> {
+        values.iter().map(|value| value.to_string()).collect()
+    }
+
+    #[cfg(feature = "regex")]
+    #[test]
+    fn given_full_lists_when_from_lists_called_then_populates_each_field() {
+        let options = CorsOptions::from_lists(CorsLists {
+            origins: strings(&["https://app.test", " ", r"re:^https://.*\.preview\.test$"]),
+            methods: strings(&["GET", "POST"]),
+            allowed_headers: strings(&["X-Test"]),
+            exposed_headers: strings(&["X-Trace"]),
+        })
+        .unwrap();
+
+        assert!(matches!(&options.origin, Origin::List(list) if list.len() == 2));
+        assert_eq!(options.methods, AllowedMethods::list(["GET", "POST"]));
+        assert!(options.allowed_headers == AllowedHeaders::list(["X-Test"]));
+        assert_eq!(options.exposed_headers, ExposedHeaders::list(["X-Trace"]));
+        assert!(!options.credentials);
+    }
+
+    #[test]
+    fn given_invalid_origin_pattern_when_from_lists_called_then_returns_malformed_pattern_error() {
+        let result = CorsOptions::from_lists(CorsLists {
+            origins: strings(&["https://app.test", "re:("]),
+            methods: strings(&["GET"]),
+            ..CorsLists::default()
+        });
+
+        let error = result.err().expect("malformed pattern should be rejected");
+        let source = error.source().expect("pattern error is kept as the source");
+
+        assert!(matches!(
+            error,
+            ValidationError::MalformedOriginPattern { .. }
+        ));
+        assert!(source.to_string().contains("origin pattern `(`"));
+    }
+
+    #[test]
+    fn given_empty_methods_when_from_lists_called_then_returns_validation_error() {
+        let result = CorsOptions::from_lists(CorsLists {
+            origins: strings(&["https://app.test"]),
+            ..CorsLists::default()
+        });
+
+        assert!(matches!(
+            result,
+            Err(ValidationError::AllowedMethodsCannotBeEmpty)
+        ));
+    }
+}
+
 mod validate {
     use super::*;
 
+    mod token_validator {
+        use super::*;
+        use std::sync::Arc;
+
+        fn legacy_tokens() -> Arc<TokenValidatorFn> {
+            Arc::new(|value: &str| {
+                value
+                    .bytes()
+                    .all(|byte| byte.is_ascii_alphanumeric() || matches!(byte, b'-' | b'.'))
+                    || value == "X-Legacy[v1]"
+            })
+        }
+
+        #[test]
+        fn given_header_outside_token_grammar_when_default_validator_then_returns_invalid_token_error()
+         {
+            let options =
+                CorsOptions::new().allowed_headers(AllowedHeaders::list(["X-Legacy[v1]"]));
+
+            let result = options.validate();
+
+            assert!(matches!(
+                result,
+                Err(ValidationError::AllowedHeadersListContainsInvalidToken)
+            ));
+        }
+
+        #[test]
+        fn given_header_outside_token_grammar_when_custom_validator_accepts_then_returns_ok() {
+            let options = CorsOptions::new()
+                .allowed_headers(AllowedHeaders::list(["X-Legacy[v1]"]))
+                .exposed_headers(ExposedHeaders::list(["X-Legacy[v1]"]))
+                .token_validator(legacy_tokens());
+
+            assert!(options.validate().is_ok());
+        }
+
+        #[test]
+        fn given_method_rejected_by_custom_validator_when_validate_called_then_returns_method_error()
+         {
+            let options = CorsOptions::new()
+                .methods(AllowedMethods::list(["GET", "PURGE!"]))
+                .token_validator(legacy_tokens());
+
+            let result = options.validate();
+
+            assert!(matches!(
+                result,
+                Err(ValidationError::AllowedMethodsListContainsInvalidToken)
+            ));
+        }
+
+        #[test]
+        fn given_custom_validator_when_header_list_contains_wildcard_then_still_returns_wildcard_error()
+         {
+            let options = CorsOptions::new()
+                .allowed_headers(AllowedHeaders::list(["*"]))
+                .token_validator(Arc::new(|_: &str| true));
+
+            let result = options.validate();
+
+            assert!(matches!(
+                result,
+                Err(ValidationError::AllowedHeadersListCannotContainWildcard)
+            ));
+        }
+    }
+
     mod credentials {
         use super::*;
 
@@ -187,11 +459,62 @@ mod validate {
                 Err(ValidationError::AllowedHeadersCannotContainEmptyToken)
             ));
         }
+
+        #[test]
+        fn given_per_method_list_with_invalid_token_when_validate_called_then_returns_invalid_token_error()
+         {
+            let options = CorsOptions::new().allowed_headers_by_method(HashMap::from([(
+                "POST".to_string(),
+                AllowedHeaders::list(["X Header"]),
+            )]));
+            let result = options.validate();
+
+            assert!(matches!(
+                result,
+                Err(ValidationError::AllowedHeadersListContainsInvalidToken)
+            ));
+        }
+
+        #[test]
+        fn given_per_method_any_with_credentials_when_validate_called_then_returns_credentials_error()
+         {
+            let options = CorsOptions::new()
+                .origin(Origin::list(["https://app.test"]))
+                .credentials(true)
+                .allowed_headers_by_method(HashMap::from([(
+                    "GET".to_string(),
+                    AllowedHeaders::Any,
+                )]));
+            let result = options.validate();
+
+            assert!(matches!(
+                result,
+                Err(ValidationError::AllowedHeadersAnyNotAllowedWithCredentials)
+            ));
+        }
     }
 
     mod allowed_methods_rules {
         use super::*;
 
+        #[test]
+        fn given_empty_methods_list_when_validate_called_then_returns_empty_list_error() {
+            let options = CorsOptions::new().methods(AllowedMethods::list(Vec::<String>::new()));
+            let result = options.validate();
+
+            assert!(matches!(
+                result,
+                Err(ValidationError::AllowedMethodsCannotBeEmpty)
+            ));
+        }
+
+        #[test]
+        fn given_single_method_list_when_validate_called_then_returns_ok() {
+            let options = CorsOptions::new().methods(AllowedMethods::list(["GET"]));
+
+            assert!(options.validate().is_ok());
+        }
+
         #[test]
         fn given_methods_with_wildcard_when_validate_called_then_returns_wildcard_error() {
             let options = CorsOptions::new().methods(AllowedMethods::list(["GET", "*"]));
@@ -244,6 +567,28 @@ mod validate {
             ));
         }
 
+        #[test]
+        fn given_any_or_with_credentials_when_validate_called_then_returns_ok() {
+            let options = CorsOptions::new()
+                .credentials(true)
+                .origin(Origin::list(["https://api.test"]))
+                .exposed_headers(ExposedHeaders::any_or(["X-Trace"]));
+
+            assert!(options.validate().is_ok());
+        }
+
+        #[test]
+        fn given_any_or_with_invalid_token_when_validate_called_then_returns_invalid_token_error() {
+            let options =
+                CorsOptions::new().exposed_headers(ExposedHeaders::any_or(["X-Trace", "X Header"]));
+            let result = options.validate();
+
+            assert!(matches!(
+                result,
+                Err(ValidationError::ExposeHeadersListContainsInvalidToken)
+            ));
+        }
+
         #[test]
         fn given_headers_with_invalid_token_when_validate_called_then_returns_invalid_token_error()
         {
@@ -277,6 +622,56 @@ mod validate {
             assert!(result.is_ok());
         }
 
+        #[test]
+        fn given_any_with_without_credentials_when_validate_called_then_returns_ok() {
+            let options =
+                CorsOptions::new().exposed_headers(ExposedHeaders::any_with(["X-Trace", "X-Span"]));
+            let result = options.validate();
+
+            assert!(result.is_ok());
+        }
+
+        #[test]
+        fn given_any_with_and_credentials_when_validate_called_then_returns_credentials_error() {
+            let options = CorsOptions::new()
+                .credentials(true)
+                .origin(Origin::list(["https://api.test"]))
+                .exposed_headers(ExposedHeaders::any_with(["X-Trace"]));
+            let result = options.validate();
+
+            assert!(matches!(
+                result,
+                Err(ValidationError::ExposeHeadersWildcardRequiresCredentialsDisabled)
+            ));
+        }
+
+        #[test]
+        fn given_any_with_and_credentialed_origins_when_validate_called_then_returns_credentials_error()
+         {
+            let options = CorsOptions::new()
+                .origin(Origin::list(["https://api.test"]))
+                .credentialed_origins(Origin::list(["https://admin.test"]))
+                .exposed_headers(ExposedHeaders::any_with(["X-Trace"]));
+            let result = options.validate();
+
+            assert!(matches!(
+                result,
+                Err(ValidationError::ExposeHeadersWildcardRequiresCredentialsDisabled)
+            ));
+        }
+
+        #[test]
+        fn given_any_with_invalid_token_when_validate_called_then_returns_invalid_token_error() {
+            let options = CorsOptions::new()
+                .exposed_headers(ExposedHeaders::any_with(["X-Trace", "X Header"]));
+            let result = options.validate();
+
+            assert!(matches!(
+                result,
+                Err(ValidationError::ExposeHeadersListContainsInvalidToken)
+            ));
+        }
+
         #[test]
         fn given_wildcard_combined_with_headers_when_validate_called_then_returns_combination_error()
          {
@@ -363,6 +758,141 @@ mod validate {
         }
     }
 
+    mod credentialed_origin_rules {
+        use super::*;
+
+        #[test]
+        fn given_wildcard_credentialed_origins_when_validate_called_then_returns_specific_origin_error()
+         {
+            let options = CorsOptions::new().credentialed_origins(Origin::Any);
+
+            let result = options.validate();
+
+            assert_eq!(
+                result,
+                Err(ValidationError::CredentialsRequireSpecificOrigin)
+            );
+        }
+
+        #[test]
+        fn given_credentialed_origins_with_wildcard_headers_when_validate_called_then_returns_headers_error()
+         {
+            let options = CorsOptions::new()
+                .credentialed_origins(Origin::list(["https://app.test"]))
+                .allowed_headers(AllowedHeaders::Any);
+
+            let result = options.validate();
+
+            assert_eq!(
+                result,
+                Err(ValidationError::AllowedHeadersAnyNotAllowedWithCredentials)
+            );
+        }
+
+        #[test]
+        fn given_credentialed_origins_with_wildcard_fallback_when_validate_called_then_returns_ok()
+        {
+            let options =
+                CorsOptions::new().credentialed_origins(Origin::list(["https://app.test"]));
+
+            let result = options.validate();
+
+            assert!(result.is_ok());
+        }
+    }
+
+    mod extra_response_header_rules {
+        use super::*;
+
+        #[test]
+        fn given_extra_header_named_like_cors_header_when_validate_called_then_returns_conflict_error()
+         {
+            let options = CorsOptions::new()
+                .extra_response_headers([("access-control-allow-origin", "https://evil.test")]);
+
+            let result = options.validate();
+
+            assert_eq!(
+                result,
+                Err(ValidationError::ExtraResponseHeaderConflictsWithCors)
+            );
+        }
+
+        #[test]
+        fn given_extra_vary_header_when_validate_called_then_returns_conflict_error() {
+            let options = CorsOptions::new().extra_response_headers([("Vary", "Accept")]);
+
+            let result = options.validate();
+
+            assert_eq!(
+                result,
+                Err(ValidationError::ExtraResponseHeaderConflictsWithCors)
+            );
+        }
+
+        #[test]
+        fn given_unrelated_extra_header_when_validate_called_then_returns_ok() {
+            let options = CorsOptions::new().extra_response_headers([("X-CORS-Handled", "bunner")]);
+
+            let result = options.validate();
+
+            assert!(result.is_ok());
+        }
+    }
+
+    #[cfg(feature = "url")]
+    mod configured_origin_rules {
+        use super::*;
+        use crate::origin::OriginMatcher;
+
+        #[test]
+        fn given_misspelled_scheme_when_validate_called_then_returns_malformed_origin_error() {
+            let options = CorsOptions::new().origin(Origin::exact("htps://app.test"));
+
+            let result = options.validate();
+
+            assert_eq!(result, Err(ValidationError::MalformedConfiguredOrigin));
+        }
+
+        #[test]
+        fn given_list_entry_with_path_when_validate_called_then_returns_malformed_origin_error() {
+            let options = CorsOptions::new().origin(Origin::list([
+                OriginMatcher::exact("https://app.test"),
+                OriginMatcher::exact("https://app.test/login"),
+            ]));
+
+            let result = options.validate();
+
+            assert_eq!(result, Err(ValidationError::MalformedConfiguredOrigin));
+        }
+
+        #[test]
+        fn given_well_formed_origins_when_validate_called_then_returns_ok() {
+            let options = CorsOptions::new().origin(Origin::list([
+                OriginMatcher::exact("https://app.test"),
+                OriginMatcher::exact("http://localhost:3000"),
+                OriginMatcher::exact("null"),
+            ]));
+
+            let result = options.validate();
+
+            assert!(result.is_ok());
+        }
+
+        #[cfg(feature = "regex")]
+        #[test]
+        fn given_pattern_entry_when_validate_called_then_skips_origin_shape_check() {
+            let options = CorsOptions::new().origin(Origin::list([
+                OriginMatcher::exact("https://app.test"),
+                OriginMatcher::pattern_str(r"^htps?://.*$").unwrap(),
+            ]));
+
+            let result = options.validate();
+
+            assert!(result.is_ok());
+        }
+    }
+
     mod composite_rules {
         use super::*;
 
@@ -421,3 +951,58 @@ mod validate {
         }
     }
 }
+
+mod validate_lint {
+    use super::*;
+
+    #[test]
+    fn given_allow_all_followed_by_exact_when_validate_lint_called_then_reports_unreachable_matcher()
+     {
+        let options = CorsOptions::new().origin(Origin::list([
+            OriginMatcher::exact("https://first.test"),
+            OriginMatcher::Bool(true),
+            OriginMatcher::exact("https://dead.test"),
+        ]));
+
+        let lints = options.validate_lint();
+
+        assert_eq!(lints, vec![Lint::UnreachableOriginMatcher { index: 2 }]);
+        assert!(options.validate().is_ok());
+    }
+
+    #[test]
+    fn given_empty_origin_list_when_validate_lint_called_then_reports_empty_list() {
+        let options = CorsOptions::new().origin(Origin::list(Vec::<OriginMatcher>::new()));
+
+        let lints = options.validate_lint();
+
+        assert_eq!(lints, vec![Lint::EmptyOriginList]);
+    }
+
+    #[test]
+    fn given_duplicate_exact_origins_when_validate_lint_called_then_reports_duplicate() {
+        let options = CorsOptions::new().origin(Origin::list([
+            "https://app.test",
+            "https://other.test",
+            "HTTPS://APP.TEST",
+        ]));
+
+        let lints = options.validate_lint();
+
+        assert_eq!(
+            lints,
+            vec![Lint::DuplicateExactOrigin {
+                index: 2,
+                origin: "HTTPS://APP.TEST".to_string(),
+            }]
+        );
+        assert!(lints[0].to_string().contains("HTTPS://APP.TEST"));
+    }
+
+    #[test]
+    fn given_non_list_origin_when_validate_lint_called_then_returns_no_lints() {
+        let options = CorsOptions::new().origin(Origin::exact("https://app.test"));
+
+        assert!(options.validate_lint().is_empty());
+    }
+}
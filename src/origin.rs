@@ -1,15 +1,37 @@
 use crate::context::RequestContext;
-use crate::util::{equals_ignore_case, lowercase_unicode_into, normalize_lower};
+use crate::util::{
+    equals_ignore_case, lowercase_unicode_into, normalize_lower, starts_with_ignore_ascii_case,
+    strip_trailing_slash,
+};
+#[cfg(feature = "regex")]
 use regex_automata::meta::{BuildError, Regex};
+use std::borrow::Cow;
 use std::cell::RefCell;
-use std::collections::{HashMap, HashSet};
+#[cfg(feature = "regex")]
+use std::collections::HashMap;
+use std::collections::HashSet;
 use std::fmt;
 use std::hash::{Hash, Hasher};
-use std::sync::{Arc, LazyLock, RwLock};
-use std::time::{Duration, Instant};
+#[cfg(feature = "regex")]
+use std::sync::RwLock;
+use std::sync::{Arc, LazyLock};
+use std::time::Duration;
+#[cfg(any(feature = "regex", feature = "fancy-regex"))]
+use std::time::Instant;
 
 /// Convenience alias used for predicate-based origin configuration.
 pub type OriginPredicateFn = dyn for<'a> Fn(&str, &RequestContext<'a>) -> bool + Send + Sync;
+/// Convenience alias used for predicates that return the allowed origin value.
+pub type OriginPredicateValueFn =
+    dyn for<'a> Fn(&str, &RequestContext<'a>) -> Option<String> + Send + Sync;
+/// Convenience alias used for functions that map a request origin onto the
+/// canonical form matched against the origin policy.
+pub type OriginCanonicalizerFn = dyn for<'a> Fn(&'a str) -> Cow<'a, str> + Send + Sync;
+/// Convenience alias used for checks that flag an origin/host pair as spoofed.
+pub type OriginHostMismatchFn = dyn Fn(&str, &str) -> bool + Send + Sync;
+/// Convenience alias used for callbacks that record origins admitted by
+/// learning mode.
+pub type OriginLearningFn = dyn Fn(&str) + Send + Sync;
 /// Convenience alias used for custom callbacks that can construct a full
 /// [`OriginDecision`].
 pub type OriginCallbackFn =
@@ -23,9 +45,24 @@ pub enum Origin {
     Exact(String),
     List(OriginList),
     Predicate(Arc<OriginPredicateFn>),
+    PredicateValue(Arc<OriginPredicateValueFn>),
     Custom(Arc<OriginCallbackFn>),
 }
 
+/// Payload-free description of an [`Origin`] variant, returned by
+/// [`Origin::kind`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum OriginKind {
+    Any,
+    Exact,
+    List,
+    /// [`Origin::Predicate`] or [`Origin::PredicateValue`].
+    Predicate,
+    Custom,
+    /// The callback installed by [`Origin::disabled`].
+    Disabled,
+}
+
 /// Outcome category emitted by [`Origin::resolve`].
 #[derive(Debug, Clone)]
 pub enum OriginDecision {
@@ -86,44 +123,238 @@ where
 }
 
 /// Errors encountered while compiling user-supplied origin patterns.
+///
+/// Compilation failures carry the offending pattern so batch configurations can
+/// be debugged from the message alone. [`PatternError::TooLong`] omits it to keep
+/// oversized input out of logs. Without the `regex` feature, `re:` allow-list
+/// entries fail with [`PatternError::Unsupported`].
 #[derive(Debug)]
+#[non_exhaustive]
 pub enum PatternError {
-    Build(Box<BuildError>),
-    Timeout { elapsed: Duration, budget: Duration },
-    TooLong { length: usize, max: usize },
+    #[cfg(feature = "regex")]
+    Build {
+        pattern: String,
+        source: Box<BuildError>,
+    },
+    #[cfg(feature = "fancy-regex")]
+    Fancy {
+        pattern: String,
+        source: Box<fancy_regex::Error>,
+    },
+    Timeout {
+        pattern: String,
+        elapsed: Duration,
+        budget: Duration,
+    },
+    TooLong {
+        length: usize,
+        max: usize,
+    },
+    TooComplex {
+        pattern: String,
+        limit: usize,
+    },
+    Unsupported {
+        pattern: String,
+    },
 }
 
 impl fmt::Display for PatternError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
-            PatternError::Build(_) => write!(f, "failed to compile origin pattern"),
-            PatternError::Timeout { .. } => {
-                write!(f, "compiling origin pattern exceeded the configured budget")
+            #[cfg(feature = "regex")]
+            PatternError::Build { pattern, .. } => {
+                write!(f, "failed to compile origin pattern `{}`", pattern)
             }
+            #[cfg(feature = "fancy-regex")]
+            PatternError::Fancy { pattern, .. } => {
+                write!(f, "failed to compile origin pattern `{}`", pattern)
+            }
+            PatternError::Timeout { pattern, .. } => write!(
+                f,
+                "compiling origin pattern `{}` exceeded the configured budget",
+                pattern
+            ),
             PatternError::TooLong { length, max } => write!(
                 f,
                 "origin pattern length {} exceeds maximum allowed {}",
                 length, max
             ),
+            PatternError::TooComplex { pattern, limit } => write!(
+                f,
+                "origin pattern `{}` exceeds the configured regex size limit of {} bytes",
+                pattern, limit
+            ),
+            PatternError::Unsupported { pattern } => write!(
+                f,
+                "origin pattern `{}` requires the `regex` feature",
+                pattern
+            ),
+        }
+    }
+}
+
+/// Compares variants field by field. The wrapped regex engine errors are not
+/// comparable themselves, so they are compared by their messages.
+impl PartialEq for PatternError {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            #[cfg(feature = "regex")]
+            (
+                PatternError::Build { pattern, source },
+                PatternError::Build {
+                    pattern: other_pattern,
+                    source: other_source,
+                },
+            ) => pattern == other_pattern && source.to_string() == other_source.to_string(),
+            #[cfg(feature = "fancy-regex")]
+            (
+                PatternError::Fancy { pattern, source },
+                PatternError::Fancy {
+                    pattern: other_pattern,
+                    source: other_source,
+                },
+            ) => pattern == other_pattern && source.to_string() == other_source.to_string(),
+            (
+                PatternError::Timeout {
+                    pattern,
+                    elapsed,
+                    budget,
+                },
+                PatternError::Timeout {
+                    pattern: other_pattern,
+                    elapsed: other_elapsed,
+                    budget: other_budget,
+                },
+            ) => pattern == other_pattern && elapsed == other_elapsed && budget == other_budget,
+            (
+                PatternError::TooLong { length, max },
+                PatternError::TooLong {
+                    length: other_length,
+                    max: other_max,
+                },
+            ) => length == other_length && max == other_max,
+            (
+                PatternError::TooComplex { pattern, limit },
+                PatternError::TooComplex {
+                    pattern: other_pattern,
+                    limit: other_limit,
+                },
+            ) => pattern == other_pattern && limit == other_limit,
+            (
+                PatternError::Unsupported { pattern },
+                PatternError::Unsupported {
+                    pattern: other_pattern,
+                },
+            ) => pattern == other_pattern,
+            _ => false,
         }
     }
 }
 
+impl Eq for PatternError {}
+
 impl std::error::Error for PatternError {
     fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
         match self {
-            PatternError::Build(err) => Some(err.as_ref()),
+            #[cfg(feature = "regex")]
+            PatternError::Build { source, .. } => Some(source.as_ref()),
+            #[cfg(feature = "fancy-regex")]
+            PatternError::Fancy { source, .. } => Some(source.as_ref()),
             _ => None,
         }
     }
 }
 
+/// Errors produced by [`Origin::from_env`].
+#[derive(Debug)]
+pub enum OriginEnvError {
+    /// The variable is unset or not valid Unicode.
+    Var {
+        name: String,
+        source: std::env::VarError,
+    },
+    /// A `re:` entry failed to compile.
+    Pattern { name: String, source: PatternError },
+}
+
+impl fmt::Display for OriginEnvError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            OriginEnvError::Var { name, .. } => {
+                write!(f, "failed to read origin allow-list from `{}`", name)
+            }
+            OriginEnvError::Pattern { name, .. } => {
+                write!(f, "invalid origin allow-list in `{}`", name)
+            }
+        }
+    }
+}
+
+impl std::error::Error for OriginEnvError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            OriginEnvError::Var { source, .. } => Some(source),
+            OriginEnvError::Pattern { source, .. } => Some(source),
+        }
+    }
+}
+
+/// Parses one allow-list entry, compiling it as a pattern when prefixed with
+/// `re:`.
+pub(crate) fn allow_list_matcher(entry: &str) -> Result<OriginMatcher, PatternError> {
+    match entry.strip_prefix("re:") {
+        #[cfg(feature = "regex")]
+        Some(pattern) => OriginMatcher::pattern_str(pattern.trim()),
+        #[cfg(not(feature = "regex"))]
+        Some(pattern) => Err(PatternError::Unsupported {
+            pattern: pattern.trim().to_string(),
+        }),
+        None => Ok(OriginMatcher::exact(entry)),
+    }
+}
+
+#[cfg(any(feature = "regex", feature = "fancy-regex"))]
 const PATTERN_COMPILE_BUDGET: Duration = Duration::from_millis(100);
+#[cfg(any(feature = "regex", feature = "fancy-regex"))]
 const MAX_PATTERN_LENGTH: usize = 50_000;
 const MAX_ORIGIN_LENGTH: usize = 4_096;
+pub(crate) const NULL_ORIGIN: &str = "null";
+
+static DISABLED_CALLBACK: LazyLock<Arc<OriginCallbackFn>> =
+    LazyLock::new(|| Arc::new(|_, _| OriginDecision::Skip));
+
+/// Compiled patterns keyed by the size limits they were built under, `None`
+/// being the `regex-automata` defaults, and then by pattern.
+#[cfg(feature = "regex")]
+type RegexCache = HashMap<Option<RegexSizeLimits>, HashMap<String, Regex>>;
+
+#[cfg(feature = "regex")]
+static REGEX_CACHE: LazyLock<RwLock<RegexCache>> = LazyLock::new(|| RwLock::new(HashMap::new()));
+
+/// Memory caps for compiling an origin pattern, passed to
+/// [`OriginMatcher::pattern_str_with_limits`].
+///
+/// `size_limit` bounds the compiled NFA; patterns exceeding it fail with
+/// [`PatternError::TooComplex`]. `dfa_size_limit` bounds the lazily built
+/// DFAs, which are skipped in favour of slower engines when they would grow
+/// past it.
+#[cfg(feature = "regex")]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct RegexSizeLimits {
+    pub size_limit: usize,
+    pub dfa_size_limit: usize,
+}
 
-static REGEX_CACHE: LazyLock<RwLock<HashMap<String, Regex>>> =
-    LazyLock::new(|| RwLock::new(HashMap::new()));
+#[cfg(feature = "regex")]
+impl RegexSizeLimits {
+    pub fn new(size_limit: usize, dfa_size_limit: usize) -> Self {
+        Self {
+            size_limit,
+            dfa_size_limit,
+        }
+    }
+}
 
 thread_local! {
     static ORIGIN_UNICODE_BUFFER: RefCell<String> = const { RefCell::new(String::new()) };
@@ -131,22 +362,44 @@ thread_local! {
 
 /// Pre-compiled matcher used by [`Origin::List`].
 #[derive(Clone, Debug)]
+#[non_exhaustive]
 pub enum OriginMatcher {
     Exact(String),
+    /// Linear-time pattern. Requires the `regex` feature, which is on by
+    /// default.
+    #[cfg(feature = "regex")]
     Pattern(Regex),
     Bool(bool),
+    Null,
+    /// Scheme and host compared exactly while any port is accepted.
+    HostAnyPort(String),
+    /// `host[:port]` compared exactly while any scheme is accepted.
+    HostPort(String),
+    /// Backtracking pattern supporting lookaround and backreferences. Requires
+    /// the `fancy-regex` feature.
+    #[cfg(feature = "fancy-regex")]
+    FancyPattern(fancy_regex::Regex),
+    /// Exact origin whose host is compared in its ASCII (punycode) form, so
+    /// internationalized and punycode spellings match each other. Requires the
+    /// `idna` feature.
+    #[cfg(feature = "idna")]
+    ExactIdn(String),
+    /// Exact origin that also accepts the `www.` form of its host. Stores the
+    /// apex spelling.
+    ExactWwwInsensitive(String),
 }
 
 /// Collection that stores and evaluates a list of [`OriginMatcher`] values.
 #[derive(Clone, Debug)]
 pub struct OriginList {
     matchers: Vec<OriginMatcher>,
-    compiled: CompiledOriginList,
+    // Boxed so the lookup tables do not inflate every `Origin` value.
+    compiled: Box<CompiledOriginList>,
 }
 
 impl OriginList {
     fn new(matchers: Vec<OriginMatcher>) -> Self {
-        let compiled = CompiledOriginList::compile(&matchers);
+        let compiled = Box::new(CompiledOriginList::compile(&matchers));
         Self { matchers, compiled }
     }
 
@@ -165,16 +418,50 @@ impl OriginList {
     pub(crate) fn matches(&self, candidate: &str) -> bool {
         self.compiled.matches(candidate, &self.matchers)
     }
+
+    /// Returns the position of the first matcher, in configuration order, that
+    /// accepts `candidate`.
+    ///
+    /// Meant for debugging large lists. Misses are answered by the compiled
+    /// lookup tables; only a hit walks the matchers to find its position.
+    pub fn matching_index(&self, candidate: &str) -> Option<usize> {
+        if !self.matches(candidate) {
+            return None;
+        }
+        self.matchers
+            .iter()
+            .position(|matcher| matcher.matches(candidate))
+    }
+
+    pub(crate) fn allows_null(&self) -> bool {
+        self.compiled.allow_null
+    }
 }
 
 const SMALL_LIST_LINEAR_SCAN_THRESHOLD: usize = 4;
 
+/// Number of ASCII exact origins above which they are matched through a single
+/// anchored automaton instead of the hash set.
+#[cfg(feature = "aho-corasick")]
+const EXACT_AUTOMATON_THRESHOLD: usize = 4096;
+
 #[derive(Clone, Debug, Default)]
 struct CompiledOriginList {
     ascii_exact: HashSet<AsciiExact>,
+    #[cfg(feature = "aho-corasick")]
+    exact_automaton: Option<aho_corasick::AhoCorasick>,
     unicode_exact: HashSet<String>,
+    host_any_port: HashSet<AsciiExact>,
+    host_port: HashSet<AsciiExact>,
+    www_insensitive: HashSet<AsciiExact>,
+    #[cfg(feature = "idna")]
+    idn_exact: HashSet<AsciiExact>,
+    #[cfg(feature = "regex")]
     regexes: Vec<Regex>,
+    #[cfg(feature = "fancy-regex")]
+    fancy_regexes: Vec<fancy_regex::Regex>,
     allow_all: bool,
+    allow_null: bool,
     prefer_linear_scan: bool,
 }
 
@@ -195,18 +482,71 @@ impl CompiledOriginList {
                         compiled.unicode_exact.insert(normalize_lower(value));
                     }
                 }
+                #[cfg(feature = "regex")]
                 OriginMatcher::Pattern(regex) => compiled.regexes.push(regex.clone()),
                 OriginMatcher::Bool(value) => {
                     if *value {
                         compiled.allow_all = true;
                     }
                 }
+                OriginMatcher::Null => compiled.allow_null = true,
+                OriginMatcher::HostAnyPort(value) => {
+                    compiled
+                        .host_any_port
+                        .insert(AsciiExact::new(value.clone()));
+                }
+                OriginMatcher::HostPort(value) => {
+                    compiled.host_port.insert(AsciiExact::new(value.clone()));
+                }
+                OriginMatcher::ExactWwwInsensitive(value) => {
+                    compiled
+                        .www_insensitive
+                        .insert(AsciiExact::new(value.clone()));
+                }
+                #[cfg(feature = "fancy-regex")]
+                OriginMatcher::FancyPattern(regex) => compiled.fancy_regexes.push(regex.clone()),
+                #[cfg(feature = "idna")]
+                OriginMatcher::ExactIdn(value) => {
+                    compiled.idn_exact.insert(AsciiExact::new(value.clone()));
+                }
             }
         }
 
+        #[cfg(feature = "aho-corasick")]
+        if compiled.ascii_exact.len() > EXACT_AUTOMATON_THRESHOLD {
+            compiled.compile_exact_automaton();
+        }
+
         compiled
     }
 
+    /// Moves the ASCII exact origins into an anchored, case-insensitive
+    /// automaton. Keeps the hash set if the automaton cannot be built.
+    #[cfg(feature = "aho-corasick")]
+    fn compile_exact_automaton(&mut self) {
+        use aho_corasick::{AhoCorasick, MatchKind, StartKind};
+
+        let automaton = AhoCorasick::builder()
+            .ascii_case_insensitive(true)
+            .match_kind(MatchKind::LeftmostLongest)
+            .start_kind(StartKind::Anchored)
+            .build(self.ascii_exact.iter().map(|exact| exact.value.as_bytes()));
+        if let Ok(automaton) = automaton {
+            self.exact_automaton = Some(automaton);
+            self.ascii_exact = HashSet::new();
+        }
+    }
+
+    /// The longest configured origin that prefixes `candidate` equals it
+    /// exactly when it spans the whole input.
+    #[cfg(feature = "aho-corasick")]
+    fn automaton_matches(automaton: &aho_corasick::AhoCorasick, candidate: &str) -> bool {
+        use aho_corasick::{Anchored, Input};
+
+        let input = Input::new(candidate).anchored(Anchored::Yes);
+        matches!(automaton.find(input), Some(found) if found.end() == candidate.len())
+    }
+
     fn matches(&self, candidate: &str, matchers: &[OriginMatcher]) -> bool {
         if self.allow_all {
             return true;
@@ -216,6 +556,18 @@ impl CompiledOriginList {
             return matchers.iter().any(|matcher| matcher.matches(candidate));
         }
 
+        if self.allow_null && candidate.eq_ignore_ascii_case(NULL_ORIGIN) {
+            return true;
+        }
+
+        #[cfg(feature = "aho-corasick")]
+        if let Some(automaton) = &self.exact_automaton
+            && candidate.is_ascii()
+            && Self::automaton_matches(automaton, candidate)
+        {
+            return true;
+        }
+
         if !self.ascii_exact.is_empty() && candidate.is_ascii() {
             let borrowed = AsciiCaseInsensitive::new(candidate);
             if self.ascii_exact.contains(borrowed) {
@@ -237,17 +589,125 @@ impl CompiledOriginList {
             return true;
         }
 
-        let haystack = candidate.as_bytes();
-        for regex in &self.regexes {
-            if regex.is_match(haystack) {
+        if !self.host_any_port.is_empty() {
+            let borrowed = AsciiCaseInsensitive::new(strip_port(candidate));
+            if self.host_any_port.contains(borrowed) {
+                return true;
+            }
+        }
+
+        if !self.host_port.is_empty() {
+            let borrowed = AsciiCaseInsensitive::new(strip_scheme(candidate));
+            if self.host_port.contains(borrowed) {
+                return true;
+            }
+        }
+
+        if !self.www_insensitive.is_empty() {
+            let apex = strip_www(candidate);
+            if self
+                .www_insensitive
+                .contains(AsciiCaseInsensitive::new(apex.as_ref()))
+            {
+                return true;
+            }
+        }
+
+        #[cfg(feature = "idna")]
+        if !self.idn_exact.is_empty() {
+            let ascii = idn_to_ascii(candidate);
+            if self
+                .idn_exact
+                .contains(AsciiCaseInsensitive::new(ascii.as_ref()))
+            {
                 return true;
             }
         }
 
+        #[cfg(feature = "regex")]
+        if self
+            .regexes
+            .iter()
+            .any(|regex| regex.is_match(candidate.as_bytes()))
+        {
+            return true;
+        }
+
+        #[cfg(feature = "fancy-regex")]
+        if self
+            .fancy_regexes
+            .iter()
+            .any(|regex| regex.is_match(candidate).unwrap_or(false))
+        {
+            return true;
+        }
+
         false
     }
 }
 
+/// Drops one leading `www.` label from the host of a serialized origin.
+fn strip_www(origin: &str) -> Cow<'_, str> {
+    let Some(authority_start) = origin.find("://").map(|index| index + 3) else {
+        return Cow::Borrowed(origin);
+    };
+    let host = &origin[authority_start..];
+    if host.len() > 4 && starts_with_ignore_ascii_case(host, "www.") {
+        Cow::Owned(format!("{}{}", &origin[..authority_start], &host[4..]))
+    } else {
+        Cow::Borrowed(origin)
+    }
+}
+
+/// Drops a leading `scheme://` from a serialized origin.
+fn strip_scheme(origin: &str) -> &str {
+    origin
+        .find("://")
+        .map_or(origin, |index| &origin[index + 3..])
+}
+
+/// Drops a trailing `:port` from the authority of a serialized origin.
+fn strip_port(origin: &str) -> &str {
+    let Some(authority_start) = origin.find("://").map(|index| index + 3) else {
+        return origin;
+    };
+    match origin.rfind(':') {
+        Some(colon)
+            if colon >= authority_start
+                && colon + 1 < origin.len()
+                && origin.as_bytes()[colon + 1..]
+                    .iter()
+                    .all(u8::is_ascii_digit) =>
+        {
+            &origin[..colon]
+        }
+        _ => origin,
+    }
+}
+
+/// Converts the host of a serialized origin to its ASCII (punycode) form,
+/// leaving the scheme and port untouched. Hosts that fail IDNA processing are
+/// returned unchanged.
+#[cfg(feature = "idna")]
+fn idn_to_ascii(origin: &str) -> Cow<'_, str> {
+    if origin.is_ascii() {
+        return Cow::Borrowed(origin);
+    }
+    let Some(host_start) = origin.find("://").map(|index| index + 3) else {
+        return Cow::Borrowed(origin);
+    };
+    let host_end = strip_port(origin).len();
+    match idna::domain_to_ascii(&origin[host_start..host_end]) {
+        Ok(host) => Cow::Owned(format!(
+            "{}{}{}",
+            &origin[..host_start],
+            host,
+            &origin[host_end..]
+        )),
+        Err(_) => Cow::Borrowed(origin),
+    }
+}
+
 #[derive(Clone, Debug, Eq)]
 struct AsciiExact {
     value: String,
@@ -326,20 +786,120 @@ impl OriginMatcher {
         Self::Exact(value.into())
     }
 
+    /// Matches only the opaque `null` origin sent by sandboxed documents and
+    /// `file:` URLs, independently of [`CorsOptions::allow_null_origin`](crate::CorsOptions::allow_null_origin).
+    pub fn null() -> Self {
+        Self::Null
+    }
+
+    /// Matches `scheme://host` exactly (ASCII case-insensitive) with or without
+    /// any `:port` suffix, e.g. `https://app.test` also accepts
+    /// `https://app.test:8443`. A port in `origin` itself is ignored.
+    pub fn host_any_port<S: Into<String>>(origin: S) -> Self {
+        let origin = origin.into();
+        Self::HostAnyPort(strip_port(&origin).to_string())
+    }
+
+    /// Matches `host[:port]` exactly (ASCII case-insensitive) under any scheme,
+    /// e.g. `app.test:8443` accepts both `https://app.test:8443` and
+    /// `http://app.test:8443`. A scheme in `host_port` itself is ignored. Default
+    /// ports are not implied: `app.test:443` does not match `https://app.test`.
+    ///
+    /// Allowed requests still reflect the full origin that was sent.
+    pub fn host_port<S: Into<String>>(host_port: S) -> Self {
+        let host_port = host_port.into();
+        Self::HostPort(strip_scheme(&host_port).to_string())
+    }
+
+    /// Matches `origin` exactly (ASCII case-insensitive) and also its `www.`
+    /// form, e.g. `https://example.com` accepts `https://www.example.com` and
+    /// the reverse. Other subdomains do not match. A `www.` prefix in `origin`
+    /// itself is ignored.
+    ///
+    /// Allowed requests still reflect the full origin that was sent.
+    pub fn exact_www_insensitive<S: Into<String>>(origin: S) -> Self {
+        let origin = origin.into();
+        Self::ExactWwwInsensitive(strip_www(&origin).into_owned())
+    }
+
+    /// Matches `origin` exactly after converting both the configured and the
+    /// request host to ASCII punycode, e.g. `https://münchen.de` also accepts
+    /// `https://xn--mnchen-3ya.de`. Scheme and port are compared as-is.
+    #[cfg(feature = "idna")]
+    pub fn exact_idn<S: Into<String>>(origin: S) -> Self {
+        let origin = origin.into();
+        Self::ExactIdn(idn_to_ascii(&origin).into_owned())
+    }
+
+    #[cfg(feature = "regex")]
     pub fn pattern(regex: Regex) -> Self {
         Self::Pattern(regex)
     }
 
+    #[cfg(feature = "regex")]
     pub fn pattern_str(pattern: &str) -> Result<Self, PatternError> {
-        if let Some(regex) = Self::cached_pattern(pattern) {
+        Self::cached_or_compile(pattern, None, PATTERN_COMPILE_BUDGET)
+    }
+
+    /// Compiles `pattern` like [`OriginMatcher::pattern_str`], capping the
+    /// memory the compiled regex may use.
+    ///
+    /// Patterns over `limits.size_limit` fail with
+    /// [`PatternError::TooComplex`]. The cache keeps patterns compiled under
+    /// different limits apart, so a pattern first built with the defaults is
+    /// still checked against `limits` here.
+    #[cfg(feature = "regex")]
+    pub fn pattern_str_with_limits(
+        pattern: &str,
+        limits: RegexSizeLimits,
+    ) -> Result<Self, PatternError> {
+        Self::cached_or_compile(pattern, Some(limits), PATTERN_COMPILE_BUDGET)
+    }
+
+    #[cfg(feature = "regex")]
+    fn cached_or_compile(
+        pattern: &str,
+        limits: Option<RegexSizeLimits>,
+        budget: Duration,
+    ) -> Result<Self, PatternError> {
+        if let Some(regex) = Self::cached_pattern(pattern, limits) {
             return Ok(Self::Pattern(regex));
         }
-        let regex = Self::compile_pattern(pattern, PATTERN_COMPILE_BUDGET)?;
-        Self::cache_pattern(pattern, &regex);
+        let regex = Self::compile_pattern(pattern, limits, budget)?;
+        Ok(Self::Pattern(Self::cache_pattern(pattern, limits, regex)))
+    }
+
+    /// Compiles `pattern` without the case-insensitive wrapper that
+    /// [`OriginMatcher::pattern_str`] adds, which makes matching cheaper.
+    ///
+    /// The caller handles casing: request origins are lowercased before the
+    /// policy sees them, so write the pattern in lowercase. Calling
+    /// [`OriginMatcher::matches`] directly compares the input as given. These
+    /// patterns are not cached; the length limit and compile budget still apply.
+    #[cfg(feature = "regex")]
+    pub fn pattern_str_case_sensitive(pattern: &str) -> Result<Self, PatternError> {
+        let regex = Self::compile_regex(pattern, pattern, None, PATTERN_COMPILE_BUDGET)?;
         Ok(Self::Pattern(regex))
     }
 
-    fn compile_pattern(pattern: &str, budget: Duration) -> Result<Regex, PatternError> {
+    #[cfg(feature = "regex")]
+    fn compile_pattern(
+        pattern: &str,
+        limits: Option<RegexSizeLimits>,
+        budget: Duration,
+    ) -> Result<Regex, PatternError> {
+        Self::compile_regex(pattern, &format!("(?i:{pattern})"), limits, budget)
+    }
+
+    /// Compiles `source`, the possibly wrapped form of `pattern`, reporting
+    /// errors against `pattern`.
+    #[cfg(feature = "regex")]
+    fn compile_regex(
+        pattern: &str,
+        source: &str,
+        limits: Option<RegexSizeLimits>,
+        budget: Duration,
+    ) -> Result<Regex, PatternError> {
         if pattern.len() > MAX_PATTERN_LENGTH {
             return Err(PatternError::TooLong {
                 length: pattern.len(),
@@ -347,45 +907,150 @@ impl OriginMatcher {
             });
         }
 
+        let mut config = Regex::config();
+        if let Some(limits) = limits {
+            config = config
+                .nfa_size_limit(Some(limits.size_limit))
+                .dfa_size_limit(Some(limits.dfa_size_limit));
+        }
+
         let started = Instant::now();
-        let regex = Regex::new(&format!("(?i:{pattern})"))
-            .map_err(|err| PatternError::Build(Box::new(err)))?;
+        let regex = Regex::builder()
+            .configure(config)
+            .build(source)
+            .map_err(|err| match err.size_limit() {
+                Some(limit) => PatternError::TooComplex {
+                    pattern: pattern.to_string(),
+                    limit,
+                },
+                None => PatternError::Build {
+                    pattern: pattern.to_string(),
+                    source: Box::new(err),
+                },
+            })?;
         let elapsed = started.elapsed();
         if elapsed > budget {
-            return Err(PatternError::Timeout { elapsed, budget });
+            return Err(PatternError::Timeout {
+                pattern: pattern.to_string(),
+                elapsed,
+                budget,
+            });
         }
 
         Ok(regex)
     }
 
-    fn cached_pattern(pattern: &str) -> Option<Regex> {
+    #[cfg(feature = "regex")]
+    fn cached_pattern(pattern: &str, limits: Option<RegexSizeLimits>) -> Option<Regex> {
         let cache = REGEX_CACHE.read().unwrap_or_else(|err| err.into_inner());
-        cache.get(pattern).cloned()
+        cache.get(&limits)?.get(pattern).cloned()
     }
 
-    fn cache_pattern(pattern: &str, regex: &Regex) {
+    /// Stores a freshly compiled pattern and returns the cached instance.
+    ///
+    /// Threads that miss the cache concurrently may both compile the same
+    /// pattern; the first insert wins so every caller shares one regex.
+    #[cfg(feature = "regex")]
+    fn cache_pattern(pattern: &str, limits: Option<RegexSizeLimits>, regex: Regex) -> Regex {
         let mut cache = REGEX_CACHE.write().unwrap_or_else(|err| err.into_inner());
-        cache.insert(pattern.to_owned(), regex.clone());
+        cache
+            .entry(limits)
+            .or_default()
+            .entry(pattern.to_owned())
+            .or_insert(regex)
+            .clone()
+    }
+
+    /// Compiles `patterns` ahead of time so the first requests that build
+    /// matchers from them hit the process-wide pattern cache.
+    ///
+    /// Stops at the first pattern that fails to compile; patterns before it stay
+    /// cached.
+    #[cfg(feature = "regex")]
+    pub fn warm(patterns: &[&str]) -> Result<(), PatternError> {
+        for pattern in patterns {
+            Self::pattern_str(pattern)?;
+        }
+        Ok(())
+    }
+
+    /// Drops every pattern compiled through [`OriginMatcher::pattern_str`] from
+    /// the process-wide cache.
+    ///
+    /// Matchers that were already built keep their compiled regex.
+    #[cfg(feature = "regex")]
+    pub fn clear_cache() {
+        REGEX_CACHE
+            .write()
+            .unwrap_or_else(|err| err.into_inner())
+            .clear();
+    }
+
+    /// Compiles a pattern with the backtracking `fancy-regex` engine.
+    ///
+    /// Use this only for patterns that need lookaround or backreferences: unlike
+    /// [`OriginMatcher::pattern_str`], matching is not guaranteed to run in
+    /// linear time and compiled patterns are not cached. Matching is
+    /// case-insensitive, and the same length and compile-time budget apply.
+    #[cfg(feature = "fancy-regex")]
+    pub fn pattern_fancy(pattern: &str) -> Result<Self, PatternError> {
+        if pattern.len() > MAX_PATTERN_LENGTH {
+            return Err(PatternError::TooLong {
+                length: pattern.len(),
+                max: MAX_PATTERN_LENGTH,
+            });
+        }
+
+        let budget = PATTERN_COMPILE_BUDGET;
+        let started = Instant::now();
+        let regex = fancy_regex::Regex::new(&format!("(?i:{pattern})")).map_err(|err| {
+            PatternError::Fancy {
+                pattern: pattern.to_string(),
+                source: Box::new(err),
+            }
+        })?;
+        let elapsed = started.elapsed();
+        if elapsed > budget {
+            return Err(PatternError::Timeout {
+                pattern: pattern.to_string(),
+                elapsed,
+                budget,
+            });
+        }
+
+        Ok(Self::FancyPattern(regex))
     }
 
-    #[cfg(test)]
+    #[cfg(all(test, feature = "regex"))]
     pub(crate) fn pattern_str_with_budget(
         pattern: &str,
         budget: Duration,
     ) -> Result<Self, PatternError> {
-        if let Some(regex) = Self::cached_pattern(pattern) {
-            return Ok(Self::Pattern(regex));
-        }
-        let regex = Self::compile_pattern(pattern, budget)?;
-        Self::cache_pattern(pattern, &regex);
-        Ok(Self::Pattern(regex))
+        Self::cached_or_compile(pattern, None, budget)
     }
 
     pub fn matches(&self, candidate: &str) -> bool {
         match self {
-            OriginMatcher::Exact(value) => equals_ignore_case(value, candidate),
+            OriginMatcher::Exact(value) => {
+                if value.is_ascii() && candidate.is_ascii() {
+                    value.eq_ignore_ascii_case(candidate)
+                } else {
+                    equals_ignore_case(value, candidate)
+                }
+            }
+            #[cfg(feature = "regex")]
             OriginMatcher::Pattern(regex) => regex.is_match(candidate.as_bytes()),
             OriginMatcher::Bool(value) => *value,
+            OriginMatcher::Null => candidate.eq_ignore_ascii_case(NULL_ORIGIN),
+            OriginMatcher::HostAnyPort(value) => value.eq_ignore_ascii_case(strip_port(candidate)),
+            OriginMatcher::HostPort(value) => value.eq_ignore_ascii_case(strip_scheme(candidate)),
+            OriginMatcher::ExactWwwInsensitive(value) => {
+                value.eq_ignore_ascii_case(&strip_www(candidate))
+            }
+            #[cfg(feature = "idna")]
+            OriginMatcher::ExactIdn(value) => value.eq_ignore_ascii_case(&idn_to_ascii(candidate)),
+            #[cfg(feature = "fancy-regex")]
+            OriginMatcher::FancyPattern(regex) => regex.is_match(candidate).unwrap_or(false),
         }
     }
 }
@@ -408,6 +1073,27 @@ impl From<bool> for OriginMatcher {
     }
 }
 
+impl From<&str> for Origin {
+    /// Same as [`Origin::exact`].
+    fn from(value: &str) -> Self {
+        Origin::exact(value)
+    }
+}
+
+impl From<String> for Origin {
+    /// Same as [`Origin::exact`].
+    fn from(value: String) -> Self {
+        Origin::exact(value)
+    }
+}
+
+impl From<Vec<&str>> for Origin {
+    /// Same as [`Origin::list`] with exact matchers.
+    fn from(values: Vec<&str>) -> Self {
+        Origin::list(values)
+    }
+}
+
 impl Origin {
     /// Returns a configuration that allows any non-empty origin.
     pub fn any() -> Self {
@@ -429,6 +1115,50 @@ impl Origin {
         Self::List(OriginList::new(matchers))
     }
 
+    /// Parses a newline-delimited allow-list into an [`Origin::List`].
+    ///
+    /// Each line is trimmed; blank lines and lines starting with `#` are skipped.
+    /// Lines prefixed with `re:` are compiled with [`OriginMatcher::pattern_str`]
+    /// and every other line becomes an exact matcher. The first invalid pattern
+    /// aborts parsing.
+    pub fn from_lines(lines: &str) -> Result<Self, PatternError> {
+        let mut matchers = Vec::new();
+        for line in lines.lines().map(str::trim) {
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            matchers.push(allow_list_matcher(line)?);
+        }
+        Ok(Self::list(matchers))
+    }
+
+    /// Reads a comma-separated allow-list from the environment variable `name`,
+    /// such as `CORS_ALLOWED_ORIGINS`, into an [`Origin::List`].
+    ///
+    /// The variable is read once, at construction. Entries are trimmed and empty
+    /// ones skipped; entries prefixed with `re:` are compiled with
+    /// [`OriginMatcher::pattern_str`] like in [`Origin::from_lines`]. Patterns
+    /// cannot contain commas. An unset variable is an error rather than an
+    /// empty list.
+    pub fn from_env(name: &str) -> Result<Self, OriginEnvError> {
+        let value = std::env::var(name).map_err(|source| OriginEnvError::Var {
+            name: name.to_string(),
+            source,
+        })?;
+        let mut matchers = Vec::new();
+        for entry in value.split(',').map(str::trim) {
+            if entry.is_empty() {
+                continue;
+            }
+            let matcher = allow_list_matcher(entry).map_err(|source| OriginEnvError::Pattern {
+                name: name.to_string(),
+                source,
+            })?;
+            matchers.push(matcher);
+        }
+        Ok(Self::list(matchers))
+    }
+
     /// Returns a configuration powered by a user-provided predicate.
     pub fn predicate<F>(predicate: F) -> Self
     where
@@ -437,6 +1167,18 @@ impl Origin {
         Self::Predicate(Arc::new(predicate))
     }
 
+    /// Returns a configuration powered by a predicate that also chooses the
+    /// value written to `Access-Control-Allow-Origin`.
+    ///
+    /// Returning `Some(value)` allows the request and emits `value` (for example
+    /// a canonicalized form of the origin); returning `None` disallows it.
+    pub fn predicate_value<F>(predicate: F) -> Self
+    where
+        F: for<'a> Fn(&str, &RequestContext<'a>) -> Option<String> + Send + Sync + 'static,
+    {
+        Self::PredicateValue(Arc::new(predicate))
+    }
+
     /// Returns a configuration that can construct arbitrary [`OriginDecision`]s.
     pub fn custom<F>(callback: F) -> Self
     where
@@ -451,7 +1193,25 @@ impl Origin {
     /// Disables CORS handling entirely, mirroring the behaviour of omitting
     /// the middleware.
     pub fn disabled() -> Self {
-        Self::custom(|_, _| OriginDecision::Skip)
+        Self::Custom(Arc::clone(&DISABLED_CALLBACK))
+    }
+
+    /// Reports which kind of policy this is without exposing its payload.
+    ///
+    /// [`Origin::disabled`] reports [`OriginKind::Disabled`] even though it is
+    /// stored as [`Origin::Custom`]; other custom callbacks that always skip
+    /// still report [`OriginKind::Custom`].
+    pub fn kind(&self) -> OriginKind {
+        match self {
+            Origin::Any => OriginKind::Any,
+            Origin::Exact(_) => OriginKind::Exact,
+            Origin::List(_) => OriginKind::List,
+            Origin::Predicate(_) | Origin::PredicateValue(_) => OriginKind::Predicate,
+            Origin::Custom(callback) if Arc::ptr_eq(callback, &DISABLED_CALLBACK) => {
+                OriginKind::Disabled
+            }
+            Origin::Custom(_) => OriginKind::Custom,
+        }
     }
 
     /// Determines which response should be returned based on the supplied
@@ -501,10 +1261,45 @@ impl Origin {
                     OriginDecision::Skip
                 }
             }
+            Origin::PredicateValue(predicate) => match request_origin {
+                Some(origin) => match predicate(origin, ctx) {
+                    Some(value) => OriginDecision::Exact(value),
+                    None => OriginDecision::Disallow,
+                },
+                None => OriginDecision::Skip,
+            },
             Origin::Custom(callback) => callback(request_origin, ctx),
         }
     }
 
+    /// Returns a copy with one trailing `/` removed from every exact origin.
+    pub(crate) fn without_trailing_slashes(&self) -> Self {
+        match self {
+            Origin::Exact(value) => Origin::Exact(strip_trailing_slash(value).to_string()),
+            Origin::List(list) => Origin::list(list.iter().map(|matcher| match matcher {
+                OriginMatcher::Exact(value) => {
+                    OriginMatcher::Exact(strip_trailing_slash(value).to_string())
+                }
+                OriginMatcher::HostAnyPort(value) => {
+                    OriginMatcher::host_any_port(strip_trailing_slash(value))
+                }
+                OriginMatcher::HostPort(value) => {
+                    OriginMatcher::host_port(strip_trailing_slash(value))
+                }
+                OriginMatcher::ExactWwwInsensitive(value) => {
+                    OriginMatcher::exact_www_insensitive(strip_trailing_slash(value))
+                }
+                other => other.clone(),
+            })),
+            other => other.clone(),
+        }
+    }
+
+    /// Returns `true` when the policy explicitly lists [`OriginMatcher::Null`].
+    pub(crate) fn allows_null_matcher(&self) -> bool {
+        matches!(self, Origin::List(list) if list.allows_null())
+    }
+
     /// Indicates whether the `Vary: Origin` header should be set when the
     /// decision is [`OriginDecision::Disallow`].
     pub fn vary_on_disallow(&self) -> bool {
@@ -516,26 +1311,21 @@ impl Origin {
 #[path = "origin_test.rs"]
 mod origin_test;
 
-#[cfg(test)]
-pub(crate) fn clear_regex_cache() {
-    REGEX_CACHE
-        .write()
-        .unwrap_or_else(|err| err.into_inner())
-        .clear();
-}
-
-#[cfg(test)]
+#[cfg(all(test, feature = "regex"))]
 pub(crate) fn regex_cache_size() -> usize {
     REGEX_CACHE
         .read()
         .unwrap_or_else(|err| err.into_inner())
-        .len()
+        .values()
+        .map(HashMap::len)
+        .sum()
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "regex"))]
 pub(crate) fn regex_cache_contains(pattern: &str) -> bool {
     REGEX_CACHE
         .read()
         .unwrap_or_else(|err| err.into_inner())
-        .contains_key(pattern)
+        .values()
+        .any(|patterns| patterns.contains_key(pattern))
 }
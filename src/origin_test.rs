@@ -8,6 +8,8 @@ fn request_context(method: &'static str, origin: Option<&'static str>) -> Reques
         access_control_request_method: Some("GET"),
         access_control_request_headers: Some("X-Test"),
         access_control_request_private_network: false,
+        sec_fetch_site: None,
+        host: None,
     }
 }
 
@@ -114,16 +116,19 @@ mod origin_decision {
 
 mod origin_matcher {
     use super::*;
+    #[cfg(feature = "regex")]
     use regex_automata::meta::Regex;
 
+    #[cfg(feature = "regex")]
     mod compile_pattern {
         use super::*;
         use std::time::Duration;
 
         #[test]
         fn should_compile_case_insensitively_when_pattern_valid_then_match_inputs() {
-            let regex = OriginMatcher::compile_pattern("^https://svc$", Duration::from_secs(1))
-                .expect("pattern should compile");
+            let regex =
+                OriginMatcher::compile_pattern("^https://svc$", None, Duration::from_secs(1))
+                    .expect("pattern should compile");
 
             assert!(regex.is_match("https://SVC".as_bytes()));
             assert!(regex.is_match("https://svc".as_bytes()));
@@ -131,7 +136,7 @@ mod origin_matcher {
 
         #[test]
         fn should_return_timeout_error_when_budget_zero_then_abort_compilation() {
-            let result = OriginMatcher::compile_pattern(".*", Duration::ZERO);
+            let result = OriginMatcher::compile_pattern(".*", None, Duration::ZERO);
 
             assert!(matches!(result, Err(PatternError::Timeout { .. })));
         }
@@ -140,7 +145,7 @@ mod origin_matcher {
         fn should_return_too_long_error_when_pattern_exceeds_limit_then_reject_compilation() {
             let pattern = "a".repeat(super::MAX_PATTERN_LENGTH + 1);
 
-            let result = OriginMatcher::compile_pattern(&pattern, Duration::from_secs(1));
+            let result = OriginMatcher::compile_pattern(&pattern, None, Duration::from_secs(1));
 
             if let Err(PatternError::TooLong { length, max }) = result {
                 assert_eq!(length, super::MAX_PATTERN_LENGTH + 1);
@@ -165,6 +170,192 @@ mod origin_matcher {
         }
     }
 
+    mod host_any_port {
+        use super::*;
+
+        #[test]
+        fn should_match_portless_and_ported_origins_when_host_matches_then_ignore_port() {
+            let matcher = OriginMatcher::host_any_port("https://app.test");
+
+            assert!(matcher.matches("https://app.test"));
+            assert!(matcher.matches("https://app.test:3000"));
+            assert!(matcher.matches("HTTPS://APP.TEST:8443"));
+        }
+
+        #[test]
+        fn should_reject_when_host_or_scheme_differs_then_require_exact_authority() {
+            let matcher = OriginMatcher::host_any_port("https://app.test");
+
+            assert!(!matcher.matches("https://other.test:3000"));
+            assert!(!matcher.matches("https://app.test.evil:3000"));
+            assert!(!matcher.matches("http://app.test:3000"));
+        }
+
+        #[test]
+        fn should_drop_configured_port_when_constructed_then_store_scheme_and_host() {
+            let matcher = OriginMatcher::host_any_port("https://[::1]:8443");
+
+            match &matcher {
+                OriginMatcher::HostAnyPort(value) => assert_eq!(value, "https://[::1]"),
+                _ => panic!("expected host-any-port matcher"),
+            }
+            assert!(matcher.matches("https://[::1]"));
+            assert!(matcher.matches("https://[::1]:3000"));
+        }
+    }
+
+    mod host_port {
+        use super::*;
+
+        #[test]
+        fn should_match_http_and_https_when_host_and_port_match_then_ignore_scheme() {
+            let matcher = OriginMatcher::host_port("app.test:8443");
+
+            assert!(matcher.matches("https://app.test:8443"));
+            assert!(matcher.matches("http://app.test:8443"));
+            assert!(matcher.matches("HTTP://APP.TEST:8443"));
+        }
+
+        #[test]
+        fn should_reject_when_port_or_host_differs_then_require_exact_authority() {
+            let matcher = OriginMatcher::host_port("app.test:8443");
+
+            assert!(!matcher.matches("https://app.test"));
+            assert!(!matcher.matches("https://app.test:443"));
+            assert!(!matcher.matches("https://app.test.evil:8443"));
+            assert!(!matcher.matches("null"));
+        }
+
+        #[test]
+        fn should_drop_configured_scheme_when_constructed_then_store_host_and_port() {
+            let matcher = OriginMatcher::host_port("https://app.test:8443");
+
+            match &matcher {
+                OriginMatcher::HostPort(value) => assert_eq!(value, "app.test:8443"),
+                _ => panic!("expected host-port matcher"),
+            }
+            assert!(matcher.matches("http://app.test:8443"));
+        }
+    }
+
+    mod exact_www_insensitive {
+        use super::*;
+
+        #[test]
+        fn should_match_apex_and_www_when_configured_with_apex_then_ignore_www_label() {
+            let matcher = OriginMatcher::exact_www_insensitive("https://example.com");
+
+            assert!(matcher.matches("https://example.com"));
+            assert!(matcher.matches("https://www.example.com"));
+            assert!(matcher.matches("HTTPS://WWW.EXAMPLE.COM"));
+        }
+
+        #[test]
+        fn should_reject_when_other_subdomain_or_scheme_then_require_apex_or_www() {
+            let matcher = OriginMatcher::exact_www_insensitive("https://example.com");
+
+            assert!(!matcher.matches("https://api.example.com"));
+            assert!(!matcher.matches("https://www.www.example.com"));
+            assert!(!matcher.matches("https://wwwexample.com"));
+            assert!(!matcher.matches("http://www.example.com"));
+            assert!(!matcher.matches("https://example.com.evil"));
+        }
+
+        #[test]
+        fn should_store_apex_when_configured_with_www_then_match_both_forms() {
+            let matcher = OriginMatcher::exact_www_insensitive("https://www.example.com");
+
+            match &matcher {
+                OriginMatcher::ExactWwwInsensitive(value) => {
+                    assert_eq!(value, "https://example.com")
+                }
+                _ => panic!("expected www-insensitive matcher"),
+            }
+            assert!(matcher.matches("https://example.com"));
+        }
+
+        #[test]
+        fn should_match_through_compiled_list_when_list_large_then_use_lookup_table() {
+            let mut matchers: Vec<OriginMatcher> = (0..64)
+                .map(|index| OriginMatcher::exact(format!("https://site-{index}.test")))
+                .collect();
+            matchers.push(OriginMatcher::exact_www_insensitive("https://example.com"));
+            let origin = Origin::list(matchers);
+            let ctx = request_context("GET", Some("https://www.example.com"));
+
+            for (candidate, allowed) in [
+                ("https://www.example.com", true),
+                ("https://example.com", true),
+                ("https://api.example.com", false),
+            ] {
+                assert_eq!(
+                    matches!(
+                        origin.resolve(Some(candidate), &ctx),
+                        OriginDecision::Mirror
+                    ),
+                    allowed,
+                    "{candidate}"
+                );
+            }
+        }
+    }
+
+    #[cfg(feature = "idna")]
+    mod exact_idn {
+        use super::*;
+
+        #[test]
+        fn should_match_punycode_request_when_configured_with_unicode_then_compare_ascii_hosts() {
+            let matcher = OriginMatcher::exact_idn("https://münchen.de");
+
+            assert!(matcher.matches("https://xn--mnchen-3ya.de"));
+            assert!(matcher.matches("https://münchen.de"));
+        }
+
+        #[test]
+        fn should_match_unicode_request_when_configured_with_punycode_then_compare_ascii_hosts() {
+            let matcher = OriginMatcher::exact_idn("https://xn--mnchen-3ya.de");
+
+            assert!(matcher.matches("https://münchen.de"));
+            assert!(matcher.matches("https://XN--MNCHEN-3YA.DE"));
+        }
+
+        #[test]
+        fn should_keep_scheme_and_port_when_host_converted_then_require_exact_match() {
+            let matcher = OriginMatcher::exact_idn("https://münchen.de:8443");
+
+            match &matcher {
+                OriginMatcher::ExactIdn(value) => {
+                    assert_eq!(value, "https://xn--mnchen-3ya.de:8443")
+                }
+                _ => panic!("expected IDN exact matcher"),
+            }
+            assert!(matcher.matches("https://münchen.de:8443"));
+            assert!(!matcher.matches("https://münchen.de"));
+            assert!(!matcher.matches("http://münchen.de:8443"));
+            assert!(!matcher.matches("https://munchen.de:8443"));
+        }
+
+        #[test]
+        fn should_match_in_large_list_when_compiled_then_use_idn_set() {
+            let origin = Origin::list([
+                OriginMatcher::exact("https://a.test"),
+                OriginMatcher::exact("https://b.test"),
+                OriginMatcher::exact("https://c.test"),
+                OriginMatcher::exact("https://d.test"),
+                OriginMatcher::exact_idn("https://münchen.de"),
+            ]);
+            let Origin::List(list) = origin else {
+                unreachable!()
+            };
+
+            assert!(list.matches("https://xn--mnchen-3ya.de"));
+            assert!(list.matches("https://münchen.de"));
+            assert!(!list.matches("https://berlin.de"));
+        }
+    }
+
+    #[cfg(feature = "regex")]
     mod pattern {
         use super::*;
 
@@ -183,6 +374,7 @@ mod origin_matcher {
         }
     }
 
+    #[cfg(feature = "regex")]
     mod pattern_str {
         use super::*;
         use std::time::Duration;
@@ -194,11 +386,18 @@ mod origin_matcher {
             assert!(matches!(matcher, OriginMatcher::Pattern(_)));
         }
 
+        #[test]
+        fn should_return_error_when_pattern_uses_backreference_then_require_fancy_engine() {
+            let result = OriginMatcher::pattern_str(r"^https://(\w+)\.\1\.test$");
+
+            assert!(matches!(result, Err(PatternError::Build { .. })));
+        }
+
         #[test]
         fn should_return_error_when_pattern_invalid_then_fail_compilation() {
             let result = OriginMatcher::pattern_str("(");
 
-            assert!(matches!(result, Err(PatternError::Build(_))));
+            assert!(matches!(result, Err(PatternError::Build { .. })));
         }
 
         #[test]
@@ -217,7 +416,7 @@ mod origin_matcher {
 
         #[test]
         fn should_cache_pattern_then_bypass_budget_on_subsequent_calls() {
-            super::clear_regex_cache();
+            OriginMatcher::clear_cache();
             let pattern = r"^https://cached\.allowed$";
 
             let first = OriginMatcher::pattern_str(pattern).expect("initial compile");
@@ -235,7 +434,7 @@ mod origin_matcher {
 
         #[test]
         fn should_return_timeout_error_when_budget_too_small_then_abort_compilation() {
-            super::clear_regex_cache();
+            OriginMatcher::clear_cache();
             let result = OriginMatcher::pattern_str_with_budget(".*", Duration::ZERO);
 
             assert!(matches!(result, Err(PatternError::Timeout { .. })));
@@ -243,7 +442,7 @@ mod origin_matcher {
 
         #[test]
         fn should_compile_with_budget_then_cache_pattern() {
-            super::clear_regex_cache();
+            OriginMatcher::clear_cache();
             let pattern = r"^https://budget\.test$";
 
             let matcher =
@@ -253,31 +452,203 @@ mod origin_matcher {
             assert!(matches!(matcher, OriginMatcher::Pattern(_)));
             assert!(super::regex_cache_contains(pattern));
 
-            super::clear_regex_cache();
+            OriginMatcher::clear_cache();
         }
 
         #[test]
         fn should_recover_from_poisoned_cache_then_continue_operations() {
             use std::panic::{AssertUnwindSafe, catch_unwind};
 
-            super::clear_regex_cache();
+            OriginMatcher::clear_cache();
             let pattern = r"^https://poisoned\.test$";
 
             let _ = catch_unwind(AssertUnwindSafe(|| {
                 let _guard = super::super::REGEX_CACHE.write().unwrap();
                 panic!("poison cache");
             }));
-            assert!(super::super::OriginMatcher::cached_pattern(pattern).is_none());
+            assert!(super::super::OriginMatcher::cached_pattern(pattern, None).is_none());
 
             let regex = Regex::new(pattern).unwrap();
-            super::super::OriginMatcher::cache_pattern(pattern, &regex);
+            super::super::OriginMatcher::cache_pattern(pattern, None, regex);
 
-            assert!(super::super::OriginMatcher::cached_pattern(pattern).is_some());
+            assert!(super::super::OriginMatcher::cached_pattern(pattern, None).is_some());
             assert!(super::regex_cache_contains(pattern));
 
-            super::clear_regex_cache();
+            OriginMatcher::clear_cache();
             assert!(!super::regex_cache_contains(pattern));
         }
+
+        #[test]
+        fn should_keep_first_entry_when_pattern_cached_twice_then_ignore_later_insert() {
+            let pattern = r"^https://first-wins\.test$";
+            let first = Regex::new(pattern).unwrap();
+            let second = Regex::new(r"^https://other\.test$").unwrap();
+
+            super::super::OriginMatcher::cache_pattern(pattern, None, first);
+            let cached = super::super::OriginMatcher::cache_pattern(pattern, None, second);
+
+            assert!(cached.is_match(b"https://first-wins.test"));
+            assert!(!cached.is_match(b"https://other.test"));
+        }
+
+        #[test]
+        fn should_compile_consistently_when_threads_contend_on_cache_then_never_deadlock() {
+            use std::sync::{Arc, Barrier};
+            use std::thread;
+
+            const THREADS: usize = 16;
+            const ROUNDS: usize = 50;
+            let barrier = Arc::new(Barrier::new(THREADS));
+
+            let handles: Vec<_> = (0..THREADS)
+                .map(|index| {
+                    let barrier = Arc::clone(&barrier);
+                    thread::spawn(move || {
+                        let shared = r"^https://shared\.stress\.test$";
+                        let own = format!(r"^https://thread{index}\.stress\.test$");
+                        barrier.wait();
+                        for _ in 0..ROUNDS {
+                            let shared_matcher = OriginMatcher::pattern_str(shared).unwrap();
+                            let own_matcher = OriginMatcher::pattern_str(&own).unwrap();
+
+                            assert!(shared_matcher.matches("https://shared.stress.test"));
+                            assert!(
+                                own_matcher.matches(&format!("https://thread{index}.stress.test"))
+                            );
+                            assert!(!own_matcher.matches("https://shared.stress.test"));
+                        }
+                    })
+                })
+                .collect();
+
+            for handle in handles {
+                handle.join().expect("stress thread should not panic");
+            }
+        }
+    }
+
+    #[cfg(feature = "regex")]
+    mod pattern_str_case_sensitive {
+        use super::*;
+
+        #[test]
+        fn should_reject_differing_case_when_compiled_case_sensitive_then_match_exact_case_only() {
+            let matcher = OriginMatcher::pattern_str_case_sensitive(r"^https://[a-z]+\.test$")
+                .expect("valid pattern");
+
+            assert!(matcher.matches("https://app.test"));
+            assert!(!matcher.matches("https://APP.test"));
+            assert!(!matcher.matches("HTTPS://app.test"));
+        }
+
+        #[test]
+        fn should_not_populate_cache_when_compiled_case_sensitive_then_leave_cache_untouched() {
+            let pattern = r"^https://case-sensitive-only\.test$";
+
+            OriginMatcher::pattern_str_case_sensitive(pattern).expect("valid pattern");
+
+            assert!(!super::regex_cache_contains(pattern));
+        }
+
+        #[test]
+        fn should_report_build_error_when_pattern_invalid_then_carry_original_pattern() {
+            match OriginMatcher::pattern_str_case_sensitive("(") {
+                Err(PatternError::Build { pattern, .. }) => assert_eq!(pattern, "("),
+                Err(other) => panic!("unexpected pattern error: {other:?}"),
+                Ok(_) => panic!("expected build error"),
+            }
+        }
+    }
+
+    #[cfg(feature = "regex")]
+    mod warm {
+        use super::*;
+
+        #[test]
+        fn should_cache_every_pattern_when_warmed_then_report_success() {
+            let patterns = [r"^https://warm-one\.test$", r"^https://warm-two\.test$"];
+
+            let result = OriginMatcher::warm(&patterns);
+
+            assert!(result.is_ok());
+            assert!(super::regex_cache_contains(patterns[0]));
+            assert!(super::regex_cache_contains(patterns[1]));
+        }
+
+        #[test]
+        fn should_return_error_when_pattern_invalid_then_keep_earlier_patterns_cached() {
+            let valid = r"^https://warm-valid\.test$";
+
+            let result = OriginMatcher::warm(&[valid, "(unclosed"]);
+
+            assert!(matches!(result, Err(PatternError::Build { .. })));
+            assert!(super::regex_cache_contains(valid));
+            assert!(!super::regex_cache_contains("(unclosed"));
+        }
+    }
+
+    #[cfg(feature = "regex")]
+    mod clear_cache {
+        use super::*;
+
+        #[test]
+        fn should_empty_cache_when_cleared_then_drop_warmed_patterns() {
+            let pattern = r"^https://warm-clear\.test$";
+            OriginMatcher::warm(&[pattern]).expect("warm pattern");
+            assert!(super::regex_cache_contains(pattern));
+
+            OriginMatcher::clear_cache();
+
+            assert!(!super::regex_cache_contains(pattern));
+        }
+
+        #[test]
+        fn should_keep_built_matcher_working_when_cache_cleared_then_match_origin() {
+            let matcher =
+                OriginMatcher::pattern_str(r"^https://warm-kept\.test$").expect("compile pattern");
+
+            OriginMatcher::clear_cache();
+
+            assert!(matcher.matches("https://warm-kept.test"));
+        }
+    }
+
+    #[cfg(feature = "fancy-regex")]
+    mod pattern_fancy {
+        use super::*;
+
+        #[test]
+        fn should_compile_backreference_when_fancy_engine_used_then_match_repeated_label() {
+            let matcher = OriginMatcher::pattern_fancy(r"^https://(\w+)\.\1\.test$").unwrap();
+
+            assert!(matches!(matcher, OriginMatcher::FancyPattern(_)));
+            assert!(matcher.matches("https://app.app.test"));
+            assert!(!matcher.matches("https://app.api.test"));
+        }
+
+        #[test]
+        fn should_support_lookahead_when_fancy_list_scanned_then_skip_excluded_origin() {
+            let list = match Origin::list([
+                OriginMatcher::exact("https://one.test"),
+                OriginMatcher::exact("https://two.test"),
+                OriginMatcher::exact("https://three.test"),
+                OriginMatcher::exact("https://four.test"),
+                OriginMatcher::pattern_fancy(r"^https://(?!admin\.)\w+\.example\.com$").unwrap(),
+            ]) {
+                Origin::List(list) => list,
+                _ => unreachable!(),
+            };
+
+            assert!(list.matches("https://app.example.com"));
+            assert!(!list.matches("https://admin.example.com"));
+        }
+
+        #[test]
+        fn should_return_error_when_fancy_pattern_invalid_then_fail_compilation() {
+            let result = OriginMatcher::pattern_fancy("(");
+
+            assert!(matches!(result, Err(PatternError::Fancy { .. })));
+        }
     }
 
     mod matches_fn {
@@ -292,6 +663,16 @@ mod origin_matcher {
             assert!(matches);
         }
 
+        #[test]
+        fn should_reject_when_ascii_bytes_differ_only_outside_letters_then_require_same_origin() {
+            // `@` (0x40) and `` ` `` (0x60) differ only in the ASCII case bit.
+            let matcher = OriginMatcher::exact("https://a@b");
+
+            assert!(matcher.matches("HTTPS://A@B"));
+            assert!(!matcher.matches("https://a`b"));
+        }
+
+        #[cfg(feature = "regex")]
         #[test]
         fn should_use_regex_when_pattern_matcher_used_then_validate_origin() {
             let matcher = OriginMatcher::pattern(Regex::new(r"^https://.*$").unwrap());
@@ -309,6 +690,15 @@ mod origin_matcher {
 
             assert!(!matches);
         }
+
+        #[test]
+        fn should_match_only_null_literal_when_null_matcher_used_then_ignore_other_origins() {
+            let matcher = OriginMatcher::null();
+
+            assert!(matcher.matches("null"));
+            assert!(matcher.matches("NULL"));
+            assert!(!matcher.matches("https://api.test"));
+        }
     }
 
     mod from_string {
@@ -347,6 +737,7 @@ mod origin_matcher {
 
 mod origin_list_behavior {
     use super::*;
+    #[cfg(feature = "regex")]
     use regex_automata::meta::Regex;
 
     fn list_from<I, T>(values: I) -> OriginList
@@ -360,6 +751,36 @@ mod origin_list_behavior {
         }
     }
 
+    #[test]
+    fn should_match_host_any_port_when_list_uses_hashed_lookup_then_ignore_port() {
+        let list = list_from([
+            OriginMatcher::exact("https://a.test"),
+            OriginMatcher::exact("https://b.test"),
+            OriginMatcher::exact("https://c.test"),
+            OriginMatcher::exact("https://d.test"),
+            OriginMatcher::host_any_port("https://app.test"),
+        ]);
+
+        assert!(list.matches("https://app.test"));
+        assert!(list.matches("https://app.test:3000"));
+        assert!(!list.matches("https://other.test:3000"));
+    }
+
+    #[test]
+    fn should_match_host_port_when_list_uses_hashed_lookup_then_ignore_scheme() {
+        let list = list_from([
+            OriginMatcher::exact("https://a.test"),
+            OriginMatcher::exact("https://b.test"),
+            OriginMatcher::exact("https://c.test"),
+            OriginMatcher::exact("https://d.test"),
+            OriginMatcher::host_port("app.test:8443"),
+        ]);
+
+        assert!(list.matches("https://app.test:8443"));
+        assert!(list.matches("http://app.test:8443"));
+        assert!(!list.matches("http://app.test:9000"));
+    }
+
     #[test]
     fn should_report_empty_when_no_matchers_then_return_true() {
         let list = list_from(Vec::<OriginMatcher>::new());
@@ -368,6 +789,49 @@ mod origin_list_behavior {
         assert_eq!(list.len(), 0);
     }
 
+    #[test]
+    fn should_match_null_origin_when_large_list_contains_null_matcher_then_skip_hash_lookup() {
+        let list = list_from([
+            OriginMatcher::exact("https://one.test"),
+            OriginMatcher::exact("https://two.test"),
+            OriginMatcher::exact("https://three.test"),
+            OriginMatcher::exact("https://four.test"),
+            OriginMatcher::null(),
+        ]);
+
+        assert!(list.allows_null());
+        assert!(list.matches("null"));
+        assert!(list.matches("https://two.test"));
+        assert!(!list.matches("https://five.test"));
+    }
+
+    #[test]
+    fn should_report_configured_position_when_third_matcher_matches_then_return_two() {
+        let list = list_from([
+            OriginMatcher::exact("https://one.test"),
+            OriginMatcher::exact("https://two.test"),
+            OriginMatcher::host_any_port("https://app.test"),
+            OriginMatcher::exact("https://four.test"),
+            OriginMatcher::exact("https://five.test"),
+        ]);
+
+        assert_eq!(list.matching_index("https://app.test:8443"), Some(2));
+        assert_eq!(list.matching_index("HTTPS://FIVE.TEST"), Some(4));
+        assert_eq!(list.matching_index("https://six.test"), None);
+    }
+
+    #[cfg(feature = "regex")]
+    #[test]
+    fn should_report_first_position_when_several_matchers_match_then_prefer_earliest() {
+        let list = list_from([
+            OriginMatcher::exact("https://one.test"),
+            OriginMatcher::pattern_str(r"^https://.*\.test$").expect("valid pattern"),
+            OriginMatcher::exact("https://app.test"),
+        ]);
+
+        assert_eq!(list.matching_index("https://app.test"), Some(1));
+    }
+
     #[test]
     fn should_iterate_insertion_order_when_iter_called_then_return_matchers() {
         let list = list_from([
@@ -386,6 +850,7 @@ mod origin_list_behavior {
         assert_eq!(collected, vec!["https://one.test", "https://two.test"]);
     }
 
+    #[cfg(feature = "regex")]
     #[test]
     fn should_use_linear_scan_when_list_small_then_match_via_original_matchers() {
         let list = list_from([
@@ -442,6 +907,7 @@ mod origin_list_behavior {
         assert!(compiled.matches("straße", &matchers));
     }
 
+    #[cfg(feature = "regex")]
     #[test]
     fn should_match_using_regex_when_no_exact_match_then_use_compiled_pattern() {
         let list = list_from([
@@ -455,6 +921,47 @@ mod origin_list_behavior {
         assert!(list.matches("https://allowed.service"));
         assert!(!list.matches("https://denied.service"));
     }
+
+    #[cfg(feature = "aho-corasick")]
+    #[test]
+    fn should_agree_with_hashed_lookup_when_exact_list_uses_automaton_then_match_same_origins() {
+        let origins: Vec<String> = (0..50_000)
+            .map(|index| format!("https://tenant-{index}.example.test"))
+            .collect();
+        let list = list_from(origins.iter().map(String::as_str));
+        let hashed = CompiledOriginList {
+            ascii_exact: origins.iter().cloned().map(AsciiExact::new).collect(),
+            ..CompiledOriginList::default()
+        };
+        assert!(list.compiled.exact_automaton.is_some());
+        assert!(list.compiled.ascii_exact.is_empty());
+
+        for (index, origin) in origins.iter().enumerate().step_by(7) {
+            let candidates = [
+                origin.clone(),
+                origin.to_ascii_uppercase(),
+                format!("{origin}0"),
+                format!("{origin}/"),
+                origin[..origin.len() - 1].to_string(),
+                format!("https://tenant-{index}.example.invalid"),
+            ];
+            for candidate in &candidates {
+                assert_eq!(
+                    list.matches(candidate),
+                    hashed.matches(candidate, &[]),
+                    "automaton and hashed lookup disagree on {candidate}"
+                );
+            }
+        }
+
+        for candidate in [
+            "https://tenant-49999.example.test",
+            "https://tenant-50000.example.test",
+        ] {
+            let linear = list.iter().any(|matcher| matcher.matches(candidate));
+            assert_eq!(list.matches(candidate), linear);
+        }
+    }
 }
 
 mod ascii_case_helpers {
@@ -488,6 +995,7 @@ mod ascii_case_helpers {
     }
 }
 
+#[cfg(feature = "regex")]
 mod pattern_error_behavior {
     use super::*;
     use std::error::Error as _;
@@ -500,6 +1008,7 @@ mod pattern_error_behavior {
             Ok(_) => panic!("expected build error"),
         };
         assert!(build_error.to_string().contains("failed to compile"));
+        assert!(build_error.to_string().contains("`(`"));
 
         let too_long = PatternError::TooLong {
             length: MAX_PATTERN_LENGTH + 10,
@@ -507,7 +1016,15 @@ mod pattern_error_behavior {
         };
         assert!(too_long.to_string().contains("exceeds"));
 
+        let too_complex = PatternError::TooComplex {
+            pattern: "[a-z]{5000}".to_string(),
+            limit: 1_024,
+        };
+        assert!(too_complex.to_string().contains("size limit of 1024 bytes"));
+        assert!(too_complex.to_string().contains("[a-z]{5000}"));
+
         let timeout = PatternError::Timeout {
+            pattern: "^https://slow\\.test$".to_string(),
             elapsed: Duration::from_millis(150),
             budget: Duration::from_millis(100),
         };
@@ -516,6 +1033,35 @@ mod pattern_error_behavior {
                 .to_string()
                 .contains("exceeded the configured budget")
         );
+        assert!(timeout.to_string().contains(r"^https://slow\.test$"));
+    }
+
+    #[test]
+    fn should_compare_fields_when_errors_compared_then_match_same_failure() {
+        let first = OriginMatcher::pattern_str("(").err().unwrap();
+        let second = OriginMatcher::pattern_str("(").err().unwrap();
+        let other = OriginMatcher::pattern_str("[").err().unwrap();
+
+        assert_eq!(first, second);
+        assert_ne!(first, other);
+        assert_ne!(
+            PatternError::TooComplex {
+                pattern: "(".to_string(),
+                limit: 1_024,
+            },
+            PatternError::Unsupported {
+                pattern: "(".to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn should_carry_offending_pattern_when_build_fails_then_expose_field() {
+        match OriginMatcher::pattern_str("(") {
+            Err(PatternError::Build { pattern, .. }) => assert_eq!(pattern, "("),
+            Err(other) => panic!("unexpected pattern error: {other:?}"),
+            Ok(_) => panic!("expected build error"),
+        }
     }
 
     #[test]
@@ -527,6 +1073,7 @@ mod pattern_error_behavior {
         assert!(build_error.source().is_some());
 
         let timeout = PatternError::Timeout {
+            pattern: "^https://slow\\.test$".to_string(),
             elapsed: Duration::from_millis(150),
             budget: Duration::from_millis(100),
         };
@@ -578,6 +1125,144 @@ mod origin_type {
         }
     }
 
+    mod from_lines {
+        use super::*;
+
+        #[cfg(feature = "regex")]
+        #[test]
+        fn should_build_list_when_lines_mix_exact_pattern_comment_and_blank_then_skip_noise() {
+            let lines = [
+                "# production",
+                "https://app.test",
+                "",
+                r"re:^https://.*\.preview\.test$",
+                "   # staging",
+                "  https://staging.test  ",
+            ]
+            .join("\n");
+            let origin = Origin::from_lines(&lines).expect("valid allow-list");
+            let ctx = request_context("GET", Some("https://app.test"));
+
+            match &origin {
+                Origin::List(values) => assert_eq!(values.len(), 3),
+                _ => panic!("expected list origin"),
+            }
+            for allowed in [
+                "https://app.test",
+                "https://staging.test",
+                "https://pr-1.preview.test",
+            ] {
+                assert!(matches!(
+                    origin.resolve(Some(allowed), &ctx),
+                    OriginDecision::Mirror
+                ));
+            }
+            assert!(matches!(
+                origin.resolve(Some("https://other.test"), &ctx),
+                OriginDecision::Disallow
+            ));
+        }
+
+        #[test]
+        fn should_return_empty_list_when_only_comments_then_disallow_all() {
+            let origin = Origin::from_lines("# nothing yet\n\n").expect("valid allow-list");
+
+            match origin {
+                Origin::List(values) => assert!(values.is_empty()),
+                _ => panic!("expected list origin"),
+            }
+        }
+
+        #[cfg(feature = "regex")]
+        #[test]
+        fn should_return_error_when_pattern_line_invalid_then_report_pattern() {
+            let result = Origin::from_lines("https://app.test\nre:(\n");
+
+            match result {
+                Err(PatternError::Build { pattern, .. }) => assert_eq!(pattern, "("),
+                Err(other) => panic!("unexpected pattern error: {other:?}"),
+                Ok(_) => panic!("expected build error"),
+            }
+        }
+    }
+
+    mod from_env {
+        use super::*;
+
+        #[cfg(feature = "regex")]
+        fn set_env(name: &str, value: &str) {
+            // SAFETY: every test uses its own variable name, and nothing else in
+            // the test binary reads these variables concurrently.
+            unsafe { std::env::set_var(name, value) };
+        }
+
+        #[cfg(feature = "regex")]
+        #[test]
+        fn should_build_list_when_env_has_exact_and_pattern_entries_then_allow_each() {
+            let name = "BUNNER_CORS_TEST_FROM_ENV_LIST";
+            set_env(
+                name,
+                r" https://app.test, ,re:^https://.*\.preview\.test$,https://staging.test ",
+            );
+
+            let origin = Origin::from_env(name).expect("valid allow-list");
+            let ctx = request_context("GET", Some("https://app.test"));
+
+            match &origin {
+                Origin::List(values) => assert_eq!(values.len(), 3),
+                _ => panic!("expected list origin"),
+            }
+            for allowed in [
+                "https://app.test",
+                "https://staging.test",
+                "https://pr-1.preview.test",
+            ] {
+                assert!(matches!(
+                    origin.resolve(Some(allowed), &ctx),
+                    OriginDecision::Mirror
+                ));
+            }
+            assert!(matches!(
+                origin.resolve(Some("https://other.test"), &ctx),
+                OriginDecision::Disallow
+            ));
+        }
+
+        #[cfg(feature = "regex")]
+        #[test]
+        fn should_return_pattern_error_when_env_entry_invalid_then_name_variable() {
+            let name = "BUNNER_CORS_TEST_FROM_ENV_BAD_PATTERN";
+            set_env(name, "https://app.test,re:(");
+
+            let result = Origin::from_env(name);
+
+            match result {
+                Err(OriginEnvError::Pattern {
+                    name: variable,
+                    source: PatternError::Build { pattern, .. },
+                }) => {
+                    assert_eq!(variable, name);
+                    assert_eq!(pattern, "(");
+                }
+                Err(other) => panic!("unexpected env error: {other:?}"),
+                Ok(_) => panic!("expected pattern error"),
+            }
+        }
+
+        #[test]
+        fn should_return_var_error_when_env_unset_then_report_not_present() {
+            let result = Origin::from_env("BUNNER_CORS_TEST_FROM_ENV_UNSET");
+
+            assert!(matches!(
+                result,
+                Err(OriginEnvError::Var {
+                    source: std::env::VarError::NotPresent,
+                    ..
+                })
+            ));
+        }
+    }
+
     mod predicate {
         use super::*;
 
@@ -589,6 +1274,17 @@ mod origin_type {
         }
     }
 
+    mod predicate_value {
+        use super::*;
+
+        #[test]
+        fn should_store_predicate_value_when_callable_provided_then_capture_logic() {
+            let origin = Origin::predicate_value(|origin, _| Some(origin.to_string()));
+
+            assert!(matches!(origin, Origin::PredicateValue(_)));
+        }
+    }
+
     mod custom {
         use super::*;
 
@@ -614,6 +1310,98 @@ mod origin_type {
         }
     }
 
+    mod kind {
+        use super::*;
+
+        #[test]
+        fn should_report_matching_kind_when_each_constructor_used_then_describe_policy() {
+            let cases = [
+                (Origin::any(), OriginKind::Any),
+                (Origin::exact("https://api.test"), OriginKind::Exact),
+                (Origin::list(["https://api.test"]), OriginKind::List),
+                (Origin::predicate(|_, _| true), OriginKind::Predicate),
+                (
+                    Origin::predicate_value(|origin, _| Some(origin.to_string())),
+                    OriginKind::Predicate,
+                ),
+                (
+                    Origin::custom(|_, _| OriginDecision::Mirror),
+                    OriginKind::Custom,
+                ),
+                (Origin::disabled(), OriginKind::Disabled),
+            ];
+
+            for (origin, expected) in cases {
+                assert_eq!(origin.kind(), expected);
+            }
+        }
+
+        #[test]
+        fn should_report_custom_when_callback_only_skips_then_not_treat_as_disabled() {
+            let origin = Origin::custom(|_, _| OriginDecision::Skip);
+
+            assert_eq!(origin.kind(), OriginKind::Custom);
+        }
+
+        #[test]
+        fn should_keep_disabled_kind_when_origin_cloned_then_share_callback() {
+            let origin = Origin::disabled();
+
+            assert_eq!(origin.clone().kind(), OriginKind::Disabled);
+        }
+    }
+
+    mod from_str {
+        use super::*;
+
+        #[test]
+        fn should_build_exact_origin_when_str_converted_then_capture_value() {
+            let origin: Origin = "https://api.test".into();
+
+            match origin {
+                Origin::Exact(value) => assert_eq!(value, "https://api.test"),
+                _ => panic!("expected exact origin"),
+            }
+        }
+    }
+
+    mod from_string {
+        use super::*;
+
+        #[test]
+        fn should_build_exact_origin_when_string_converted_then_capture_value() {
+            let origin = Origin::from(String::from("https://api.test"));
+
+            match origin {
+                Origin::Exact(value) => assert_eq!(value, "https://api.test"),
+                _ => panic!("expected exact origin"),
+            }
+        }
+    }
+
+    mod from_vec {
+        use super::*;
+
+        #[test]
+        fn should_build_list_of_exact_matchers_when_vec_converted_then_keep_order() {
+            let origin: Origin = vec!["https://api.test", "https://other.test"].into();
+
+            match origin {
+                Origin::List(values) => {
+                    let collected: Vec<_> = values
+                        .iter()
+                        .map(|matcher| match matcher {
+                            OriginMatcher::Exact(value) => value.as_str(),
+                            _ => "unexpected",
+                        })
+                        .collect();
+                    assert_eq!(collected, vec!["https://api.test", "https://other.test"]);
+                }
+                _ => panic!("expected list origin"),
+            }
+        }
+    }
+
     mod resolve {
         use super::*;
 
@@ -844,6 +1632,43 @@ mod origin_type {
             assert!(!invoked.load(Ordering::Relaxed));
         }
 
+        #[test]
+        fn should_return_exact_decision_when_predicate_value_returns_some_then_use_override() {
+            let origin = Origin::predicate_value(|value, _| {
+                value
+                    .strip_suffix('/')
+                    .filter(|trimmed| *trimmed == "https://app.test")
+                    .map(str::to_string)
+            });
+            let ctx = request_context("GET", Some("https://app.test/"));
+
+            let decision = origin.resolve(Some("https://app.test/"), &ctx);
+
+            assert!(
+                matches!(decision, OriginDecision::Exact(value) if value == "https://app.test")
+            );
+        }
+
+        #[test]
+        fn should_return_disallow_decision_when_predicate_value_returns_none_then_block_request() {
+            let origin = Origin::predicate_value(|_, _| None);
+            let ctx = request_context("GET", Some("https://api.test"));
+
+            let decision = origin.resolve(Some("https://api.test"), &ctx);
+
+            assert!(matches!(decision, OriginDecision::Disallow));
+        }
+
+        #[test]
+        fn should_return_skip_decision_when_predicate_value_origin_missing_then_avoid_invocation() {
+            let origin = Origin::predicate_value(|_, _| Some("https://api.test".into()));
+            let ctx = request_context("GET", None);
+
+            let decision = origin.resolve(None, &ctx);
+
+            assert!(matches!(decision, OriginDecision::Skip));
+        }
+
         #[test]
         fn should_forward_decision_when_custom_callback_returns_value_then_propagate_result() {
             let origin = Origin::custom(|_, _| OriginDecision::Exact("https://custom.test".into()));
@@ -893,4 +1718,38 @@ mod origin_type {
             assert!(vary);
         }
     }
+
+    mod without_trailing_slashes {
+        use super::*;
+
+        #[test]
+        fn should_strip_single_slash_when_origin_exact_then_return_canonical_value() {
+            let origin = Origin::exact("https://api.test/");
+
+            let stripped = origin.without_trailing_slashes();
+
+            assert!(matches!(stripped, Origin::Exact(ref value) if value == "https://api.test"));
+        }
+
+        #[cfg(feature = "regex")]
+        #[test]
+        fn should_strip_exact_entries_when_origin_list_then_keep_other_matchers() {
+            let origin = Origin::list([
+                OriginMatcher::exact("https://api.test/"),
+                OriginMatcher::pattern_str(r"^https://.*\.test/$").unwrap(),
+            ]);
+            let ctx = request_context("GET", Some("https://api.test"));
+
+            let stripped = origin.without_trailing_slashes();
+
+            assert!(matches!(
+                stripped.resolve(Some("https://api.test"), &ctx),
+                OriginDecision::Mirror
+            ));
+            assert!(matches!(
+                stripped.resolve(Some("https://app.test/"), &ctx),
+                OriginDecision::Mirror
+            ));
+        }
+    }
 }
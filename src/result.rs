@@ -1,10 +1,13 @@
-use crate::headers::Headers;
+use crate::constants::header;
+use crate::headers::{HeaderSink, Headers, drain_into_sink};
+use crate::util::starts_with_ignore_ascii_case;
 use thiserror::Error;
 
 /// Reason a simple (non-preflight) request was rejected.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum SimpleRejectionReason {
     OriginNotAllowed,
+    MethodNotAllowed { requested_method: String },
 }
 
 /// Details describing why the request was blocked, including headers that still
@@ -13,14 +16,46 @@ pub enum SimpleRejectionReason {
 pub struct SimpleRejection {
     pub headers: Headers,
     pub reason: SimpleRejectionReason,
+    /// Response status from [`CorsOptions::rejection_status`](crate::CorsOptions::rejection_status).
+    pub status: u16,
+}
+
+impl SimpleRejection {
+    /// Status code and headers for a rejection response; see
+    /// [`PreflightRejection::to_response_parts`].
+    pub fn to_response_parts(&self) -> (u16, Vec<(String, String)>) {
+        (self.status, rejection_headers(&self.headers))
+    }
 }
 
 /// Fine-grained status describing why a preflight request failed.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum PreflightRejectionReason {
     OriginNotAllowed,
-    MethodNotAllowed { requested_method: String },
-    HeadersNotAllowed { requested_headers: String },
+    /// The preflight carried no `Origin` header. Only reported with
+    /// [`CorsOptions::require_origin_on_preflight`](crate::CorsOptions::require_origin_on_preflight).
+    OriginMissing,
+    /// An `OPTIONS` request carried `Origin` but no
+    /// `Access-Control-Request-Method`. Only reported with
+    /// [`CorsOptions::strict_preflight`](crate::CorsOptions::strict_preflight).
+    RequestMethodMissing,
+    MethodNotAllowed {
+        requested_method: String,
+    },
+    MalformedRequestMethod {
+        requested_method: String,
+    },
+    HeadersNotAllowed {
+        requested_headers: String,
+    },
+    HeadersTooLarge {
+        length: usize,
+        max: usize,
+    },
+    /// Every failed check, in evaluation order. Only reported with
+    /// [`CorsOptions::aggregate_rejections`](crate::CorsOptions::aggregate_rejections)
+    /// when more than one check fails.
+    Multiple(Vec<PreflightRejectionReason>),
 }
 
 /// Wrapper struct that exposes the rejection reason alongside the headers that
@@ -29,18 +64,181 @@ pub enum PreflightRejectionReason {
 pub struct PreflightRejection {
     pub headers: Headers,
     pub reason: PreflightRejectionReason,
+    /// Response status from [`CorsOptions::rejection_status`](crate::CorsOptions::rejection_status).
+    pub status: u16,
+}
+
+impl PreflightRejection {
+    /// Status code and headers for a rejection response, so frameworks answer
+    /// rejected preflights consistently.
+    ///
+    /// The status is [`PreflightRejection::status`], `403 Forbidden` unless
+    /// [`CorsOptions::rejection_status`](crate::CorsOptions::rejection_status)
+    /// changes it. Headers are sorted by name, and any `Access-Control-Allow-*`
+    /// header is dropped so a rejection never grants access; what remains is
+    /// typically `Vary: Origin`.
+    pub fn to_response_parts(&self) -> (u16, Vec<(String, String)>) {
+        (self.status, rejection_headers(&self.headers))
+    }
+}
+
+fn rejection_headers(headers: &Headers) -> Vec<(String, String)> {
+    let mut headers: Vec<(String, String)> = headers
+        .iter()
+        .filter(|(name, _)| !starts_with_ignore_ascii_case(name, "Access-Control-Allow-"))
+        .map(|(name, value)| (name.clone(), value.clone()))
+        .collect();
+    headers.sort();
+    headers
 }
 
 /// Outcome of evaluating a request against the configured CORS policy.
 #[derive(Debug, Clone)]
 pub enum CorsDecision {
-    PreflightAccepted { headers: Headers },
+    /// `empty_body` signals that the preflight response must not carry a body;
+    /// it is currently always `true`. `granted` summarizes what `headers` grant.
+    /// `matched_origin` is the origin granted to the requester, as in
+    /// [`CorsDecision::SimpleAccepted`].
+    PreflightAccepted {
+        headers: Headers,
+        empty_body: bool,
+        granted: PreflightGrants,
+        matched_origin: Option<String>,
+    },
     PreflightRejected(PreflightRejection),
-    SimpleAccepted { headers: Headers },
+    /// `matched_origin` is the origin granted to the requester: the mirrored
+    /// origin, the configured fixed value, or `*` when any origin is allowed.
+    /// It names the requester even when
+    /// [`CorsOptions::allow_origin_multi`](crate::CorsOptions::allow_origin_multi)
+    /// emits a whole cache group.
+    SimpleAccepted {
+        headers: Headers,
+        matched_origin: Option<String>,
+    },
     SimpleRejected(SimpleRejection),
+    /// A simple request without an `Origin` header under
+    /// [`Origin::Any`](crate::Origin::Any): the policy allows it, but no CORS
+    /// headers apply. Unlike [`CorsDecision::NotApplicable`], the request was
+    /// evaluated rather than skipped.
+    AllowedNoHeaders,
+    NotApplicable,
+}
+
+/// What an accepted preflight response grants, so observability layers need not
+/// parse the response headers.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PreflightGrants {
+    /// `Access-Control-Allow-Private-Network: true` was emitted.
+    pub private_network: bool,
+    /// Value of the emitted `Access-Control-Max-Age`, if any.
+    pub max_age: Option<u64>,
+    /// `Access-Control-Allow-Credentials: true` was emitted.
+    pub credentials: bool,
+}
+
+impl PreflightGrants {
+    pub(crate) fn from_headers(headers: &Headers) -> Self {
+        let is_true = |name: &str| headers.get(name).is_some_and(|value| value == "true");
+        Self {
+            private_network: is_true(header::ACCESS_CONTROL_ALLOW_PRIVATE_NETWORK),
+            max_age: headers
+                .get(header::ACCESS_CONTROL_MAX_AGE)
+                .and_then(|value| value.parse().ok()),
+            credentials: is_true(header::ACCESS_CONTROL_ALLOW_CREDENTIALS),
+        }
+    }
+}
+
+/// Outcome of [`Cors::check_websocket`](crate::Cors::check_websocket).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WebSocketDecision {
+    /// Whether the upgrade should be accepted.
+    pub allowed: bool,
+    /// The origin the policy accepted, or `*` when any origin is allowed.
+    /// `None` when rejected or when the request carried no origin.
+    pub allow_origin: Option<String>,
+}
+
+/// Mismatches found by [`Cors::audit`](crate::Cors::audit).
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AuditReport {
+    /// Origins expected to be allowed that the origin policy rejects.
+    pub unexpectedly_blocked: Vec<String>,
+    /// Origins expected to be blocked that the origin policy allows.
+    pub unexpectedly_allowed: Vec<String>,
+}
+
+impl AuditReport {
+    /// Returns `true` when every origin matched its expectation.
+    pub fn is_clean(&self) -> bool {
+        self.unexpectedly_blocked.is_empty() && self.unexpectedly_allowed.is_empty()
+    }
+}
+
+/// Payload-free mirror of [`CorsDecision`] for branching without destructuring.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DecisionKind {
+    PreflightAccepted,
+    PreflightRejected,
+    SimpleAccepted,
+    SimpleRejected,
+    AllowedNoHeaders,
     NotApplicable,
 }
 
+impl CorsDecision {
+    /// Returns the [`DecisionKind`] of this decision.
+    pub fn kind(&self) -> DecisionKind {
+        match self {
+            CorsDecision::PreflightAccepted { .. } => DecisionKind::PreflightAccepted,
+            CorsDecision::PreflightRejected(_) => DecisionKind::PreflightRejected,
+            CorsDecision::SimpleAccepted { .. } => DecisionKind::SimpleAccepted,
+            CorsDecision::SimpleRejected(_) => DecisionKind::SimpleRejected,
+            CorsDecision::AllowedNoHeaders => DecisionKind::AllowedNoHeaders,
+            CorsDecision::NotApplicable => DecisionKind::NotApplicable,
+        }
+    }
+
+    /// Returns the headers carried by this decision, if any.
+    pub(crate) fn headers(&self) -> Option<&Headers> {
+        match self {
+            CorsDecision::PreflightAccepted { headers, .. }
+            | CorsDecision::SimpleAccepted { headers, .. } => Some(headers),
+            CorsDecision::PreflightRejected(rejection) => Some(&rejection.headers),
+            CorsDecision::SimpleRejected(rejection) => Some(&rejection.headers),
+            CorsDecision::AllowedNoHeaders | CorsDecision::NotApplicable => None,
+        }
+    }
+
+    /// Moves this decision's headers into `sink`, leaving an empty map behind.
+    pub(crate) fn drain_headers_into(&mut self, sink: &mut impl HeaderSink, canonical: bool) {
+        let headers = match self {
+            CorsDecision::PreflightAccepted { headers, .. }
+            | CorsDecision::SimpleAccepted { headers, .. } => headers,
+            CorsDecision::PreflightRejected(rejection) => &mut rejection.headers,
+            CorsDecision::SimpleRejected(rejection) => &mut rejection.headers,
+            CorsDecision::AllowedNoHeaders | CorsDecision::NotApplicable => return,
+        };
+        drain_into_sink(std::mem::take(headers), sink, canonical);
+    }
+
+    /// Returns `true` when the response will carry
+    /// `Access-Control-Allow-Credentials: true`.
+    ///
+    /// Only accepted decisions can allow credentials; rejections,
+    /// [`CorsDecision::AllowedNoHeaders`], and [`CorsDecision::NotApplicable`]
+    /// always return `false`.
+    pub fn allows_credentials(&self) -> bool {
+        match self {
+            CorsDecision::PreflightAccepted { headers, .. }
+            | CorsDecision::SimpleAccepted { headers, .. } => headers
+                .get(header::ACCESS_CONTROL_ALLOW_CREDENTIALS)
+                .is_some_and(|value| value == "true"),
+            _ => false,
+        }
+    }
+}
+
 /// Errors raised when the CORS engine detects misbehaviour in user-provided callbacks.
 #[derive(Debug, Error, Clone, PartialEq, Eq)]
 pub enum CorsError {
@@ -49,3 +247,7 @@ pub enum CorsError {
     )]
     InvalidOriginAnyWithCredentials,
 }
+
+#[cfg(test)]
+#[path = "result_test.rs"]
+mod result_test;
@@ -0,0 +1,146 @@
+use super::*;
+
+mod kind {
+    use super::*;
+
+    #[test]
+    fn should_map_each_decision_when_kind_called_then_return_matching_variant() {
+        let cases = [
+            (
+                CorsDecision::PreflightAccepted {
+                    headers: Headers::new(),
+                    empty_body: true,
+                    granted: PreflightGrants::default(),
+                    matched_origin: None,
+                },
+                DecisionKind::PreflightAccepted,
+            ),
+            (
+                CorsDecision::PreflightRejected(PreflightRejection {
+                    headers: Headers::new(),
+                    reason: PreflightRejectionReason::OriginNotAllowed,
+                    status: 403,
+                }),
+                DecisionKind::PreflightRejected,
+            ),
+            (
+                CorsDecision::SimpleAccepted {
+                    headers: Headers::new(),
+                    matched_origin: None,
+                },
+                DecisionKind::SimpleAccepted,
+            ),
+            (
+                CorsDecision::SimpleRejected(SimpleRejection {
+                    headers: Headers::new(),
+                    reason: SimpleRejectionReason::OriginNotAllowed,
+                    status: 403,
+                }),
+                DecisionKind::SimpleRejected,
+            ),
+            (
+                CorsDecision::AllowedNoHeaders,
+                DecisionKind::AllowedNoHeaders,
+            ),
+            (CorsDecision::NotApplicable, DecisionKind::NotApplicable),
+        ];
+
+        for (decision, expected) in cases {
+            assert_eq!(decision.kind(), expected);
+        }
+    }
+}
+
+mod allows_credentials {
+    use super::*;
+    use crate::{Cors, CorsOptions, Origin, RequestContext};
+
+    fn simple_request(origin: &str) -> RequestContext<'_> {
+        RequestContext {
+            method: "GET",
+            origin: Some(origin),
+            access_control_request_method: None,
+            access_control_request_headers: None,
+            access_control_request_private_network: false,
+            sec_fetch_site: None,
+            host: None,
+        }
+    }
+
+    fn check(credentials: bool) -> CorsDecision {
+        let cors = Cors::new(
+            CorsOptions::new()
+                .origin(Origin::list(["https://app.test"]))
+                .credentials(credentials),
+        )
+        .expect("valid CORS configuration");
+
+        cors.check(&simple_request("https://app.test"))
+            .expect("simple request evaluation should succeed")
+    }
+
+    #[test]
+    fn should_return_true_when_credentialed_simple_request_accepted_then_report_credentials() {
+        let decision = check(true);
+
+        assert!(decision.allows_credentials());
+    }
+
+    #[test]
+    fn should_return_false_when_simple_request_accepted_without_credentials_then_report_none() {
+        let decision = check(false);
+
+        assert!(!decision.allows_credentials());
+    }
+
+    #[test]
+    fn should_return_false_when_decision_rejected_then_ignore_headers() {
+        let mut headers = Headers::new();
+        headers.insert(
+            header::ACCESS_CONTROL_ALLOW_CREDENTIALS.to_string(),
+            "true".to_string(),
+        );
+        let decision = CorsDecision::SimpleRejected(SimpleRejection {
+            headers,
+            reason: SimpleRejectionReason::OriginNotAllowed,
+            status: 403,
+        });
+
+        assert!(!decision.allows_credentials());
+    }
+}
+
+mod to_response_parts {
+    use super::*;
+
+    #[test]
+    fn should_drop_allow_headers_when_rejection_carries_them_then_keep_safe_headers_sorted() {
+        let mut headers = Headers::new();
+        headers.insert(header::VARY.to_string(), "Origin".to_string());
+        headers.insert(
+            header::ACCESS_CONTROL_ALLOW_ORIGIN.to_string(),
+            "https://app.test".to_string(),
+        );
+        headers.insert(
+            header::ACCESS_CONTROL_ALLOW_PRIVATE_NETWORK.to_string(),
+            "true".to_string(),
+        );
+        headers.insert("X-Request-Id".to_string(), "abc".to_string());
+        let rejection = PreflightRejection {
+            headers,
+            reason: PreflightRejectionReason::OriginNotAllowed,
+            status: 451,
+        };
+
+        let (status, headers) = rejection.to_response_parts();
+
+        assert_eq!(status, 451);
+        assert_eq!(
+            headers,
+            vec![
+                (header::VARY.to_string(), "Origin".to_string()),
+                ("X-Request-Id".to_string(), "abc".to_string()),
+            ]
+        );
+    }
+}
@@ -1,10 +1,20 @@
+//! String helpers the engine uses to compare origins, methods, and header
+//! names.
+//!
+//! They are public so integrations can compare values exactly the way the
+//! crate does.
+
+use crate::constants::method;
 use std::cell::RefCell;
 
 thread_local! {
     static CASEFOLD_BUFFERS: RefCell<(String, String)> = const { RefCell::new((String::new(), String::new())) };
 }
 
-#[doc(hidden)]
+/// Lowercases `value` the way the engine normalizes names and origins.
+///
+/// ASCII input is lowercased byte-wise; other input uses Unicode lowercase
+/// mappings, so `"TÉST"` becomes `"tést"`.
 pub fn normalize_lower(value: &str) -> String {
     if value.is_ascii() {
         let mut owned = value.to_owned();
@@ -15,7 +25,11 @@ pub fn normalize_lower(value: &str) -> String {
     }
 }
 
-#[doc(hidden)]
+/// Compares two values case-insensitively, consistent with
+/// [`normalize_lower`].
+///
+/// ASCII pairs use ASCII case folding; otherwise both sides are compared after
+/// Unicode lowercasing, so `"TÉST"` equals `"tést"`.
 pub fn equals_ignore_case(a: &str, b: &str) -> bool {
     if a == b {
         return true;
@@ -52,7 +66,39 @@ pub fn equals_ignore_case(a: &str, b: &str) -> bool {
     })
 }
 
-pub(crate) fn is_http_token(value: &str) -> bool {
+pub(crate) fn starts_with_ignore_ascii_case(value: &str, prefix: &str) -> bool {
+    value
+        .get(..prefix.len())
+        .is_some_and(|head| head.eq_ignore_ascii_case(prefix))
+}
+
+pub(crate) fn strip_trailing_slash(value: &str) -> &str {
+    value.strip_suffix('/').unwrap_or(value)
+}
+
+/// Drops `:443` from an `https` origin and `:80` from an `http` origin.
+pub(crate) fn strip_default_port(value: &str) -> &str {
+    let default_port = if starts_with_ignore_ascii_case(value, "https://") {
+        ":443"
+    } else if starts_with_ignore_ascii_case(value, "http://") {
+        ":80"
+    } else {
+        return value;
+    };
+    value.strip_suffix(default_port).unwrap_or(value)
+}
+
+pub(crate) fn is_cors_safelisted_method(value: &str) -> bool {
+    [method::GET, method::HEAD, method::POST]
+        .iter()
+        .any(|safelisted| value.eq_ignore_ascii_case(safelisted))
+}
+
+/// Returns `true` when `value` is a non-empty RFC 7230 `token`, the grammar
+/// required for method and header names.
+///
+/// Non-ASCII input is never a token.
+pub fn is_http_token(value: &str) -> bool {
     !value.is_empty()
         && value.bytes().all(|byte| {
             matches!(
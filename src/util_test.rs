@@ -99,6 +99,53 @@ mod is_http_token {
     }
 }
 
+mod strip_default_port {
+    use super::*;
+
+    #[test]
+    fn should_strip_default_port_when_scheme_matches_then_return_portless_origin() {
+        assert_eq!(
+            strip_default_port("https://app.test:443"),
+            "https://app.test"
+        );
+        assert_eq!(strip_default_port("HTTP://app.test:80"), "HTTP://app.test");
+    }
+
+    #[test]
+    fn should_keep_port_when_not_default_for_scheme_then_return_input() {
+        assert_eq!(
+            strip_default_port("https://app.test:80"),
+            "https://app.test:80"
+        );
+        assert_eq!(
+            strip_default_port("http://app.test:443"),
+            "http://app.test:443"
+        );
+        assert_eq!(
+            strip_default_port("https://app.test:4443"),
+            "https://app.test:4443"
+        );
+        assert_eq!(strip_default_port("null"), "null");
+    }
+}
+
+mod is_cors_safelisted_method {
+    use super::*;
+
+    #[test]
+    fn should_return_true_when_method_safelisted_then_ignore_case() {
+        assert!(is_cors_safelisted_method("GET"));
+        assert!(is_cors_safelisted_method("head"));
+        assert!(is_cors_safelisted_method("Post"));
+    }
+
+    #[test]
+    fn should_return_false_when_method_not_safelisted_then_reject_value() {
+        assert!(!is_cors_safelisted_method("PUT"));
+        assert!(!is_cors_safelisted_method("DELETE"));
+    }
+}
+
 mod lowercase_unicode_if_needed_fn {
     use super::*;
 
@@ -0,0 +1,156 @@
+//! Origin allow-lists loaded from a file and reloaded when it changes.
+//!
+//! Enabled through the `watch` cargo feature.
+
+use crate::context::RequestContext;
+use crate::origin::{Origin, OriginDecision, PatternError};
+use arc_swap::ArcSwap;
+use notify::{RecommendedWatcher, RecursiveMode, Watcher};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use thiserror::Error;
+
+/// Errors raised while loading or watching an origin allow-list file.
+#[derive(Debug, Error)]
+pub enum OriginFileError {
+    #[error("failed to read origin file `{path}`")]
+    Io {
+        path: PathBuf,
+        #[source]
+        source: std::io::Error,
+    },
+    #[error("invalid origin file `{path}`")]
+    Pattern {
+        path: PathBuf,
+        #[source]
+        source: PatternError,
+    },
+    #[error("failed to watch origin file `{path}`")]
+    Watch {
+        path: PathBuf,
+        #[source]
+        source: notify::Error,
+    },
+}
+
+/// Allow-list parsed with [`Origin::from_lines`] from a file, swapped
+/// atomically whenever the file changes.
+///
+/// Replace the file atomically (write a sibling file, then rename it over the
+/// original) so the watcher never observes a partial write. A reload that fails
+/// to read or parse the file keeps the previous list. Watching stops when the
+/// handle is dropped; origins obtained from it keep the last loaded list.
+pub struct WatchedOriginFile {
+    shared: Arc<Shared>,
+    _watcher: Mutex<RecommendedWatcher>,
+}
+
+struct Shared {
+    path: PathBuf,
+    current: ArcSwap<Origin>,
+}
+
+impl Shared {
+    fn reload(&self) -> Result<(), OriginFileError> {
+        self.current.store(Arc::new(load(&self.path)?));
+        Ok(())
+    }
+
+    fn resolve(&self, origin: Option<&str>, ctx: &RequestContext<'_>) -> OriginDecision {
+        self.current.load().resolve(origin, ctx)
+    }
+}
+
+impl WatchedOriginFile {
+    /// Loads `path` and starts watching it for changes.
+    ///
+    /// The parent directory is watched so editors that replace the file through
+    /// a rename are picked up too.
+    pub fn new(path: impl AsRef<Path>) -> Result<Self, OriginFileError> {
+        let path = path.as_ref().to_path_buf();
+        let shared = Arc::new(Shared {
+            current: ArcSwap::from_pointee(load(&path)?),
+            path: path.clone(),
+        });
+
+        let watch_error = |source| OriginFileError::Watch {
+            path: path.clone(),
+            source,
+        };
+        let listener = Arc::clone(&shared);
+        let mut watcher =
+            notify::recommended_watcher(move |event: notify::Result<notify::Event>| {
+                let Ok(event) = event else {
+                    return;
+                };
+                if event.kind.is_modify() || event.kind.is_create() {
+                    let touches_file = event
+                        .paths
+                        .iter()
+                        .any(|changed| changed.file_name() == listener.path.file_name());
+                    if touches_file {
+                        // Keep serving the previous list until the file parses again.
+                        let _ = listener.reload();
+                    }
+                }
+            })
+            .map_err(watch_error)?;
+        let directory = match path.parent() {
+            Some(parent) if !parent.as_os_str().is_empty() => parent,
+            _ => Path::new("."),
+        };
+        watcher
+            .watch(directory, RecursiveMode::NonRecursive)
+            .map_err(watch_error)?;
+
+        Ok(Self {
+            shared,
+            _watcher: Mutex::new(watcher),
+        })
+    }
+
+    /// Re-reads the file immediately instead of waiting for a change event.
+    ///
+    /// On failure the previous list stays active.
+    pub fn reload(&self) -> Result<(), OriginFileError> {
+        self.shared.reload()
+    }
+
+    /// Returns an [`Origin`] that evaluates against the current list.
+    ///
+    /// The origin is a [`Origin::Custom`] policy, so
+    /// [`CorsOptions::validate`](crate::CorsOptions::validate) does not inspect
+    /// the file contents.
+    pub fn origin(&self) -> Origin {
+        let shared = Arc::clone(&self.shared);
+        Origin::custom(move |origin, ctx| shared.resolve(origin, ctx))
+    }
+}
+
+impl Origin {
+    /// Loads a newline-delimited allow-list (see [`Origin::from_lines`]) from
+    /// `path` and reloads it whenever the file changes.
+    ///
+    /// The watcher lives as long as the returned origin. Use
+    /// [`WatchedOriginFile`] to also trigger reloads manually.
+    pub fn from_file_watched(path: impl AsRef<Path>) -> Result<Self, OriginFileError> {
+        let watched = WatchedOriginFile::new(path)?;
+        // Capture the whole handle so the watcher lives as long as the origin.
+        Ok(Origin::custom(move |origin, ctx| {
+            let watched = &watched;
+            watched.shared.resolve(origin, ctx)
+        }))
+    }
+}
+
+fn load(path: &Path) -> Result<Origin, OriginFileError> {
+    let contents = fs::read_to_string(path).map_err(|source| OriginFileError::Io {
+        path: path.to_path_buf(),
+        source,
+    })?;
+    Origin::from_lines(&contents).map_err(|source| OriginFileError::Pattern {
+        path: path.to_path_buf(),
+        source,
+    })
+}
@@ -0,0 +1,149 @@
+#![cfg(feature = "actix")]
+
+use actix_web::http::StatusCode;
+use actix_web::{App, HttpResponse, test, web};
+use bunner_cors_rs::actix::BunnerCors;
+use bunner_cors_rs::constants::{header, method};
+use bunner_cors_rs::{AllowedHeaders, AllowedMethods, Cors, CorsOptions, ExposedHeaders, Origin};
+
+fn cors() -> Cors {
+    Cors::new(
+        CorsOptions::new()
+            .origin(Origin::list(["https://app.example"]))
+            .methods(AllowedMethods::list([method::GET, method::POST]))
+            .allowed_headers(AllowedHeaders::list(["X-Trace"]))
+            .exposed_headers(ExposedHeaders::list(["X-Trace"]))
+            .max_age(600),
+    )
+    .expect("valid CORS configuration")
+}
+
+async fn greet() -> HttpResponse {
+    HttpResponse::Ok().body("hello")
+}
+
+mod bunner_cors {
+    use super::*;
+
+    #[actix_web::test]
+    async fn should_short_circuit_preflight_when_request_allowed_then_return_no_content() {
+        let app = test::init_service(
+            App::new()
+                .wrap(BunnerCors::from(cors()))
+                .route("/greet", web::get().to(greet)),
+        )
+        .await;
+
+        let request = test::TestRequest::default()
+            .method(actix_web::http::Method::OPTIONS)
+            .uri("/greet")
+            .insert_header((header::ORIGIN, "https://app.example"))
+            .insert_header((header::ACCESS_CONTROL_REQUEST_METHOD, method::POST))
+            .insert_header((header::ACCESS_CONTROL_REQUEST_HEADERS, "X-Trace"))
+            .to_request();
+        let response = test::call_service(&app, request).await;
+
+        assert_eq!(response.status(), StatusCode::NO_CONTENT);
+        let headers = response.headers();
+        assert_eq!(
+            headers.get(header::ACCESS_CONTROL_ALLOW_ORIGIN).unwrap(),
+            "https://app.example"
+        );
+        assert_eq!(
+            headers.get(header::ACCESS_CONTROL_ALLOW_METHODS).unwrap(),
+            "GET,POST"
+        );
+        assert_eq!(
+            headers.get(header::ACCESS_CONTROL_ALLOW_HEADERS).unwrap(),
+            "X-Trace"
+        );
+        assert_eq!(headers.get(header::ACCESS_CONTROL_MAX_AGE).unwrap(), "600");
+    }
+
+    #[actix_web::test]
+    async fn should_inject_headers_when_simple_request_allowed_then_forward_to_service() {
+        let app = test::init_service(
+            App::new()
+                .wrap(BunnerCors::from(cors()))
+                .route("/greet", web::get().to(greet)),
+        )
+        .await;
+
+        let request = test::TestRequest::get()
+            .uri("/greet")
+            .insert_header((header::ORIGIN, "https://app.example"))
+            .to_request();
+        let response = test::call_service(&app, request).await;
+
+        assert_eq!(response.status(), StatusCode::OK);
+        let headers = response.headers();
+        assert_eq!(
+            headers.get(header::ACCESS_CONTROL_ALLOW_ORIGIN).unwrap(),
+            "https://app.example"
+        );
+        assert_eq!(
+            headers.get(header::ACCESS_CONTROL_EXPOSE_HEADERS).unwrap(),
+            "X-Trace"
+        );
+        assert_eq!(headers.get(header::VARY).unwrap(), "Origin");
+        let body = test::read_body(response).await;
+        assert_eq!(body, "hello");
+    }
+
+    #[actix_web::test]
+    async fn should_return_forbidden_when_origin_disallowed_then_skip_service() {
+        let app = test::init_service(
+            App::new()
+                .wrap(BunnerCors::from(cors()))
+                .route("/greet", web::get().to(greet)),
+        )
+        .await;
+
+        let request = test::TestRequest::get()
+            .uri("/greet")
+            .insert_header((header::ORIGIN, "https://evil.example"))
+            .to_request();
+        let response = test::call_service(&app, request).await;
+
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+        assert!(
+            response
+                .headers()
+                .get(header::ACCESS_CONTROL_ALLOW_ORIGIN)
+                .is_none()
+        );
+    }
+
+    #[actix_web::test]
+    async fn should_use_configured_status_when_preflight_rejected_then_send_response_parts() {
+        let cors = Cors::new(
+            CorsOptions::new()
+                .origin(Origin::list(["https://app.example"]))
+                .rejection_status(404),
+        )
+        .expect("valid CORS configuration");
+        let app = test::init_service(
+            App::new()
+                .wrap(BunnerCors::from(cors))
+                .route("/greet", web::get().to(greet)),
+        )
+        .await;
+
+        let request = test::TestRequest::default()
+            .method(actix_web::http::Method::OPTIONS)
+            .uri("/greet")
+            .insert_header((header::ORIGIN, "https://evil.example"))
+            .insert_header((header::ACCESS_CONTROL_REQUEST_METHOD, method::GET))
+            .to_request();
+        let response = test::call_service(&app, request).await;
+
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+        assert_eq!(response.headers().get(header::VARY).unwrap(), "Origin");
+        assert!(
+            response
+                .headers()
+                .get(header::ACCESS_CONTROL_ALLOW_ORIGIN)
+                .is_none()
+        );
+    }
+}
@@ -6,7 +6,7 @@ use super::headers::{header_value, vary_values};
 
 pub fn assert_simple(decision: CorsDecision) -> Headers {
     match decision {
-        CorsDecision::SimpleAccepted { headers } => headers,
+        CorsDecision::SimpleAccepted { headers, .. } => headers,
         other => panic!("expected simple acceptance, got {:?}", other),
     }
 }
@@ -20,7 +20,7 @@ pub fn assert_simple_rejected(decision: CorsDecision) -> SimpleRejection {
 
 pub fn assert_preflight(decision: CorsDecision) -> Headers {
     match decision {
-        CorsDecision::PreflightAccepted { headers } => headers,
+        CorsDecision::PreflightAccepted { headers, .. } => headers,
         CorsDecision::PreflightRejected(rejection) => rejection.headers,
         other => panic!("expected preflight decision, got {:?}", other),
     }
@@ -15,6 +15,12 @@ pub struct CorsBuilder {
     allow_null_origin: Option<bool>,
     private_network: Option<bool>,
     timing_allow_origin: Option<TimingAllowOrigin>,
+    rejection_status: Option<u16>,
+    normalize_trailing_slash: Option<bool>,
+    normalize_default_ports: Option<bool>,
+    require_https: Option<bool>,
+    require_origin_on_preflight: Option<bool>,
+    default_origin_when_missing: Option<String>,
 }
 
 impl CorsBuilder {
@@ -80,6 +86,36 @@ impl CorsBuilder {
         self
     }
 
+    pub fn rejection_status(mut self, status: u16) -> Self {
+        self.rejection_status = Some(status);
+        self
+    }
+
+    pub fn normalize_trailing_slash(mut self, enabled: bool) -> Self {
+        self.normalize_trailing_slash = Some(enabled);
+        self
+    }
+
+    pub fn normalize_default_ports(mut self, enabled: bool) -> Self {
+        self.normalize_default_ports = Some(enabled);
+        self
+    }
+
+    pub fn require_https(mut self, enabled: bool) -> Self {
+        self.require_https = Some(enabled);
+        self
+    }
+
+    pub fn require_origin_on_preflight(mut self, enabled: bool) -> Self {
+        self.require_origin_on_preflight = Some(enabled);
+        self
+    }
+
+    pub fn default_origin_when_missing(mut self, origin: impl Into<String>) -> Self {
+        self.default_origin_when_missing = Some(origin.into());
+        self
+    }
+
     pub fn build(self) -> Cors {
         let defaults = CorsOptions::new();
 
@@ -109,7 +145,28 @@ impl CorsBuilder {
                 self.private_network
                     .unwrap_or(defaults.allow_private_network),
             )
-            .credentials(credentials);
+            .credentials(credentials)
+            .rejection_status(self.rejection_status.unwrap_or(defaults.rejection_status))
+            .normalize_trailing_slash(
+                self.normalize_trailing_slash
+                    .unwrap_or(defaults.normalize_trailing_slash),
+            )
+            .normalize_default_ports(
+                self.normalize_default_ports
+                    .unwrap_or(defaults.normalize_default_ports),
+            )
+            .require_https(self.require_https.unwrap_or(defaults.require_https))
+            .require_origin_on_preflight(
+                self.require_origin_on_preflight
+                    .unwrap_or(defaults.require_origin_on_preflight),
+            );
+
+        if let Some(origin) = self
+            .default_origin_when_missing
+            .or(defaults.default_origin_when_missing.clone())
+        {
+            options = options.default_origin_when_missing(origin);
+        }
 
         if let Some(max_age) = self.max_age.or(defaults.max_age) {
             options = options.max_age(max_age);
@@ -169,6 +226,8 @@ impl SimpleRequestBuilder {
             access_control_request_method: None,
             access_control_request_headers: None,
             access_control_request_private_network: private_network,
+            sec_fetch_site: None,
+            host: None,
         };
         cors.check(&ctx)
             .expect("simple request evaluation should succeed")
@@ -222,6 +281,8 @@ impl PreflightRequestBuilder {
             access_control_request_method: request_method.as_deref(),
             access_control_request_headers: request_headers.as_deref(),
             access_control_request_private_network: private_network,
+            sec_fetch_site: None,
+            host: None,
         };
         cors.check(&ctx)
             .expect("preflight request evaluation should succeed")
@@ -1,3 +1,5 @@
+#![allow(dead_code)]
+
 pub mod asserts;
 pub mod builders;
 pub mod headers;
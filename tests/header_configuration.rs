@@ -2,7 +2,8 @@ mod common;
 
 use bunner_cors_rs::constants::{header, method};
 use bunner_cors_rs::{
-    AllowedHeaders, CorsDecision, Origin, PreflightRejectionReason, TimingAllowOrigin,
+    AllowedHeaders, Cors, CorsDecision, CorsOptions, CredentialsScope, ExposedHeaders, Origin,
+    PreflightRejectionReason, TimingAllowOrigin,
 };
 use common::asserts::{
     assert_header_eq, assert_preflight, assert_simple, assert_vary_eq, assert_vary_is_empty,
@@ -254,5 +255,592 @@ mod check {
 
             assert_header_eq(&headers, header::TIMING_ALLOW_ORIGIN, "*");
         }
+
+        #[test]
+        fn should_emit_wildcard_exposed_headers_when_any_or_without_credentials_then_return_star() {
+            let cors = cors()
+                .exposed_headers_config(ExposedHeaders::any_or(["X-Trace", "X-Span"]))
+                .build();
+
+            let headers = assert_simple(simple_request().origin("https://foo.bar").check(&cors));
+
+            assert_header_eq(&headers, header::ACCESS_CONTROL_EXPOSE_HEADERS, "*");
+        }
+
+        #[test]
+        fn should_emit_fallback_list_when_any_or_with_credentials_then_return_explicit_headers() {
+            let cors = cors()
+                .exposed_headers_config(ExposedHeaders::any_or(["X-Trace", "X-Span"]))
+                .credentials(true)
+                .build();
+
+            let headers = assert_simple(simple_request().origin("https://foo.bar").check(&cors));
+
+            assert_header_eq(
+                &headers,
+                header::ACCESS_CONTROL_EXPOSE_HEADERS,
+                "X-Trace,X-Span",
+            );
+        }
+
+        #[test]
+        fn should_emit_wildcard_and_names_when_any_with_configured_then_return_combined_value() {
+            let cors = cors()
+                .exposed_headers_config(ExposedHeaders::any_with(["X-Trace", "X-Span"]))
+                .build();
+
+            let headers = assert_simple(simple_request().origin("https://foo.bar").check(&cors));
+
+            assert_header_eq(
+                &headers,
+                header::ACCESS_CONTROL_EXPOSE_HEADERS,
+                "*,X-Trace,X-Span",
+            );
+        }
+    }
+}
+
+mod credentials_scope {
+    use super::*;
+
+    fn scoped_cors(scope: CredentialsScope) -> Cors {
+        Cors::new(
+            CorsOptions::new()
+                .origin(Origin::list(["https://app.example"]))
+                .credentials(true)
+                .credentials_scope(scope),
+        )
+        .expect("valid CORS configuration")
+    }
+
+    fn preflight_has_credentials(cors: &Cors) -> bool {
+        let headers = assert_preflight(
+            preflight_request()
+                .origin("https://app.example")
+                .request_method(method::POST)
+                .check(cors),
+        );
+        has_header(&headers, header::ACCESS_CONTROL_ALLOW_CREDENTIALS)
+    }
+
+    fn simple_has_credentials(cors: &Cors) -> bool {
+        let headers = assert_simple(simple_request().origin("https://app.example").check(cors));
+        has_header(&headers, header::ACCESS_CONTROL_ALLOW_CREDENTIALS)
+    }
+
+    #[test]
+    fn should_emit_credentials_on_both_when_scope_both_then_include_header_everywhere() {
+        let cors = scoped_cors(CredentialsScope::Both);
+
+        assert!(preflight_has_credentials(&cors));
+        assert!(simple_has_credentials(&cors));
+    }
+
+    #[test]
+    fn should_emit_credentials_on_preflight_only_when_scope_preflight_only_then_omit_on_simple() {
+        let cors = scoped_cors(CredentialsScope::PreflightOnly);
+
+        assert!(preflight_has_credentials(&cors));
+        assert!(!simple_has_credentials(&cors));
+    }
+
+    #[test]
+    fn should_emit_credentials_on_simple_only_when_scope_simple_only_then_omit_on_preflight() {
+        let cors = scoped_cors(CredentialsScope::SimpleOnly);
+
+        assert!(!preflight_has_credentials(&cors));
+        assert!(simple_has_credentials(&cors));
+    }
+
+    #[test]
+    fn should_omit_credentials_when_credentials_disabled_then_ignore_scope() {
+        let cors = Cors::new(
+            CorsOptions::new()
+                .origin(Origin::list(["https://app.example"]))
+                .credentials_scope(CredentialsScope::Both),
+        )
+        .expect("valid CORS configuration");
+
+        assert!(!preflight_has_credentials(&cors));
+        assert!(!simple_has_credentials(&cors));
+    }
+
+    fn simple_exposed_headers(scope: CredentialsScope) -> bunner_cors_rs::Headers {
+        let cors = Cors::new(
+            CorsOptions::new()
+                .origin(Origin::list(["https://app.example"]))
+                .credentials(true)
+                .credentials_scope(scope)
+                .exposed_headers(ExposedHeaders::any_or(["X-Trace"])),
+        )
+        .expect("valid CORS configuration");
+
+        assert_simple(simple_request().origin("https://app.example").check(&cors))
+    }
+
+    #[test]
+    fn should_expose_wildcard_when_simple_response_not_credentialed_then_use_any() {
+        let headers = simple_exposed_headers(CredentialsScope::PreflightOnly);
+
+        assert!(!has_header(
+            &headers,
+            header::ACCESS_CONTROL_ALLOW_CREDENTIALS
+        ));
+        assert_header_eq(&headers, header::ACCESS_CONTROL_EXPOSE_HEADERS, "*");
+    }
+
+    #[test]
+    fn should_expose_explicit_list_when_simple_response_credentialed_then_fall_back() {
+        let headers = simple_exposed_headers(CredentialsScope::SimpleOnly);
+
+        assert_header_eq(&headers, header::ACCESS_CONTROL_ALLOW_CREDENTIALS, "true");
+        assert_header_eq(&headers, header::ACCESS_CONTROL_EXPOSE_HEADERS, "X-Trace");
+    }
+}
+
+mod extra_response_headers {
+    use super::*;
+    use common::asserts::assert_simple_rejected;
+
+    fn tagged_cors() -> Cors {
+        Cors::new(
+            CorsOptions::new()
+                .origin(Origin::list(["https://app.example"]))
+                .extra_response_headers([("X-CORS-Handled", "bunner")]),
+        )
+        .expect("valid CORS configuration")
+    }
+
+    #[test]
+    fn should_append_extra_header_when_preflight_accepted_then_include_static_value() {
+        let cors = tagged_cors();
+
+        let headers = assert_preflight(
+            preflight_request()
+                .origin("https://app.example")
+                .request_method(method::POST)
+                .check(&cors),
+        );
+
+        assert_header_eq(&headers, "X-CORS-Handled", "bunner");
+    }
+
+    #[test]
+    fn should_append_extra_header_when_simple_accepted_then_include_static_value() {
+        let cors = tagged_cors();
+
+        let headers = assert_simple(simple_request().origin("https://app.example").check(&cors));
+
+        assert_header_eq(&headers, "X-CORS-Handled", "bunner");
+    }
+
+    #[test]
+    fn should_omit_extra_header_when_origin_disallowed_then_keep_rejection_minimal() {
+        let cors = tagged_cors();
+
+        let rejection =
+            assert_simple_rejected(simple_request().origin("https://evil.example").check(&cors));
+
+        assert!(!has_header(&rejection.headers, "X-CORS-Handled"));
+    }
+
+    #[test]
+    fn should_omit_extra_header_when_preflight_rejected_then_keep_rejection_minimal() {
+        let cors = tagged_cors();
+
+        let decision = preflight_request()
+            .origin("https://evil.example")
+            .request_method(method::POST)
+            .check(&cors);
+
+        match decision {
+            CorsDecision::PreflightRejected(rejection) => {
+                assert!(!has_header(&rejection.headers, "X-CORS-Handled"));
+            }
+            other => panic!("expected preflight rejection, got {other:?}"),
+        }
+    }
+}
+
+mod credentialed_origins {
+    use super::*;
+    use common::asserts::assert_simple_rejected;
+
+    fn split_cors() -> Cors {
+        Cors::new(
+            CorsOptions::new()
+                .origin(Origin::list([
+                    "https://public.example",
+                    "https://docs.example",
+                ]))
+                .credentialed_origins(Origin::list(["https://app.example"])),
+        )
+        .expect("valid CORS configuration")
+    }
+
+    #[test]
+    fn should_emit_credentials_when_origin_in_credentialed_set_then_reflect_origin() {
+        let cors = split_cors();
+
+        let simple = assert_simple(simple_request().origin("https://app.example").check(&cors));
+        let preflight = assert_preflight(
+            preflight_request()
+                .origin("https://app.example")
+                .request_method(method::POST)
+                .check(&cors),
+        );
+
+        for headers in [&simple, &preflight] {
+            assert_header_eq(
+                headers,
+                header::ACCESS_CONTROL_ALLOW_ORIGIN,
+                "https://app.example",
+            );
+            assert_header_eq(headers, header::ACCESS_CONTROL_ALLOW_CREDENTIALS, "true");
+        }
+    }
+
+    #[test]
+    fn should_omit_credentials_when_origin_only_in_fallback_set_then_allow_without_credentials() {
+        let cors = split_cors();
+
+        let headers = assert_simple(
+            simple_request()
+                .origin("https://public.example")
+                .check(&cors),
+        );
+
+        assert_header_eq(
+            &headers,
+            header::ACCESS_CONTROL_ALLOW_ORIGIN,
+            "https://public.example",
+        );
+        assert!(!has_header(
+            &headers,
+            header::ACCESS_CONTROL_ALLOW_CREDENTIALS
+        ));
+    }
+
+    #[test]
+    fn should_reject_when_origin_in_neither_set_then_report_origin_not_allowed() {
+        let cors = split_cors();
+
+        let rejection =
+            assert_simple_rejected(simple_request().origin("https://evil.example").check(&cors));
+
+        assert!(!has_header(
+            &rejection.headers,
+            header::ACCESS_CONTROL_ALLOW_CREDENTIALS
+        ));
+        assert!(!has_header(
+            &rejection.headers,
+            header::ACCESS_CONTROL_ALLOW_ORIGIN
+        ));
+    }
+
+    #[test]
+    fn should_keep_wildcard_fallback_when_origin_any_then_credential_only_listed_origins() {
+        let cors = Cors::new(
+            CorsOptions::new()
+                .credentialed_origins(Origin::list(["https://app.example"]))
+                .exposed_headers(ExposedHeaders::any_or(["X-Trace"])),
+        )
+        .expect("valid CORS configuration");
+
+        let credentialed =
+            assert_simple(simple_request().origin("https://app.example").check(&cors));
+        let anonymous = assert_simple(
+            simple_request()
+                .origin("https://other.example")
+                .check(&cors),
+        );
+
+        assert_header_eq(
+            &credentialed,
+            header::ACCESS_CONTROL_ALLOW_CREDENTIALS,
+            "true",
+        );
+        assert_header_eq(
+            &credentialed,
+            header::ACCESS_CONTROL_EXPOSE_HEADERS,
+            "X-Trace",
+        );
+        assert_header_eq(&anonymous, header::ACCESS_CONTROL_ALLOW_ORIGIN, "*");
+        assert_vary_eq(&anonymous, [header::ORIGIN]);
+        assert_header_eq(&anonymous, header::ACCESS_CONTROL_EXPOSE_HEADERS, "*");
+        assert!(!has_header(
+            &anonymous,
+            header::ACCESS_CONTROL_ALLOW_CREDENTIALS
+        ));
+    }
+
+    #[test]
+    fn should_drop_wildcard_exposed_headers_when_response_credentialed_then_keep_it_for_anonymous()
+    {
+        let cors = Cors::new(
+            CorsOptions::new()
+                .credentialed_origins(Origin::list(["https://app.example"]))
+                .exposed_headers(ExposedHeaders::any_or(Vec::<String>::new())),
+        )
+        .expect("valid CORS configuration");
+
+        let credentialed =
+            assert_simple(simple_request().origin("https://app.example").check(&cors));
+        let anonymous = assert_simple(
+            simple_request()
+                .origin("https://other.example")
+                .check(&cors),
+        );
+
+        assert_header_eq(
+            &credentialed,
+            header::ACCESS_CONTROL_ALLOW_CREDENTIALS,
+            "true",
+        );
+        assert!(!has_header(
+            &credentialed,
+            header::ACCESS_CONTROL_EXPOSE_HEADERS
+        ));
+        assert_header_eq(&anonymous, header::ACCESS_CONTROL_EXPOSE_HEADERS, "*");
+    }
+}
+
+mod max_age_only_for_exact_origin {
+    use super::*;
+
+    fn preflight_headers(origin: Origin, restricted: bool) -> bunner_cors_rs::Headers {
+        let cors = Cors::new(
+            CorsOptions::new()
+                .origin(origin)
+                .max_age(600)
+                .max_age_only_for_exact_origin(restricted),
+        )
+        .expect("valid CORS configuration");
+
+        assert_preflight(
+            preflight_request()
+                .origin("https://app.example")
+                .request_method(method::POST)
+                .check(&cors),
+        )
+    }
+
+    #[test]
+    fn should_emit_max_age_when_origin_any_and_toggle_disabled_then_keep_default() {
+        let headers = preflight_headers(Origin::Any, false);
+
+        assert_header_eq(&headers, header::ACCESS_CONTROL_MAX_AGE, "600");
+    }
+
+    #[test]
+    fn should_omit_max_age_when_origin_any_and_toggle_enabled_then_skip_cache_hint() {
+        let headers = preflight_headers(Origin::Any, true);
+
+        assert_header_eq(&headers, header::ACCESS_CONTROL_ALLOW_ORIGIN, "*");
+        assert!(!has_header(&headers, header::ACCESS_CONTROL_MAX_AGE));
+    }
+
+    #[test]
+    fn should_emit_max_age_when_exact_origin_and_toggle_enabled_then_keep_cache_hint() {
+        let headers = preflight_headers(Origin::exact("https://app.example"), true);
+
+        assert_header_eq(&headers, header::ACCESS_CONTROL_MAX_AGE, "600");
+    }
+
+    #[test]
+    fn should_emit_max_age_when_list_origin_and_toggle_enabled_then_keep_cache_hint() {
+        let headers = preflight_headers(Origin::list(["https://app.example"]), true);
+
+        assert_header_eq(&headers, header::ACCESS_CONTROL_MAX_AGE, "600");
+    }
+}
+
+mod list_echo_case {
+    use super::*;
+
+    fn preflight_headers(allowed: AllowedHeaders, requested: &str) -> bunner_cors_rs::Headers {
+        let cors = cors().allowed_headers(allowed).build();
+
+        assert_preflight(
+            preflight_request()
+                .origin("https://app.example")
+                .request_method(method::POST)
+                .request_headers(requested)
+                .check(&cors),
+        )
+    }
+
+    #[test]
+    fn should_emit_requested_casing_when_header_overlaps_static_list_then_echo_client_token() {
+        let headers = preflight_headers(
+            AllowedHeaders::list_echo_case(["Content-Type"]),
+            "content-type",
+        );
+
+        assert_header_eq(
+            &headers,
+            header::ACCESS_CONTROL_ALLOW_HEADERS,
+            "content-type",
+        );
+        assert_vary_eq(&headers, [header::ACCESS_CONTROL_REQUEST_HEADERS]);
+    }
+
+    #[test]
+    fn should_keep_configured_casing_when_header_not_requested_then_emit_static_entry() {
+        let headers = preflight_headers(
+            AllowedHeaders::list_echo_case(["Content-Type", "X-Api-Key"]),
+            "x-API-key",
+        );
+
+        assert_header_eq(
+            &headers,
+            header::ACCESS_CONTROL_ALLOW_HEADERS,
+            "Content-Type,x-API-key",
+        );
+    }
+
+    #[test]
+    fn should_reject_preflight_when_header_not_listed_then_validate_like_list() {
+        let cors = cors()
+            .allowed_headers(AllowedHeaders::list_echo_case(["Content-Type"]))
+            .build();
+
+        let decision = preflight_request()
+            .origin("https://app.example")
+            .request_method(method::POST)
+            .request_headers("x-debug")
+            .check(&cors);
+
+        assert!(matches!(
+            decision,
+            CorsDecision::PreflightRejected(rejection)
+                if matches!(rejection.reason, PreflightRejectionReason::HeadersNotAllowed { .. })
+        ));
+    }
+}
+
+mod emit_vary {
+    use super::*;
+
+    fn exact_origin_cors(emit_vary: bool) -> Cors {
+        Cors::new(
+            CorsOptions::new()
+                .origin(Origin::exact("https://app.example"))
+                .emit_vary(emit_vary),
+        )
+        .expect("valid CORS configuration")
+    }
+
+    #[test]
+    fn should_emit_vary_when_flag_default_then_keep_origin_vary() {
+        let headers = assert_simple(
+            simple_request()
+                .origin("https://app.example")
+                .check(&exact_origin_cors(true)),
+        );
+
+        assert_vary_eq(&headers, [header::ORIGIN]);
+    }
+
+    #[test]
+    fn should_omit_vary_when_flag_disabled_then_still_allow_origin() {
+        let headers = assert_simple(
+            simple_request()
+                .origin("https://app.example")
+                .check(&exact_origin_cors(false)),
+        );
+
+        assert_header_eq(
+            &headers,
+            header::ACCESS_CONTROL_ALLOW_ORIGIN,
+            "https://app.example",
+        );
+        assert!(!has_header(&headers, header::VARY));
+    }
+
+    #[test]
+    fn should_omit_vary_when_flag_disabled_and_preflight_rejected_then_keep_rejection() {
+        let decision = preflight_request()
+            .origin("https://other.example")
+            .request_method(method::POST)
+            .check(&exact_origin_cors(false));
+
+        match decision {
+            CorsDecision::PreflightRejected(rejection) => {
+                assert_eq!(rejection.reason, PreflightRejectionReason::OriginNotAllowed);
+                assert!(!has_header(&rejection.headers, header::VARY));
+            }
+            other => panic!("expected preflight rejection, got {:?}", other),
+        }
+    }
+}
+
+mod max_vary_values {
+    use super::*;
+
+    fn echo_case_preflight(cap: Option<usize>) -> bunner_cors_rs::Headers {
+        let mut options = CorsOptions::new()
+            .origin(Origin::exact("https://app.example"))
+            .allowed_headers(AllowedHeaders::list_echo_case(["X-Trace"]));
+        if let Some(cap) = cap {
+            options = options.max_vary_values(cap);
+        }
+        let cors = Cors::new(options).expect("valid CORS configuration");
+
+        assert_preflight(
+            preflight_request()
+                .origin("https://app.example")
+                .request_method(method::POST)
+                .request_headers("x-trace")
+                .check(&cors),
+        )
+    }
+
+    #[test]
+    fn should_keep_all_vary_values_when_no_cap_then_emit_every_token() {
+        let headers = echo_case_preflight(None);
+
+        assert_vary_eq(
+            &headers,
+            [header::ORIGIN, header::ACCESS_CONTROL_REQUEST_HEADERS],
+        );
+    }
+
+    #[test]
+    fn should_truncate_to_first_sorted_values_when_cap_exceeded_then_drop_rest() {
+        let headers = echo_case_preflight(Some(1));
+
+        assert_header_eq(
+            &headers,
+            header::VARY,
+            header::ACCESS_CONTROL_REQUEST_HEADERS,
+        );
+        assert_header_eq(
+            &headers,
+            header::ACCESS_CONTROL_ALLOW_ORIGIN,
+            "https://app.example",
+        );
+    }
+}
+
+mod set_exposed {
+    use super::*;
+    use bunner_cors_rs::HeadersExt;
+
+    #[test]
+    fn should_rewrite_exposed_headers_when_amended_after_handler_then_keep_other_headers() {
+        let cors = cors()
+            .exposed_headers_config(ExposedHeaders::list(["X-Trace"]))
+            .build();
+        let mut headers = assert_simple(simple_request().origin("https://foo.bar").check(&cors));
+        let mut expected = headers.clone();
+        expected.insert(
+            header::ACCESS_CONTROL_EXPOSE_HEADERS.to_string(),
+            "X-Trace,X-Page-Count".to_string(),
+        );
+
+        headers.set_exposed(&["X-Trace", "X-Page-Count"]);
+
+        assert_eq!(headers, expected);
     }
 }
@@ -0,0 +1,62 @@
+#![cfg(feature = "hyper")]
+
+mod common;
+
+use bunner_cors_rs::constants::{header, method};
+use bunner_cors_rs::hyper::apply_to_response_builder;
+use bunner_cors_rs::{AllowedHeaders, AllowedMethods, Cors, CorsOptions, Origin};
+use common::asserts::assert_preflight;
+use common::builders::preflight_request;
+use hyper::Response;
+use hyper::http::StatusCode;
+
+mod apply_to_response_builder {
+    use super::*;
+
+    #[test]
+    fn should_append_cors_headers_when_applied_to_builder_then_response_carries_values() {
+        let cors = Cors::new(
+            CorsOptions::new()
+                .origin(Origin::list(["https://app.example"]))
+                .methods(AllowedMethods::list([method::GET, method::POST]))
+                .allowed_headers(AllowedHeaders::list(["X-Trace"]))
+                .max_age(600),
+        )
+        .expect("valid CORS configuration");
+        let headers = assert_preflight(
+            preflight_request()
+                .origin("https://app.example")
+                .request_method(method::POST)
+                .request_headers("X-Trace")
+                .check(&cors),
+        );
+
+        let response = apply_to_response_builder(
+            Response::builder()
+                .status(StatusCode::NO_CONTENT)
+                .header(header::VARY, "Accept-Encoding"),
+            &headers,
+        )
+        .body(())
+        .expect("valid response");
+
+        let value = |name: &str| response.headers().get(name).and_then(|v| v.to_str().ok());
+        assert_eq!(
+            value(header::ACCESS_CONTROL_ALLOW_ORIGIN),
+            Some("https://app.example")
+        );
+        assert_eq!(
+            value(header::ACCESS_CONTROL_ALLOW_METHODS),
+            Some("GET,POST")
+        );
+        assert_eq!(value(header::ACCESS_CONTROL_ALLOW_HEADERS), Some("X-Trace"));
+        assert_eq!(value(header::ACCESS_CONTROL_MAX_AGE), Some("600"));
+        let vary: Vec<_> = response
+            .headers()
+            .get_all(header::VARY)
+            .iter()
+            .filter_map(|v| v.to_str().ok())
+            .collect();
+        assert_eq!(vary, ["Accept-Encoding", "Origin"]);
+    }
+}
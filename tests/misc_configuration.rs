@@ -1,7 +1,7 @@
 mod common;
 
 use bunner_cors_rs::constants::{header, method};
-use bunner_cors_rs::{CorsDecision, Origin, PreflightRejectionReason};
+use bunner_cors_rs::{AllowedMethods, Cors, CorsOptions, Origin, ValidationError};
 use common::asserts::{assert_preflight, assert_vary_contains, assert_vary_not_contains};
 use common::builders::{cors, preflight_request};
 use common::headers::{has_header, header_value};
@@ -58,23 +58,14 @@ mod check {
     }
 
     #[test]
-    fn should_reject_preflight_when_methods_list_empty_then_return_rejection() {
-        let cors = cors().methods(Vec::<String>::new()).build();
-
-        let decision = preflight_request()
-            .origin("https://foo.bar")
-            .request_method(method::PATCH)
-            .check(&cors);
-
-        match decision {
-            CorsDecision::PreflightRejected(rejection) => assert_eq!(
-                rejection.reason,
-                PreflightRejectionReason::MethodNotAllowed {
-                    requested_method: "patch".to_string(),
-                }
-            ),
-            other => panic!("expected preflight rejection, got {:?}", other),
-        }
+    fn should_fail_construction_when_methods_list_empty_then_return_validation_error() {
+        let result =
+            Cors::new(CorsOptions::new().methods(AllowedMethods::list(Vec::<String>::new())));
+
+        assert!(matches!(
+            result,
+            Err(ValidationError::AllowedMethodsCannotBeEmpty)
+        ));
     }
 
     #[test]
@@ -107,3 +98,50 @@ mod check {
         assert_vary_not_contains(&headers, header::ORIGIN);
     }
 }
+
+#[cfg(feature = "regex")]
+mod from_lists {
+    use super::*;
+    use bunner_cors_rs::{CorsDecision, CorsLists};
+
+    #[test]
+    fn should_handle_preflight_when_policy_built_from_lists_then_apply_every_list() {
+        let options = CorsOptions::from_lists(CorsLists {
+            origins: vec![
+                "https://app.test".to_string(),
+                r"re:^https://.*\.preview\.test$".to_string(),
+            ],
+            methods: vec!["GET".to_string(), "PUT".to_string()],
+            allowed_headers: vec!["X-Test".to_string()],
+            exposed_headers: vec!["X-Trace".to_string()],
+        })
+        .expect("valid CORS lists");
+        let cors = Cors::new(options).expect("valid CORS configuration");
+
+        let headers = assert_preflight(
+            preflight_request()
+                .origin("https://pr-7.preview.test")
+                .request_method(method::PUT)
+                .request_headers("x-test")
+                .check(&cors),
+        );
+        let denied = preflight_request()
+            .origin("https://other.test")
+            .request_method(method::PUT)
+            .check(&cors);
+
+        assert_eq!(
+            header_value(&headers, header::ACCESS_CONTROL_ALLOW_ORIGIN),
+            Some("https://pr-7.preview.test"),
+        );
+        assert_eq!(
+            header_value(&headers, header::ACCESS_CONTROL_ALLOW_METHODS),
+            Some("GET,PUT"),
+        );
+        assert_eq!(
+            header_value(&headers, header::ACCESS_CONTROL_ALLOW_HEADERS),
+            Some("X-Test"),
+        );
+        assert!(matches!(denied, CorsDecision::PreflightRejected(_)));
+    }
+}
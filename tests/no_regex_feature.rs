@@ -0,0 +1,65 @@
+#![cfg(not(feature = "regex"))]
+
+mod common;
+
+use bunner_cors_rs::constants::{header, method};
+use bunner_cors_rs::{Origin, OriginMatcher, PatternError};
+use common::asserts::{assert_header_eq, assert_preflight, assert_simple};
+use common::builders::{cors, preflight_request, simple_request};
+
+mod exact_matching {
+    use super::*;
+
+    #[test]
+    fn should_allow_listed_origin_when_regex_disabled_then_reflects_origin() {
+        let cors = cors()
+            .origin(Origin::list([OriginMatcher::exact("https://app.example")]))
+            .build();
+
+        let headers = assert_simple(simple_request().origin("https://APP.example").check(&cors));
+
+        assert_header_eq(
+            &headers,
+            header::ACCESS_CONTROL_ALLOW_ORIGIN,
+            "https://APP.example",
+        );
+    }
+
+    #[test]
+    fn should_accept_preflight_when_regex_disabled_then_emits_allow_methods() {
+        let cors = cors()
+            .origin(Origin::list(["https://app.example"]))
+            .methods([method::GET, method::PUT])
+            .build();
+
+        let headers = assert_preflight(
+            preflight_request()
+                .origin("https://app.example")
+                .request_method(method::PUT)
+                .check(&cors),
+        );
+
+        assert_header_eq(&headers, header::ACCESS_CONTROL_ALLOW_METHODS, "GET,PUT");
+    }
+}
+
+mod from_lines {
+    use super::*;
+
+    #[test]
+    fn should_return_unsupported_when_regex_disabled_then_pattern_entry_is_rejected() {
+        let result = Origin::from_lines("https://app.example\nre:^https://.*\\.example$");
+
+        assert!(matches!(
+            result,
+            Err(PatternError::Unsupported { ref pattern }) if pattern == r"^https://.*\.example$"
+        ));
+    }
+
+    #[test]
+    fn should_parse_exact_entries_when_regex_disabled_then_builds_list() {
+        let origin = Origin::from_lines("https://app.example\n# comment\n").unwrap();
+
+        assert!(matches!(origin, Origin::List(ref list) if list.len() == 1));
+    }
+}
@@ -1,9 +1,7 @@
 mod common;
 
 use bunner_cors_rs::constants::{header, method};
-use bunner_cors_rs::{
-    CorsDecision, Origin, OriginDecision, OriginMatcher, PatternError, SimpleRejectionReason,
-};
+use bunner_cors_rs::{CorsDecision, Origin, OriginDecision, OriginMatcher, SimpleRejectionReason};
 use common::asserts::{
     assert_simple, assert_simple_rejected, assert_vary_eq, assert_vary_is_empty,
 };
@@ -12,6 +10,7 @@ use common::headers::{has_header, header_value};
 
 mod check {
     use super::*;
+    #[cfg(feature = "regex")]
     use regex_automata::meta::Regex;
 
     #[test]
@@ -32,6 +31,7 @@ mod check {
         assert_vary_eq(&headers, [header::ORIGIN]);
     }
 
+    #[cfg(feature = "regex")]
     #[test]
     fn should_allow_origin_when_list_contains_exact_and_pattern_then_mirror_and_vary() {
         let cors = cors()
@@ -100,6 +100,7 @@ mod check {
         assert_vary_eq(&headers, [header::ORIGIN]);
     }
 
+    #[cfg(feature = "regex")]
     #[test]
     fn should_match_case_insensitive_when_origin_pattern_configured_then_preserve_request_origin() {
         let cors = cors()
@@ -162,6 +163,68 @@ mod check {
         assert_vary_is_empty(&headers);
     }
 
+    #[test]
+    fn should_mirror_null_origin_when_list_contains_null_matcher_then_allow_without_global_flag() {
+        let cors = cors()
+            .origin(Origin::list([
+                OriginMatcher::null(),
+                OriginMatcher::exact("https://allowed.dev"),
+            ]))
+            .build();
+
+        let null_headers = assert_simple(simple_request().origin("null").check(&cors));
+        let exact_headers =
+            assert_simple(simple_request().origin("https://allowed.dev").check(&cors));
+        let rejection =
+            assert_simple_rejected(simple_request().origin("https://denied.dev").check(&cors));
+
+        assert_eq!(
+            header_value(&null_headers, header::ACCESS_CONTROL_ALLOW_ORIGIN),
+            Some("null"),
+        );
+        assert_vary_eq(&null_headers, [header::ORIGIN]);
+        assert_eq!(
+            header_value(&exact_headers, header::ACCESS_CONTROL_ALLOW_ORIGIN),
+            Some("https://allowed.dev"),
+        );
+        assert_eq!(rejection.reason, SimpleRejectionReason::OriginNotAllowed);
+    }
+
+    #[test]
+    fn should_reject_null_origin_when_list_lacks_null_matcher_then_emit_vary() {
+        let cors = cors()
+            .origin(Origin::list([OriginMatcher::exact("https://allowed.dev")]))
+            .build();
+
+        let rejection = assert_simple_rejected(simple_request().origin("null").check(&cors));
+
+        assert_eq!(rejection.reason, SimpleRejectionReason::OriginNotAllowed);
+        assert_vary_eq(&rejection.headers, [header::ORIGIN]);
+    }
+
+    #[test]
+    fn should_mirror_null_instead_of_wildcard_when_credentials_enabled_then_emit_literal() {
+        let cors = cors()
+            .origin(Origin::list([
+                OriginMatcher::exact("https://allowed.dev"),
+                OriginMatcher::null(),
+            ]))
+            .credentials(true)
+            .build();
+
+        let headers = assert_simple(simple_request().origin("null").check(&cors));
+
+        assert_eq!(
+            header_value(&headers, header::ACCESS_CONTROL_ALLOW_ORIGIN),
+            Some("null"),
+        );
+        assert_eq!(
+            header_value(&headers, header::ACCESS_CONTROL_ALLOW_CREDENTIALS),
+            Some("true"),
+        );
+    }
+
+    #[cfg(feature = "regex")]
     #[test]
     fn should_mirror_origin_when_list_contains_multiple_matchers_then_respect_each() {
         let cors = cors()
@@ -452,6 +515,7 @@ mod check {
         assert_vary_eq(&headers, [header::ORIGIN]);
     }
 
+    #[cfg(feature = "regex")]
     #[test]
     fn should_support_precompiled_regex_when_matcher_in_origin_list_then_reflect_origin() {
         let cors = cors()
@@ -471,10 +535,34 @@ mod check {
             Some("https://precompiled.api.dev"),
         );
     }
+
+    #[test]
+    fn should_reflect_canonical_origin_when_predicate_value_strips_trailing_slash_then_emit_override()
+     {
+        let cors = cors()
+            .origin(Origin::predicate_value(|origin, _| {
+                let canonical = origin.strip_suffix('/').unwrap_or(origin);
+                (canonical == "https://app.test").then(|| canonical.to_string())
+            }))
+            .build();
+
+        let headers = assert_simple(simple_request().origin("https://app.test/").check(&cors));
+        let rejection =
+            assert_simple_rejected(simple_request().origin("https://other.test/").check(&cors));
+
+        assert_eq!(
+            header_value(&headers, header::ACCESS_CONTROL_ALLOW_ORIGIN),
+            Some("https://app.test"),
+        );
+        assert_vary_eq(&headers, [header::ORIGIN]);
+        assert_eq!(rejection.reason, SimpleRejectionReason::OriginNotAllowed);
+    }
 }
 
+#[cfg(feature = "regex")]
 mod pattern_str {
     use super::*;
+    use bunner_cors_rs::PatternError;
 
     #[test]
     fn should_validate_pattern_length_when_origin_matcher_compiles_then_error_on_oversized() {
@@ -497,3 +585,1141 @@ mod pattern_str {
         assert!(OriginMatcher::pattern_str("(").is_err());
     }
 }
+
+mod normalize_trailing_slash {
+    use super::*;
+
+    #[test]
+    fn should_allow_origin_when_request_has_trailing_slash_and_config_does_not_then_emit_canonical_origin()
+     {
+        let cors = cors()
+            .origin(Origin::exact("https://app.test"))
+            .normalize_trailing_slash(true)
+            .build();
+
+        let headers = assert_simple(simple_request().origin("https://app.test/").check(&cors));
+
+        assert_eq!(
+            header_value(&headers, header::ACCESS_CONTROL_ALLOW_ORIGIN),
+            Some("https://app.test"),
+        );
+        assert_vary_eq(&headers, [header::ORIGIN]);
+    }
+
+    #[test]
+    fn should_allow_origin_when_config_has_trailing_slash_and_request_does_not_then_emit_canonical_origin()
+     {
+        let cors = cors()
+            .origin(Origin::list(["https://app.test/"]))
+            .normalize_trailing_slash(true)
+            .build();
+
+        let headers = assert_simple(simple_request().origin("https://app.test").check(&cors));
+
+        assert_eq!(
+            header_value(&headers, header::ACCESS_CONTROL_ALLOW_ORIGIN),
+            Some("https://app.test"),
+        );
+    }
+
+    #[test]
+    fn should_reflect_canonical_origin_when_list_matches_slashed_request_then_strip_slash() {
+        let cors = cors()
+            .origin(Origin::list(["https://app.test"]))
+            .normalize_trailing_slash(true)
+            .build();
+
+        let headers = assert_simple(simple_request().origin("https://app.test/").check(&cors));
+
+        assert_eq!(
+            header_value(&headers, header::ACCESS_CONTROL_ALLOW_ORIGIN),
+            Some("https://app.test"),
+        );
+    }
+
+    #[test]
+    fn should_strip_only_one_slash_when_request_has_several_then_reject() {
+        let cors = cors()
+            .origin(Origin::exact("https://app.test"))
+            .normalize_trailing_slash(true)
+            .build();
+
+        let rejection =
+            assert_simple_rejected(simple_request().origin("https://app.test//").check(&cors));
+
+        assert_eq!(rejection.reason, SimpleRejectionReason::OriginNotAllowed);
+    }
+
+    #[test]
+    fn should_reject_slashed_origin_when_normalization_disabled_then_keep_strict_match() {
+        let cors = cors()
+            .origin(Origin::exact("https://app.test"))
+            .normalize_trailing_slash(false)
+            .build();
+
+        let rejection =
+            assert_simple_rejected(simple_request().origin("https://app.test/").check(&cors));
+
+        assert_eq!(rejection.reason, SimpleRejectionReason::OriginNotAllowed);
+    }
+}
+
+mod require_https {
+    use super::*;
+
+    #[test]
+    fn should_reject_http_origin_when_require_https_with_any_origin_then_disallow() {
+        let cors = cors()
+            .origin(Origin::any())
+            .allow_null_origin(true)
+            .require_https(true)
+            .build();
+
+        let rejection =
+            assert_simple_rejected(simple_request().origin("http://app.test").check(&cors));
+
+        assert_eq!(rejection.reason, SimpleRejectionReason::OriginNotAllowed);
+    }
+
+    #[test]
+    fn should_allow_https_origin_when_require_https_with_any_origin_then_emit_wildcard() {
+        let cors = cors()
+            .origin(Origin::any())
+            .allow_null_origin(true)
+            .require_https(true)
+            .build();
+
+        let headers = assert_simple(simple_request().origin("HTTPS://app.test").check(&cors));
+
+        assert_eq!(
+            header_value(&headers, header::ACCESS_CONTROL_ALLOW_ORIGIN),
+            Some("*"),
+        );
+    }
+
+    #[test]
+    fn should_reject_listed_http_origin_when_require_https_then_ignore_allow_list() {
+        let cors = cors()
+            .origin(Origin::list(["http://app.test", "https://app.test"]))
+            .allow_null_origin(true)
+            .require_https(true)
+            .build();
+
+        let rejection =
+            assert_simple_rejected(simple_request().origin("http://app.test").check(&cors));
+        let headers = assert_simple(simple_request().origin("https://app.test").check(&cors));
+
+        assert_eq!(rejection.reason, SimpleRejectionReason::OriginNotAllowed);
+        assert_vary_eq(&rejection.headers, [header::ORIGIN]);
+        assert_eq!(
+            header_value(&headers, header::ACCESS_CONTROL_ALLOW_ORIGIN),
+            Some("https://app.test"),
+        );
+    }
+
+    #[test]
+    fn should_defer_to_null_handling_when_require_https_and_null_origin_then_allow() {
+        let cors = cors()
+            .origin(Origin::any())
+            .allow_null_origin(true)
+            .require_https(true)
+            .build();
+
+        let headers = assert_simple(simple_request().origin("null").check(&cors));
+
+        assert!(has_header(&headers, header::ACCESS_CONTROL_ALLOW_ORIGIN));
+    }
+}
+
+mod default_origin_when_missing {
+    use super::*;
+    use bunner_cors_rs::RequestContext;
+
+    #[test]
+    fn should_mirror_default_origin_when_request_has_no_origin_then_emit_headers() {
+        let cors = cors()
+            .origin(Origin::list(["https://internal.test", "https://app.test"]))
+            .default_origin_when_missing("https://internal.test")
+            .build();
+
+        let headers = assert_simple(simple_request().check(&cors));
+
+        assert_eq!(
+            header_value(&headers, header::ACCESS_CONTROL_ALLOW_ORIGIN),
+            Some("https://internal.test"),
+        );
+        assert_vary_eq(&headers, [header::ORIGIN]);
+    }
+
+    #[test]
+    fn should_keep_request_origin_when_present_then_ignore_default() {
+        let cors = cors()
+            .origin(Origin::list(["https://internal.test", "https://app.test"]))
+            .default_origin_when_missing("https://internal.test")
+            .build();
+
+        let headers = assert_simple(simple_request().origin("https://app.test").check(&cors));
+
+        assert_eq!(
+            header_value(&headers, header::ACCESS_CONTROL_ALLOW_ORIGIN),
+            Some("https://app.test"),
+        );
+    }
+
+    #[test]
+    fn should_reject_originless_request_when_default_not_allowed_then_disallow() {
+        let cors = cors()
+            .origin(Origin::list(["https://internal.test", "https://app.test"]))
+            .default_origin_when_missing("https://unlisted.test")
+            .build();
+
+        let rejection = assert_simple_rejected(simple_request().check(&cors));
+
+        assert_eq!(rejection.reason, SimpleRejectionReason::OriginNotAllowed);
+    }
+
+    #[test]
+    fn should_resolve_default_origin_when_resolve_origin_called_without_origin_then_mirror() {
+        let cors = cors()
+            .origin(Origin::list(["https://internal.test", "https://app.test"]))
+            .default_origin_when_missing("https://internal.test")
+            .build();
+        let request = RequestContext {
+            method: method::GET,
+            origin: None,
+            access_control_request_method: None,
+            access_control_request_headers: None,
+            access_control_request_private_network: false,
+            sec_fetch_site: None,
+            host: None,
+        };
+
+        assert!(matches!(
+            cors.resolve_origin(&request),
+            OriginDecision::Mirror
+        ));
+    }
+}
+
+mod origin_canonicalizer {
+    use super::*;
+    use bunner_cors_rs::{Cors, CorsOptions};
+    use std::borrow::Cow;
+    use std::sync::Arc;
+
+    fn regional_cors(origin: Origin) -> Cors {
+        Cors::new(
+            CorsOptions::new()
+                .origin(origin)
+                .origin_canonicalizer(Arc::new(|origin: &str| {
+                    match origin
+                        .strip_prefix("https://")
+                        .and_then(|rest| rest.split_once('.'))
+                    {
+                        Some(("eu" | "us", host)) => Cow::Owned(format!("https://{host}")),
+                        _ => Cow::Borrowed(origin),
+                    }
+                })),
+        )
+        .expect("valid CORS configuration")
+    }
+
+    #[test]
+    fn should_match_canonical_entry_when_regional_origins_requested_then_reflect_original() {
+        let cors = regional_cors(Origin::list(["https://app.test"]));
+
+        for regional in ["https://eu.app.test", "https://us.app.test"] {
+            let headers = assert_simple(simple_request().origin(regional).check(&cors));
+
+            assert_eq!(
+                header_value(&headers, header::ACCESS_CONTROL_ALLOW_ORIGIN),
+                Some(regional),
+            );
+            assert_vary_eq(&headers, [header::ORIGIN]);
+        }
+    }
+
+    #[test]
+    fn should_reflect_original_when_exact_origin_matches_canonical_form_then_skip_config_value() {
+        let cors = regional_cors(Origin::exact("https://app.test"));
+
+        let headers = assert_simple(simple_request().origin("https://eu.app.test").check(&cors));
+
+        assert_eq!(
+            header_value(&headers, header::ACCESS_CONTROL_ALLOW_ORIGIN),
+            Some("https://eu.app.test"),
+        );
+    }
+
+    #[test]
+    fn should_reject_origin_when_canonical_form_not_listed_then_disallow() {
+        let cors = regional_cors(Origin::list(["https://app.test"]));
+
+        let rejection =
+            assert_simple_rejected(simple_request().origin("https://ap.app.test").check(&cors));
+
+        assert_eq!(rejection.reason, SimpleRejectionReason::OriginNotAllowed);
+    }
+}
+
+mod sec_fetch_site {
+    use super::*;
+    use bunner_cors_rs::RequestContext;
+
+    fn same_origin_or_listed_cors() -> bunner_cors_rs::Cors {
+        cors()
+            .origin(Origin::custom(|origin, ctx| {
+                if ctx.sec_fetch_site == Some("same-origin") {
+                    return OriginDecision::Mirror;
+                }
+                match origin {
+                    Some("https://partner.test") => OriginDecision::Mirror,
+                    _ => OriginDecision::Disallow,
+                }
+            }))
+            .build()
+    }
+
+    fn request<'a>(origin: &'a str, sec_fetch_site: Option<&'a str>) -> RequestContext<'a> {
+        RequestContext {
+            method: method::GET,
+            origin: Some(origin),
+            access_control_request_method: None,
+            access_control_request_headers: None,
+            access_control_request_private_network: false,
+            sec_fetch_site,
+            host: None,
+        }
+    }
+
+    #[test]
+    fn should_allow_unlisted_origin_when_sec_fetch_site_same_origin_then_reflect_origin() {
+        let cors = same_origin_or_listed_cors();
+
+        let decision = cors
+            .check(&request("https://app.test", Some("Same-Origin")))
+            .expect("simple request evaluation should succeed");
+
+        let headers = assert_simple(decision);
+        assert_eq!(
+            header_value(&headers, header::ACCESS_CONTROL_ALLOW_ORIGIN),
+            Some("https://app.test")
+        );
+    }
+
+    #[test]
+    fn should_reject_unlisted_origin_when_sec_fetch_site_cross_site_then_fall_back_to_list() {
+        let cors = same_origin_or_listed_cors();
+
+        let decision = cors
+            .check(&request("https://app.test", Some("cross-site")))
+            .expect("simple request evaluation should succeed");
+
+        let rejection = assert_simple_rejected(decision);
+        assert_eq!(rejection.reason, SimpleRejectionReason::OriginNotAllowed);
+    }
+
+    #[test]
+    fn should_allow_listed_origin_when_sec_fetch_site_missing_then_use_allow_list() {
+        let cors = same_origin_or_listed_cors();
+
+        let decision = cors
+            .check(&request("https://partner.test", None))
+            .expect("simple request evaluation should succeed");
+
+        assert_simple(decision);
+    }
+}
+
+mod reject_origin_host_mismatch {
+    use super::*;
+    use bunner_cors_rs::RequestContext;
+    use std::sync::Arc;
+
+    fn host_checked_cors() -> bunner_cors_rs::Cors {
+        bunner_cors_rs::Cors::new(
+            bunner_cors_rs::CorsOptions::new()
+                .origin(Origin::list(["https://api.test", "https://evil.test"]))
+                .reject_origin_host_mismatch(Arc::new(|origin, host| {
+                    origin
+                        .split_once("://")
+                        .is_none_or(|(_, authority)| authority != host)
+                })),
+        )
+        .expect("valid CORS configuration")
+    }
+
+    fn request<'a>(origin: &'a str, host: Option<&'a str>) -> RequestContext<'a> {
+        RequestContext {
+            method: method::GET,
+            origin: Some(origin),
+            access_control_request_method: None,
+            access_control_request_headers: None,
+            access_control_request_private_network: false,
+            sec_fetch_site: None,
+            host,
+        }
+    }
+
+    #[test]
+    fn should_reject_origin_when_host_mismatched_then_disallow_request() {
+        let cors = host_checked_cors();
+
+        let decision = cors
+            .check(&request("https://evil.test", Some("api.test")))
+            .expect("simple request evaluation should succeed");
+
+        let rejection = assert_simple_rejected(decision);
+        assert_eq!(rejection.reason, SimpleRejectionReason::OriginNotAllowed);
+    }
+
+    #[test]
+    fn should_allow_origin_when_host_matches_ignoring_case_then_reflect_origin() {
+        let cors = host_checked_cors();
+
+        let decision = cors
+            .check(&request("https://api.test", Some("API.test")))
+            .expect("simple request evaluation should succeed");
+
+        let headers = assert_simple(decision);
+        assert_eq!(
+            header_value(&headers, header::ACCESS_CONTROL_ALLOW_ORIGIN),
+            Some("https://api.test")
+        );
+    }
+
+    #[test]
+    fn should_skip_check_when_host_missing_then_use_origin_policy() {
+        let cors = host_checked_cors();
+
+        let decision = cors
+            .check(&request("https://evil.test", None))
+            .expect("simple request evaluation should succeed");
+
+        assert_simple(decision);
+    }
+}
+
+mod audit {
+    use super::*;
+    use bunner_cors_rs::AuditReport;
+
+    #[test]
+    fn should_return_clean_report_when_expectations_match_then_report_no_mismatches() {
+        let cors = cors()
+            .origin(Origin::list(["https://app.test", "https://admin.test"]))
+            .build();
+
+        let report = cors.audit(
+            &["https://app.test", "https://admin.test"],
+            &["https://evil.test"],
+        );
+
+        assert!(report.is_clean());
+    }
+
+    #[test]
+    fn should_report_mismatches_when_expectations_differ_then_list_offending_origins() {
+        let cors = cors()
+            .origin(Origin::list(["https://app.test", "https://legacy.test"]))
+            .build();
+
+        let report = cors.audit(
+            &["https://app.test", "https://admin.test"],
+            &["https://evil.test", "https://legacy.test"],
+        );
+
+        assert!(!report.is_clean());
+        assert_eq!(
+            report,
+            AuditReport {
+                unexpectedly_blocked: vec!["https://admin.test".to_string()],
+                unexpectedly_allowed: vec!["https://legacy.test".to_string()],
+            }
+        );
+    }
+}
+
+mod strict_null_origin {
+    use super::*;
+
+    fn null_cors(strict: bool) -> bunner_cors_rs::Cors {
+        bunner_cors_rs::Cors::new(
+            bunner_cors_rs::CorsOptions::new()
+                .origin(Origin::list([
+                    OriginMatcher::null(),
+                    OriginMatcher::exact("https://allowed.dev"),
+                ]))
+                .strict_null_origin(strict),
+        )
+        .expect("valid CORS configuration")
+    }
+
+    #[test]
+    fn should_accept_null_variants_when_lenient_then_reflect_canonical_null() {
+        let cors = null_cors(false);
+
+        for origin in ["null", "Null", " null "] {
+            let headers = assert_simple(simple_request().origin(origin).check(&cors));
+
+            assert_eq!(
+                header_value(&headers, header::ACCESS_CONTROL_ALLOW_ORIGIN),
+                Some("null"),
+                "origin {origin:?} should be reflected as null",
+            );
+        }
+    }
+
+    #[test]
+    fn should_accept_null_variants_when_allow_null_origin_enabled_then_emit_wildcard() {
+        let cors = cors().allow_null_origin(true).build();
+
+        for origin in ["null", "Null", " null "] {
+            let headers = assert_simple(simple_request().origin(origin).check(&cors));
+
+            assert_eq!(
+                header_value(&headers, header::ACCESS_CONTROL_ALLOW_ORIGIN),
+                Some("*"),
+                "origin {origin:?} should be allowed",
+            );
+        }
+    }
+
+    #[test]
+    fn should_accept_exact_null_when_strict_then_reflect_null() {
+        let cors = null_cors(true);
+
+        let headers = assert_simple(simple_request().origin("null").check(&cors));
+
+        assert_eq!(
+            header_value(&headers, header::ACCESS_CONTROL_ALLOW_ORIGIN),
+            Some("null"),
+        );
+    }
+
+    #[test]
+    fn should_reject_null_variants_when_strict_then_report_origin_not_allowed() {
+        let cors = null_cors(true);
+
+        for origin in ["Null", " null "] {
+            let rejection = assert_simple_rejected(simple_request().origin(origin).check(&cors));
+
+            assert_eq!(
+                rejection.reason,
+                SimpleRejectionReason::OriginNotAllowed,
+                "origin {origin:?} should be rejected",
+            );
+        }
+    }
+
+    #[test]
+    fn should_keep_other_origins_when_strict_then_allow_listed_origin() {
+        let cors = null_cors(true);
+
+        assert_simple(simple_request().origin("https://allowed.dev").check(&cors));
+    }
+}
+
+mod host_port {
+    use super::*;
+
+    #[test]
+    fn should_reflect_full_origin_when_host_port_matches_any_scheme_then_echo_request() {
+        let cors = cors()
+            .origin(Origin::list([OriginMatcher::host_port("app.test:8443")]))
+            .build();
+
+        for origin in ["https://app.test:8443", "http://app.test:8443"] {
+            let headers = assert_simple(simple_request().origin(origin).check(&cors));
+
+            assert_eq!(
+                header_value(&headers, header::ACCESS_CONTROL_ALLOW_ORIGIN),
+                Some(origin)
+            );
+        }
+    }
+}
+
+mod exact_www_insensitive {
+    use super::*;
+
+    #[test]
+    fn should_reflect_request_origin_when_apex_or_www_matches_then_reject_other_subdomain() {
+        let cors = cors()
+            .origin(Origin::list([OriginMatcher::exact_www_insensitive(
+                "https://example.com",
+            )]))
+            .build();
+
+        for origin in ["https://example.com", "https://www.example.com"] {
+            let headers = assert_simple(simple_request().origin(origin).check(&cors));
+
+            assert_eq!(
+                header_value(&headers, header::ACCESS_CONTROL_ALLOW_ORIGIN),
+                Some(origin)
+            );
+        }
+        let rejection = assert_simple_rejected(
+            simple_request()
+                .origin("https://api.example.com")
+                .check(&cors),
+        );
+        assert_eq!(rejection.reason, SimpleRejectionReason::OriginNotAllowed);
+    }
+}
+
+mod from_conversions {
+    use super::*;
+    use bunner_cors_rs::{Cors, CorsOptions};
+
+    #[test]
+    fn should_allow_configured_origin_when_str_converted_into_origin_then_reflect_it() {
+        let cors = Cors::new(CorsOptions::new().origin("https://app.test".into()))
+            .expect("valid CORS configuration");
+
+        let headers = assert_simple(simple_request().origin("https://app.test").check(&cors));
+
+        assert_eq!(
+            header_value(&headers, header::ACCESS_CONTROL_ALLOW_ORIGIN),
+            Some("https://app.test")
+        );
+        let rejection =
+            assert_simple_rejected(simple_request().origin("https://other.test").check(&cors));
+        assert_eq!(rejection.reason, SimpleRejectionReason::OriginNotAllowed);
+    }
+
+    #[test]
+    fn should_allow_each_entry_when_vec_converted_into_origin_then_reject_others() {
+        let cors = Cors::new(
+            CorsOptions::new().origin(vec!["https://app.test", "https://admin.test"].into()),
+        )
+        .expect("valid CORS configuration");
+
+        assert_simple(simple_request().origin("https://admin.test").check(&cors));
+        assert_simple_rejected(simple_request().origin("https://other.test").check(&cors));
+    }
+}
+
+mod allow_origin_multi {
+    use super::*;
+    use bunner_cors_rs::{Cors, CorsOptions};
+
+    const GROUP: [&str; 2] = ["https://app.test", "https://admin.test"];
+
+    fn group_cors(options: CorsOptions) -> Cors {
+        Cors::new(
+            options
+                .origin(Origin::list([
+                    "https://app.test",
+                    "https://admin.test",
+                    "https://internal.test",
+                ]))
+                .allow_origin_multi(GROUP),
+        )
+        .expect("valid CORS configuration")
+    }
+
+    #[test]
+    fn should_emit_cache_group_when_member_allowed_then_join_with_spaces() {
+        let cors = group_cors(CorsOptions::new());
+
+        let headers = assert_simple(simple_request().origin("https://admin.test").check(&cors));
+
+        assert_eq!(
+            header_value(&headers, header::ACCESS_CONTROL_ALLOW_ORIGIN),
+            Some("https://app.test https://admin.test")
+        );
+        assert_vary_eq(&headers, [header::ORIGIN]);
+    }
+
+    #[test]
+    fn should_report_requester_as_matched_origin_when_group_emitted_then_name_single_origin() {
+        let cors = group_cors(CorsOptions::new());
+
+        let decision = simple_request().origin("https://admin.test").check(&cors);
+
+        match decision {
+            CorsDecision::SimpleAccepted { matched_origin, .. } => {
+                assert_eq!(matched_origin.as_deref(), Some("https://admin.test"));
+            }
+            other => panic!("expected simple acceptance, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn should_reflect_request_origin_when_allowed_outside_group_then_keep_group_undisclosed() {
+        let cors = group_cors(CorsOptions::new());
+
+        let headers = assert_simple(
+            simple_request()
+                .origin("https://internal.test")
+                .check(&cors),
+        );
+
+        assert_eq!(
+            header_value(&headers, header::ACCESS_CONTROL_ALLOW_ORIGIN),
+            Some("https://internal.test")
+        );
+    }
+
+    #[cfg(feature = "regex")]
+    #[test]
+    fn should_reflect_request_origin_when_matched_by_pattern_then_emit_single_value() {
+        let cors = Cors::new(
+            CorsOptions::new()
+                .origin(Origin::list([
+                    OriginMatcher::exact("https://app.test"),
+                    OriginMatcher::pattern_str(r"^https://.*\.preview\.test$").unwrap(),
+                ]))
+                .allow_origin_multi(GROUP),
+        )
+        .expect("valid CORS configuration");
+
+        let headers = assert_simple(
+            simple_request()
+                .origin("https://pr-7.preview.test")
+                .check(&cors),
+        );
+
+        assert_eq!(
+            header_value(&headers, header::ACCESS_CONTROL_ALLOW_ORIGIN),
+            Some("https://pr-7.preview.test")
+        );
+    }
+
+    #[test]
+    fn should_reflect_request_origin_when_disabled_then_emit_single_value() {
+        let cors = cors().origin(Origin::list(GROUP)).build();
+
+        let headers = assert_simple(simple_request().origin("https://admin.test").check(&cors));
+
+        assert_eq!(
+            header_value(&headers, header::ACCESS_CONTROL_ALLOW_ORIGIN),
+            Some("https://admin.test")
+        );
+    }
+
+    #[test]
+    fn should_reflect_request_origin_when_credentials_enabled_then_keep_spec_compliant_value() {
+        let cors = group_cors(CorsOptions::new().credentials(true));
+
+        let headers = assert_simple(simple_request().origin("https://admin.test").check(&cors));
+
+        assert_eq!(
+            header_value(&headers, header::ACCESS_CONTROL_ALLOW_ORIGIN),
+            Some("https://admin.test")
+        );
+    }
+
+    #[test]
+    fn should_reflect_request_origin_when_member_is_credentialed_origin_then_keep_single_value() {
+        let cors = group_cors(
+            CorsOptions::new().credentialed_origins(Origin::exact("https://admin.test")),
+        );
+
+        let credentialed =
+            assert_simple(simple_request().origin("https://admin.test").check(&cors));
+        let public = assert_simple(simple_request().origin("https://app.test").check(&cors));
+
+        assert_eq!(
+            header_value(&credentialed, header::ACCESS_CONTROL_ALLOW_ORIGIN),
+            Some("https://admin.test")
+        );
+        assert_eq!(
+            header_value(&credentialed, header::ACCESS_CONTROL_ALLOW_CREDENTIALS),
+            Some("true")
+        );
+        assert_eq!(
+            header_value(&public, header::ACCESS_CONTROL_ALLOW_ORIGIN),
+            Some("https://app.test https://admin.test")
+        );
+    }
+
+    #[test]
+    fn should_skip_allow_origin_when_member_disallowed_by_policy_then_leave_group_unexposed() {
+        let cors = Cors::new(
+            CorsOptions::new()
+                .origin(Origin::list(["https://app.test"]))
+                .allow_origin_multi(GROUP),
+        )
+        .expect("valid CORS configuration");
+
+        let rejection =
+            assert_simple_rejected(simple_request().origin("https://admin.test").check(&cors));
+
+        assert!(!has_header(
+            &rejection.headers,
+            header::ACCESS_CONTROL_ALLOW_ORIGIN
+        ));
+    }
+}
+
+mod learning_mode {
+    use super::*;
+    use bunner_cors_rs::{Cors, CorsOptions};
+    use std::sync::{Arc, Mutex};
+
+    fn learning_cors(options: CorsOptions) -> (Cors, Arc<Mutex<Vec<String>>>) {
+        let learned = Arc::new(Mutex::new(Vec::new()));
+        let sink = Arc::clone(&learned);
+        let cors = Cors::new(options.learning_mode(Arc::new(move |origin: &str| {
+            sink.lock().unwrap().push(origin.to_string());
+        })))
+        .expect("valid CORS configuration");
+        (cors, learned)
+    }
+
+    #[test]
+    fn should_mirror_unlisted_origin_when_learning_then_record_it() {
+        let (cors, learned) =
+            learning_cors(CorsOptions::new().origin(Origin::list(["https://app.test"])));
+
+        let headers = assert_simple(simple_request().origin("https://new.test").check(&cors));
+
+        assert_eq!(
+            header_value(&headers, header::ACCESS_CONTROL_ALLOW_ORIGIN),
+            Some("https://new.test")
+        );
+        assert_vary_eq(&headers, [header::ORIGIN]);
+        assert_eq!(
+            *learned.lock().unwrap(),
+            vec!["https://new.test".to_string()]
+        );
+    }
+
+    #[test]
+    fn should_skip_callback_when_origin_listed_then_allow_without_recording() {
+        let (cors, learned) =
+            learning_cors(CorsOptions::new().origin(Origin::list(["https://app.test"])));
+
+        assert_simple(simple_request().origin("https://app.test").check(&cors));
+
+        assert!(learned.lock().unwrap().is_empty());
+    }
+
+    #[test]
+    fn should_record_once_when_credentialed_set_rejects_then_learn_through_main_policy() {
+        let (cors, learned) = learning_cors(
+            CorsOptions::new()
+                .origin(Origin::list(["https://app.test"]))
+                .credentialed_origins(Origin::list(["https://admin.test"])),
+        );
+
+        let headers = assert_simple(simple_request().origin("https://new.test").check(&cors));
+
+        assert!(!has_header(
+            &headers,
+            header::ACCESS_CONTROL_ALLOW_CREDENTIALS
+        ));
+        assert_eq!(
+            *learned.lock().unwrap(),
+            vec!["https://new.test".to_string()]
+        );
+    }
+
+    #[test]
+    fn should_report_unlisted_origin_blocked_when_audited_then_skip_callback() {
+        let (cors, learned) =
+            learning_cors(CorsOptions::new().origin(Origin::list(["https://app.test"])));
+
+        let report = cors.audit(&["https://new.test"], &[]);
+
+        assert_eq!(report.unexpectedly_blocked, vec!["https://new.test"]);
+        assert!(learned.lock().unwrap().is_empty());
+    }
+
+    #[test]
+    fn should_skip_callback_when_cache_key_parts_computed_then_leave_learned_empty() {
+        let (cors, learned) =
+            learning_cors(CorsOptions::new().origin(Origin::list(["https://app.test"])));
+        let request = bunner_cors_rs::RequestContext {
+            method: method::GET,
+            origin: Some("https://new.test"),
+            access_control_request_method: None,
+            access_control_request_headers: None,
+            access_control_request_private_network: false,
+            sec_fetch_site: None,
+            host: None,
+        };
+
+        let parts = cors.cache_key_parts(&request);
+
+        assert_eq!(parts, vec![header::ORIGIN, "https://new.test"]);
+        assert!(learned.lock().unwrap().is_empty());
+    }
+
+    #[test]
+    fn should_not_record_when_origin_missing_then_keep_default_decision() {
+        let (cors, learned) =
+            learning_cors(CorsOptions::new().origin(Origin::list(["https://app.test"])));
+
+        let decision = simple_request().check(&cors);
+
+        assert!(matches!(decision, CorsDecision::NotApplicable));
+        assert!(learned.lock().unwrap().is_empty());
+    }
+}
+
+mod null_origin_mode {
+    use super::*;
+    use bunner_cors_rs::{Cors, CorsError, CorsOptions, NullOriginMode, RequestContext};
+
+    fn build(options: CorsOptions) -> Cors {
+        Cors::new(options).expect("valid CORS configuration")
+    }
+
+    fn credentialed_list(mode: NullOriginMode) -> Cors {
+        build(
+            CorsOptions::new()
+                .origin(Origin::list(["https://a.test"]))
+                .credentials(true)
+                .allow_null_origin(true)
+                .null_origin_mode(mode),
+        )
+    }
+
+    fn credentialed_any_callback(mode: NullOriginMode) -> Cors {
+        build(
+            CorsOptions::new()
+                .origin(Origin::custom(|_, _| OriginDecision::Any))
+                .credentials(true)
+                .allow_null_origin(true)
+                .null_origin_mode(mode),
+        )
+    }
+
+    fn null_request() -> RequestContext<'static> {
+        RequestContext {
+            method: method::GET,
+            origin: Some("null"),
+            access_control_request_method: None,
+            access_control_request_headers: None,
+            access_control_request_private_network: false,
+            sec_fetch_site: None,
+            host: None,
+        }
+    }
+
+    #[test]
+    fn should_emit_wildcard_when_wildcard_mode_without_credentials_then_keep_policy_answer() {
+        let cors = build(
+            CorsOptions::new()
+                .allow_null_origin(true)
+                .null_origin_mode(NullOriginMode::Wildcard),
+        );
+
+        let headers = assert_simple(simple_request().origin("null").check(&cors));
+
+        assert_eq!(
+            header_value(&headers, header::ACCESS_CONTROL_ALLOW_ORIGIN),
+            Some("*"),
+        );
+        assert_vary_is_empty(&headers);
+    }
+
+    #[test]
+    fn should_reflect_literal_null_when_credentialed_list_omits_it_then_allow_credentials() {
+        for mode in [NullOriginMode::Wildcard, NullOriginMode::Literal] {
+            let cors = credentialed_list(mode);
+
+            let headers = assert_simple(simple_request().origin("null").check(&cors));
+
+            assert_eq!(
+                header_value(&headers, header::ACCESS_CONTROL_ALLOW_ORIGIN),
+                Some("null"),
+            );
+            assert_eq!(
+                header_value(&headers, header::ACCESS_CONTROL_ALLOW_CREDENTIALS),
+                Some("true"),
+            );
+            assert_vary_eq(&headers, [header::ORIGIN]);
+        }
+    }
+
+    #[test]
+    fn should_reject_null_when_list_omits_it_without_credentials_then_keep_policy_answer() {
+        let cors = build(
+            CorsOptions::new()
+                .origin(Origin::list(["https://a.test"]))
+                .allow_null_origin(true)
+                .null_origin_mode(NullOriginMode::Literal),
+        );
+
+        let rejection = assert_simple_rejected(simple_request().origin("null").check(&cors));
+
+        assert_eq!(rejection.reason, SimpleRejectionReason::OriginNotAllowed);
+    }
+
+    #[test]
+    fn should_reflect_literal_null_when_literal_mode_without_credentials_then_vary_on_origin() {
+        let cors = build(
+            CorsOptions::new()
+                .allow_null_origin(true)
+                .null_origin_mode(NullOriginMode::Literal),
+        );
+
+        let headers = assert_simple(simple_request().origin(" Null ").check(&cors));
+
+        assert_eq!(
+            header_value(&headers, header::ACCESS_CONTROL_ALLOW_ORIGIN),
+            Some("null"),
+        );
+        assert!(!has_header(
+            &headers,
+            header::ACCESS_CONTROL_ALLOW_CREDENTIALS
+        ));
+        assert_vary_eq(&headers, [header::ORIGIN]);
+    }
+
+    #[test]
+    fn should_reflect_literal_null_when_literal_mode_and_policy_answers_any_then_allow_credentials()
+    {
+        let cors = credentialed_any_callback(NullOriginMode::Literal);
+
+        let headers = assert_simple(simple_request().origin("null").check(&cors));
+
+        assert_eq!(
+            header_value(&headers, header::ACCESS_CONTROL_ALLOW_ORIGIN),
+            Some("null"),
+        );
+        assert_eq!(
+            header_value(&headers, header::ACCESS_CONTROL_ALLOW_CREDENTIALS),
+            Some("true"),
+        );
+        assert_vary_eq(&headers, [header::ORIGIN]);
+    }
+
+    #[test]
+    fn should_fail_when_wildcard_mode_and_policy_answers_any_with_credentials_then_report_error() {
+        let cors = credentialed_any_callback(NullOriginMode::Wildcard);
+
+        let result = cors.check(&null_request());
+
+        assert!(matches!(
+            result,
+            Err(CorsError::InvalidOriginAnyWithCredentials)
+        ));
+    }
+
+    #[test]
+    fn should_reflect_literal_null_when_list_matches_null_then_allow_credentials_in_either_mode() {
+        for mode in [NullOriginMode::Wildcard, NullOriginMode::Literal] {
+            let cors = build(
+                CorsOptions::new()
+                    .origin(Origin::list([
+                        OriginMatcher::exact("https://a.test"),
+                        OriginMatcher::null(),
+                    ]))
+                    .credentials(true)
+                    .null_origin_mode(mode),
+            );
+
+            let headers = assert_simple(simple_request().origin("null").check(&cors));
+
+            assert_eq!(
+                header_value(&headers, header::ACCESS_CONTROL_ALLOW_ORIGIN),
+                Some("null"),
+            );
+            assert_eq!(
+                header_value(&headers, header::ACCESS_CONTROL_ALLOW_CREDENTIALS),
+                Some("true"),
+            );
+        }
+    }
+
+    #[test]
+    fn should_reject_null_when_credentials_without_allow_null_origin_then_report_origin_not_allowed()
+     {
+        let cors = build(
+            CorsOptions::new()
+                .origin(Origin::list(["https://app.test"]))
+                .credentials(true)
+                .null_origin_mode(NullOriginMode::Literal),
+        );
+
+        let rejection = assert_simple_rejected(simple_request().origin("null").check(&cors));
+
+        assert_eq!(rejection.reason, SimpleRejectionReason::OriginNotAllowed);
+    }
+}
+
+mod downgrade_wildcard_for_credentials {
+    use super::*;
+    use bunner_cors_rs::{Cors, CorsOptions};
+
+    #[test]
+    fn should_reflect_origin_when_callback_returns_any_with_credentials_then_allow_credentials() {
+        let cors = Cors::new(
+            CorsOptions::new()
+                .origin(Origin::custom(|_, _| OriginDecision::Any))
+                .credentials(true)
+                .downgrade_wildcard_for_credentials(true),
+        )
+        .expect("valid CORS configuration");
+
+        let headers = assert_simple(simple_request().origin("https://any.dev").check(&cors));
+
+        assert_eq!(
+            header_value(&headers, header::ACCESS_CONTROL_ALLOW_ORIGIN),
+            Some("https://any.dev"),
+        );
+        assert_eq!(
+            header_value(&headers, header::ACCESS_CONTROL_ALLOW_CREDENTIALS),
+            Some("true"),
+        );
+        assert_vary_eq(&headers, [header::ORIGIN]);
+    }
+}
+
+#[cfg(feature = "regex")]
+mod normalize_default_ports {
+    use super::*;
+
+    fn portless_pattern() -> Origin {
+        Origin::list([OriginMatcher::pattern_str(r"^https://app\.test$").expect("valid pattern")])
+    }
+
+    #[test]
+    fn should_match_portless_pattern_when_https_origin_has_default_port_then_reflect_origin() {
+        let cors = cors()
+            .origin(portless_pattern())
+            .normalize_default_ports(true)
+            .build();
+
+        let headers = assert_simple(simple_request().origin("https://app.test:443").check(&cors));
+
+        assert_eq!(
+            header_value(&headers, header::ACCESS_CONTROL_ALLOW_ORIGIN),
+            Some("https://app.test:443"),
+        );
+    }
+
+    #[test]
+    fn should_reject_default_port_when_flag_disabled_then_keep_pattern_strict() {
+        let cors = cors()
+            .origin(portless_pattern())
+            .normalize_default_ports(false)
+            .build();
+
+        let rejection =
+            assert_simple_rejected(simple_request().origin("https://app.test:443").check(&cors));
+
+        assert_eq!(rejection.reason, SimpleRejectionReason::OriginNotAllowed);
+    }
+
+    #[test]
+    fn should_keep_non_default_port_when_flag_enabled_then_reject_origin() {
+        let cors = cors()
+            .origin(portless_pattern())
+            .normalize_default_ports(true)
+            .build();
+
+        let rejection = assert_simple_rejected(
+            simple_request()
+                .origin("https://app.test:8443")
+                .check(&cors),
+        );
+
+        assert_eq!(rejection.reason, SimpleRejectionReason::OriginNotAllowed);
+    }
+}
@@ -9,7 +9,7 @@ use common::asserts::{
     assert_header_eq, assert_preflight, assert_vary_contains, assert_vary_is_empty,
 };
 use common::builders::{cors, preflight_request};
-use common::headers::has_header;
+use common::headers::{has_header, header_value};
 
 mod new {
     use super::*;
@@ -124,6 +124,26 @@ mod check {
         }
     }
 
+    #[test]
+    fn should_reject_preflight_when_request_method_malformed_then_return_malformed_reason() {
+        let decision = preflight_request()
+            .origin("https://foo.bar")
+            .request_method("GE T")
+            .check(&cors().build());
+
+        match decision {
+            CorsDecision::PreflightRejected(rejection) => {
+                assert_eq!(
+                    rejection.reason,
+                    PreflightRejectionReason::MalformedRequestMethod {
+                        requested_method: "ge t".to_string(),
+                    }
+                );
+            }
+            other => panic!("expected preflight rejection, got {:?}", other),
+        }
+    }
+
     #[test]
     fn should_reject_preflight_when_request_headers_disallowed_then_return_rejection() {
         let decision = preflight_request()
@@ -295,6 +315,7 @@ mod check {
         assert_vary_contains(&headers, header::ORIGIN);
     }
 
+    #[cfg(feature = "regex")]
     #[test]
     fn should_mirror_request_origin_when_origin_list_matches_then_emit_vary() {
         let cors = cors()
@@ -405,6 +426,8 @@ mod check {
             access_control_request_method: Some(&requested_method),
             access_control_request_headers: Some(&requested_headers),
             access_control_request_private_network: false,
+            sec_fetch_site: None,
+            host: None,
         };
 
         let headers = assert_preflight(
@@ -505,3 +528,796 @@ mod check {
         );
     }
 }
+
+mod require_origin_on_preflight {
+    use super::*;
+
+    fn originless_preflight() -> RequestContext<'static> {
+        RequestContext {
+            method: method::OPTIONS,
+            origin: None,
+            access_control_request_method: Some(method::POST),
+            access_control_request_headers: None,
+            access_control_request_private_network: false,
+            sec_fetch_site: None,
+            host: None,
+        }
+    }
+
+    #[test]
+    fn should_return_not_applicable_when_origin_missing_by_default_then_skip_cors() {
+        let decision = cors()
+            .origin(Origin::list(["https://app.test"]))
+            .require_origin_on_preflight(false)
+            .build()
+            .check(&originless_preflight())
+            .expect("preflight evaluation should succeed");
+
+        assert!(matches!(decision, CorsDecision::NotApplicable));
+    }
+
+    #[test]
+    fn should_reject_with_origin_missing_when_required_then_report_reason() {
+        let decision = cors()
+            .origin(Origin::list(["https://app.test"]))
+            .require_origin_on_preflight(true)
+            .build()
+            .check(&originless_preflight())
+            .expect("preflight evaluation should succeed");
+
+        match decision {
+            CorsDecision::PreflightRejected(rejection) => {
+                assert_eq!(rejection.reason, PreflightRejectionReason::OriginMissing);
+                assert!(rejection.headers.is_empty());
+            }
+            other => panic!("expected preflight rejection, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn should_accept_preflight_with_origin_when_required_then_evaluate_normally() {
+        let cors = cors()
+            .origin(Origin::list(["https://app.test"]))
+            .require_origin_on_preflight(true)
+            .build();
+
+        let headers = assert_preflight(
+            preflight_request()
+                .origin("https://app.test")
+                .request_method(method::POST)
+                .check(&cors),
+        );
+
+        assert_header_eq(
+            &headers,
+            header::ACCESS_CONTROL_ALLOW_ORIGIN,
+            "https://app.test",
+        );
+    }
+}
+
+mod preflight_grants {
+    use super::*;
+    use bunner_cors_rs::PreflightGrants;
+
+    fn granted(decision: CorsDecision) -> PreflightGrants {
+        match decision {
+            CorsDecision::PreflightAccepted { granted, .. } => granted,
+            other => panic!("expected preflight acceptance, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn should_report_grants_when_private_network_preflight_accepted_then_mirror_configuration() {
+        let cors = cors()
+            .origin(Origin::exact("https://intranet.dev"))
+            .credentials(true)
+            .private_network(true)
+            .max_age(600)
+            .build();
+
+        let decision = preflight_request()
+            .origin("https://intranet.dev")
+            .request_method(method::GET)
+            .private_network(true)
+            .check(&cors);
+
+        assert_eq!(
+            granted(decision),
+            PreflightGrants {
+                private_network: true,
+                max_age: Some(600),
+                credentials: true,
+            }
+        );
+    }
+
+    #[test]
+    fn should_report_no_grants_when_defaults_used_then_leave_flags_unset() {
+        let decision = preflight_request()
+            .origin("https://foo.bar")
+            .request_method(method::GET)
+            .private_network(true)
+            .check(&cors().build());
+
+        assert_eq!(granted(decision), PreflightGrants::default());
+    }
+}
+
+mod aggregate_rejections {
+    use super::*;
+
+    fn rejection_reason(aggregate: bool, request_headers: &str) -> PreflightRejectionReason {
+        let cors = Cors::new(
+            CorsOptions::new()
+                .origin(Origin::list(["https://app.test"]))
+                .allowed_headers(AllowedHeaders::list(["X-Allowed"]))
+                .aggregate_rejections(aggregate),
+        )
+        .expect("valid CORS configuration");
+
+        match preflight_request()
+            .origin("https://app.test")
+            .request_method("TRACE")
+            .request_headers(request_headers)
+            .check(&cors)
+        {
+            CorsDecision::PreflightRejected(rejection) => rejection.reason,
+            other => panic!("expected preflight rejection, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn should_report_first_failure_when_aggregation_disabled_then_keep_single_reason() {
+        let reason = rejection_reason(false, "X-Forbidden");
+
+        assert_eq!(
+            reason,
+            PreflightRejectionReason::MethodNotAllowed {
+                requested_method: "trace".to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn should_report_every_failure_when_aggregation_enabled_then_return_multiple() {
+        let reason = rejection_reason(true, "X-Forbidden");
+
+        assert_eq!(
+            reason,
+            PreflightRejectionReason::Multiple(vec![
+                PreflightRejectionReason::MethodNotAllowed {
+                    requested_method: "trace".to_string(),
+                },
+                PreflightRejectionReason::HeadersNotAllowed {
+                    requested_headers: "x-forbidden".to_string(),
+                },
+            ])
+        );
+    }
+
+    #[test]
+    fn should_report_single_reason_when_only_one_check_fails_then_skip_multiple() {
+        let reason = rejection_reason(true, "X-Allowed");
+
+        assert!(matches!(
+            reason,
+            PreflightRejectionReason::MethodNotAllowed { .. }
+        ));
+    }
+}
+
+mod token_validator {
+    use super::*;
+    use bunner_cors_rs::AllowedMethods;
+    use std::sync::Arc;
+
+    fn legacy_cors(custom: bool) -> Cors {
+        let mut options = CorsOptions::new()
+            .origin(Origin::list(["https://app.test"]))
+            .methods(AllowedMethods::list(if custom {
+                vec![method::GET, "LEGACY@GET"]
+            } else {
+                vec![method::GET]
+            }));
+        if custom {
+            options = options.token_validator(Arc::new(|value: &str| {
+                !value.is_empty() && value.bytes().all(|byte| byte.is_ascii_graphic())
+            }));
+        }
+        Cors::new(options).expect("valid CORS configuration")
+    }
+
+    #[test]
+    fn should_accept_legacy_method_when_custom_validator_configured_then_allow_method() {
+        let cors = legacy_cors(true);
+
+        let headers = assert_preflight(
+            preflight_request()
+                .origin("https://app.test")
+                .request_method("LEGACY@GET")
+                .check(&cors),
+        );
+
+        assert_header_eq(
+            &headers,
+            header::ACCESS_CONTROL_ALLOW_METHODS,
+            "GET,LEGACY@GET",
+        );
+    }
+
+    #[test]
+    fn should_report_malformed_method_when_default_validator_then_reject_preflight() {
+        let cors = legacy_cors(false);
+
+        let decision = preflight_request()
+            .origin("https://app.test")
+            .request_method("LEGACY@GET")
+            .check(&cors);
+
+        match decision {
+            CorsDecision::PreflightRejected(rejection) => assert!(matches!(
+                rejection.reason,
+                PreflightRejectionReason::MalformedRequestMethod { .. }
+            )),
+            other => panic!("expected preflight rejection, got {:?}", other),
+        }
+    }
+}
+
+mod to_response_parts {
+    use super::*;
+
+    #[test]
+    fn should_return_forbidden_with_vary_only_when_origin_not_allowed_then_hide_allow_origin() {
+        let cors = cors().origin(Origin::list(["https://app.test"])).build();
+
+        let decision = preflight_request()
+            .origin("https://other.test")
+            .request_method(method::POST)
+            .check(&cors);
+
+        let CorsDecision::PreflightRejected(rejection) = decision else {
+            panic!("expected preflight rejection, got {:?}", decision);
+        };
+        assert_eq!(rejection.reason, PreflightRejectionReason::OriginNotAllowed);
+        assert_eq!(
+            rejection.to_response_parts(),
+            (
+                403,
+                vec![(header::VARY.to_string(), header::ORIGIN.to_string())]
+            )
+        );
+    }
+
+    #[test]
+    fn should_return_configured_status_when_rejection_status_set_then_use_it() {
+        let cors = cors()
+            .origin(Origin::list(["https://app.test"]))
+            .rejection_status(404)
+            .build();
+
+        let decision = preflight_request()
+            .origin("https://other.test")
+            .request_method(method::POST)
+            .check(&cors);
+
+        let CorsDecision::PreflightRejected(rejection) = decision else {
+            panic!("expected preflight rejection, got {:?}", decision);
+        };
+        assert_eq!(rejection.status, 404);
+        assert_eq!(rejection.to_response_parts().0, 404);
+    }
+}
+
+mod duplicate_requested_headers {
+    use super::*;
+
+    #[test]
+    fn should_reflect_single_token_when_mirrored_header_duplicated_then_drop_repeat() {
+        let cors = cors()
+            .allowed_headers(AllowedHeaders::MirrorRequest)
+            .build();
+
+        let headers = assert_preflight(
+            preflight_request()
+                .origin("https://app.test")
+                .request_method(method::POST)
+                .request_headers("X-Test, x-test")
+                .check(&cors),
+        );
+
+        assert_header_eq(&headers, header::ACCESS_CONTROL_ALLOW_HEADERS, "X-Test");
+    }
+
+    #[test]
+    fn should_accept_preflight_when_listed_header_duplicated_then_compare_once() {
+        let cors = cors()
+            .allowed_headers(AllowedHeaders::list(["X-Test"]))
+            .build();
+
+        let headers = assert_preflight(
+            preflight_request()
+                .origin("https://app.test")
+                .request_method(method::POST)
+                .request_headers("X-Test, x-test")
+                .check(&cors),
+        );
+
+        assert_header_eq(&headers, header::ACCESS_CONTROL_ALLOW_HEADERS, "X-Test");
+    }
+}
+
+mod echo_request {
+    use super::*;
+
+    fn allow_headers(cors: &Cors) -> Option<String> {
+        let headers = assert_preflight(
+            preflight_request()
+                .origin("https://app.test")
+                .request_method(method::POST)
+                .request_headers("X-Test, bad header")
+                .check(cors),
+        );
+        header_value(&headers, header::ACCESS_CONTROL_ALLOW_HEADERS).map(str::to_string)
+    }
+
+    #[test]
+    fn should_drop_invalid_token_when_echo_request_then_reflect_valid_headers_only() {
+        let echo = cors()
+            .allowed_headers(AllowedHeaders::echo_request())
+            .build();
+        let mirror = cors()
+            .allowed_headers(AllowedHeaders::MirrorRequest)
+            .build();
+
+        assert_eq!(allow_headers(&echo).as_deref(), Some("X-Test"));
+        assert_eq!(
+            allow_headers(&mirror).as_deref(),
+            Some("X-Test, bad header")
+        );
+    }
+
+    #[test]
+    fn should_omit_allow_headers_when_every_token_invalid_then_keep_vary() {
+        let cors = cors()
+            .allowed_headers(AllowedHeaders::echo_request())
+            .build();
+
+        let headers = assert_preflight(
+            preflight_request()
+                .origin("https://app.test")
+                .request_method(method::POST)
+                .request_headers("bad header")
+                .check(&cors),
+        );
+
+        assert!(!has_header(&headers, header::ACCESS_CONTROL_ALLOW_HEADERS));
+        assert_vary_contains(&headers, header::ACCESS_CONTROL_REQUEST_HEADERS);
+    }
+}
+
+mod emit_empty_allow_headers {
+    use super::*;
+
+    fn empty_list_cors(emit: bool) -> Cors {
+        Cors::new(
+            CorsOptions::new()
+                .allowed_headers(AllowedHeaders::list(std::iter::empty::<&str>()))
+                .emit_empty_allow_headers(emit),
+        )
+        .expect("valid CORS configuration")
+    }
+
+    #[test]
+    fn should_emit_empty_allow_headers_when_enabled_and_list_empty_then_send_blank_value() {
+        let cors = empty_list_cors(true);
+
+        let headers = assert_preflight(
+            preflight_request()
+                .origin("https://app.test")
+                .request_method(method::POST)
+                .check(&cors),
+        );
+
+        assert_header_eq(&headers, header::ACCESS_CONTROL_ALLOW_HEADERS, "");
+    }
+
+    #[test]
+    fn should_omit_allow_headers_when_disabled_and_list_empty_then_keep_default() {
+        let cors = empty_list_cors(false);
+
+        let headers = assert_preflight(
+            preflight_request()
+                .origin("https://app.test")
+                .request_method(method::POST)
+                .check(&cors),
+        );
+
+        assert!(!has_header(&headers, header::ACCESS_CONTROL_ALLOW_HEADERS));
+    }
+
+    #[test]
+    fn should_reject_preflight_when_enabled_and_header_requested_then_report_headers_not_allowed() {
+        let cors = empty_list_cors(true);
+
+        let decision = preflight_request()
+            .origin("https://app.test")
+            .request_method(method::POST)
+            .request_headers("X-Test")
+            .check(&cors);
+
+        match decision {
+            CorsDecision::PreflightRejected(rejection) => {
+                assert_eq!(
+                    rejection.reason,
+                    PreflightRejectionReason::HeadersNotAllowed {
+                        requested_headers: "x-test".to_string(),
+                    }
+                );
+            }
+            other => panic!("expected preflight rejection, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn should_keep_list_value_when_enabled_and_list_non_empty_then_ignore_option() {
+        let cors = Cors::new(
+            CorsOptions::new()
+                .allowed_headers(AllowedHeaders::list(["X-Test"]))
+                .emit_empty_allow_headers(true),
+        )
+        .expect("valid CORS configuration");
+
+        let headers = assert_preflight(
+            preflight_request()
+                .origin("https://app.test")
+                .request_method(method::POST)
+                .request_headers("X-Test")
+                .check(&cors),
+        );
+
+        assert_header_eq(&headers, header::ACCESS_CONTROL_ALLOW_HEADERS, "X-Test");
+    }
+}
+
+mod request_header_rewriter {
+    use super::*;
+    use std::borrow::Cow;
+    use std::sync::Arc;
+
+    fn strip_vendor_prefix(token: &str) -> Cow<'_, str> {
+        match token.strip_prefix("x-vendor-") {
+            Some(rest) => Cow::Owned(rest.to_string()),
+            None => Cow::Borrowed(token),
+        }
+    }
+
+    fn rewriting_cors(allowed_headers: AllowedHeaders) -> Cors {
+        Cors::new(
+            CorsOptions::new()
+                .allowed_headers(allowed_headers)
+                .request_header_rewriter(Arc::new(strip_vendor_prefix)),
+        )
+        .expect("valid CORS configuration")
+    }
+
+    #[test]
+    fn should_accept_preflight_when_prefixed_token_rewritten_then_match_allow_entry() {
+        let cors = rewriting_cors(AllowedHeaders::list(["X-Test"]));
+
+        let headers = assert_preflight(
+            preflight_request()
+                .origin("https://app.test")
+                .request_method(method::POST)
+                .request_headers("x-vendor-x-test")
+                .check(&cors),
+        );
+
+        assert_header_eq(&headers, header::ACCESS_CONTROL_ALLOW_HEADERS, "X-Test");
+    }
+
+    #[test]
+    fn should_reflect_rewritten_tokens_when_mirroring_then_emit_stripped_names() {
+        let cors = rewriting_cors(AllowedHeaders::MirrorRequest);
+
+        let headers = assert_preflight(
+            preflight_request()
+                .origin("https://app.test")
+                .request_method(method::POST)
+                .request_headers("x-vendor-x-test, content-type")
+                .check(&cors),
+        );
+
+        assert_header_eq(
+            &headers,
+            header::ACCESS_CONTROL_ALLOW_HEADERS,
+            "x-test, content-type",
+        );
+    }
+
+    #[test]
+    fn should_reject_preflight_when_rewritten_token_still_unlisted_then_report_rewritten_value() {
+        let cors = rewriting_cors(AllowedHeaders::list(["X-Test"]));
+
+        let decision = preflight_request()
+            .origin("https://app.test")
+            .request_method(method::POST)
+            .request_headers("x-vendor-x-other")
+            .check(&cors);
+
+        match decision {
+            CorsDecision::PreflightRejected(rejection) => {
+                assert_eq!(
+                    rejection.reason,
+                    PreflightRejectionReason::HeadersNotAllowed {
+                        requested_headers: "x-other".to_string(),
+                    }
+                );
+            }
+            other => panic!("expected preflight rejection, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn should_apply_rewriter_when_previewing_then_match_preflight_value() {
+        let cors = rewriting_cors(AllowedHeaders::list(["X-Test"]));
+
+        assert_eq!(
+            cors.preview_allowed_headers("x-vendor-x-test").as_deref(),
+            Some("X-Test")
+        );
+    }
+}
+
+mod check_with_sink {
+    use super::*;
+    use bunner_cors_rs::{DecisionKind, HeaderSink};
+
+    #[derive(Default)]
+    struct PairSink {
+        pairs: Vec<(String, String)>,
+    }
+
+    impl HeaderSink for PairSink {
+        fn push(&mut self, name: String, value: String) {
+            self.pairs.push((name, value));
+        }
+    }
+
+    fn preflight_context<'a>(origin: &'a str, headers: Option<&'a str>) -> RequestContext<'a> {
+        RequestContext {
+            method: method::OPTIONS,
+            origin: Some(origin),
+            access_control_request_method: Some(method::POST),
+            access_control_request_headers: headers,
+            access_control_request_private_network: false,
+            sec_fetch_site: None,
+            host: None,
+        }
+    }
+
+    fn sorted_pairs(headers: bunner_cors_rs::Headers) -> Vec<(String, String)> {
+        let mut pairs: Vec<_> = headers.into_iter().collect();
+        pairs.sort();
+        pairs
+    }
+
+    #[test]
+    fn should_push_same_headers_as_check_when_preflight_accepted_then_leave_decision_empty() {
+        let cors = cors()
+            .allowed_headers(AllowedHeaders::list(["X-Test"]))
+            .build();
+        let request = preflight_context("https://app.test", Some("X-Test"));
+        let mut sink = PairSink::default();
+
+        let expected = assert_preflight(cors.check(&request).expect("valid decision"));
+        let decision = cors
+            .check_with_sink(&request, &mut sink)
+            .expect("valid decision");
+
+        assert_eq!(decision.kind(), DecisionKind::PreflightAccepted);
+        assert!(matches!(
+            decision,
+            CorsDecision::PreflightAccepted { ref headers, .. } if headers.is_empty()
+        ));
+        let mut pushed = sink.pairs.clone();
+        pushed.sort();
+        assert_eq!(pushed, sorted_pairs(expected));
+    }
+
+    #[test]
+    fn should_push_rejection_headers_when_origin_disallowed_then_keep_reason() {
+        let cors = cors().origin(Origin::exact("https://app.test")).build();
+        let request = preflight_context("https://other.test", None);
+        let mut sink = PairSink::default();
+
+        let decision = cors
+            .check_with_sink(&request, &mut sink)
+            .expect("valid decision");
+
+        match decision {
+            CorsDecision::PreflightRejected(rejection) => {
+                assert_eq!(rejection.reason, PreflightRejectionReason::OriginNotAllowed);
+                assert!(rejection.headers.is_empty());
+            }
+            other => panic!("expected preflight rejection, got {:?}", other),
+        }
+        assert_eq!(
+            sink.pairs,
+            vec![(header::VARY.to_string(), header::ORIGIN.to_string())]
+        );
+    }
+}
+
+mod allowed_headers_by_method {
+    use super::*;
+    use std::collections::HashMap;
+
+    fn per_method_cors() -> Cors {
+        Cors::new(
+            CorsOptions::new()
+                .allowed_headers(AllowedHeaders::list(["X-Fallback"]))
+                .allowed_headers_by_method(HashMap::from([
+                    ("POST".to_string(), AllowedHeaders::list(["Content-Type"])),
+                    ("get".to_string(), AllowedHeaders::list(["Authorization"])),
+                ])),
+        )
+        .expect("valid CORS configuration")
+    }
+
+    fn preflight(cors: &Cors, request_method: &str, request_headers: &str) -> CorsDecision {
+        preflight_request()
+            .origin("https://app.test")
+            .request_method(request_method)
+            .request_headers(request_headers)
+            .check(cors)
+    }
+
+    #[test]
+    fn should_allow_content_type_when_post_preflight_then_reject_same_header_for_get() {
+        let cors = per_method_cors();
+
+        let post = assert_preflight(preflight(&cors, method::POST, "Content-Type"));
+        let get = preflight(&cors, method::GET, "Content-Type");
+
+        assert_header_eq(&post, header::ACCESS_CONTROL_ALLOW_HEADERS, "Content-Type");
+        assert_vary_contains(&post, header::ACCESS_CONTROL_REQUEST_METHOD);
+        match get {
+            CorsDecision::PreflightRejected(rejection) => assert!(matches!(
+                rejection.reason,
+                PreflightRejectionReason::HeadersNotAllowed { .. }
+            )),
+            other => panic!("expected preflight rejection, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn should_use_method_entry_when_get_preflight_then_allow_authorization() {
+        let cors = per_method_cors();
+
+        let headers = assert_preflight(preflight(&cors, method::GET, "Authorization"));
+
+        assert_header_eq(
+            &headers,
+            header::ACCESS_CONTROL_ALLOW_HEADERS,
+            "Authorization",
+        );
+    }
+
+    #[test]
+    fn should_fall_back_to_global_headers_when_method_not_mapped_then_apply_allowed_headers() {
+        let cors = per_method_cors();
+
+        let headers = assert_preflight(preflight(&cors, method::PUT, "X-Fallback"));
+
+        assert_header_eq(&headers, header::ACCESS_CONTROL_ALLOW_HEADERS, "X-Fallback");
+    }
+}
+
+mod strict_preflight {
+    use super::*;
+    use bunner_cors_rs::Headers;
+    use std::borrow::Cow;
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    static STRICT_REWRITES: AtomicUsize = AtomicUsize::new(0);
+    static LENIENT_REWRITES: AtomicUsize = AtomicUsize::new(0);
+
+    fn count_strict(token: &str) -> Cow<'_, str> {
+        STRICT_REWRITES.fetch_add(1, Ordering::Relaxed);
+        Cow::Borrowed(token)
+    }
+
+    fn count_lenient(token: &str) -> Cow<'_, str> {
+        LENIENT_REWRITES.fetch_add(1, Ordering::Relaxed);
+        Cow::Borrowed(token)
+    }
+
+    fn options_without_acrm(origin: Option<&'static str>) -> RequestContext<'static> {
+        RequestContext {
+            method: method::OPTIONS,
+            origin,
+            access_control_request_method: None,
+            access_control_request_headers: Some("X-Test"),
+            access_control_request_private_network: false,
+            sec_fetch_site: None,
+            host: None,
+        }
+    }
+
+    fn strict_cors() -> Cors {
+        Cors::new(
+            CorsOptions::new()
+                .strict_preflight(true)
+                .default_origin_when_missing("https://fallback.test")
+                .request_header_rewriter(Arc::new(count_strict)),
+        )
+        .expect("valid CORS configuration")
+    }
+
+    #[test]
+    fn should_reject_when_strict_and_origin_sent_without_acrm_then_emit_no_headers() {
+        let cors = strict_cors();
+        let mut sink = Headers::new();
+
+        let decision = cors
+            .check_with_sink(&options_without_acrm(Some("https://app.test")), &mut sink)
+            .expect("OPTIONS evaluation should succeed");
+
+        match decision {
+            CorsDecision::PreflightRejected(rejection) => {
+                assert_eq!(
+                    rejection.reason,
+                    PreflightRejectionReason::RequestMethodMissing
+                );
+            }
+            other => panic!("expected preflight rejection, got {other:?}"),
+        }
+        assert!(sink.is_empty());
+        assert_eq!(STRICT_REWRITES.load(Ordering::Relaxed), 0);
+    }
+
+    #[test]
+    fn should_skip_evaluation_when_strict_and_bare_options_then_return_not_applicable() {
+        let cors = strict_cors();
+        let mut sink = Headers::new();
+
+        let decision = cors
+            .check_with_sink(&options_without_acrm(None), &mut sink)
+            .expect("bare OPTIONS evaluation should succeed");
+
+        assert!(matches!(decision, CorsDecision::NotApplicable));
+        assert!(sink.is_empty());
+        assert_eq!(STRICT_REWRITES.load(Ordering::Relaxed), 0);
+    }
+
+    #[test]
+    fn should_keep_not_applicable_when_non_strict_and_acrm_missing_then_still_run_rewriter() {
+        let cors = Cors::new(CorsOptions::new().request_header_rewriter(Arc::new(count_lenient)))
+            .expect("valid CORS configuration");
+
+        let decision = cors
+            .check(&options_without_acrm(Some("https://app.test")))
+            .expect("OPTIONS evaluation should succeed");
+
+        assert!(matches!(decision, CorsDecision::NotApplicable));
+        assert!(LENIENT_REWRITES.load(Ordering::Relaxed) > 0);
+    }
+
+    #[test]
+    fn should_evaluate_preflight_when_strict_and_acrm_present_then_accept_request() {
+        let cors =
+            Cors::new(CorsOptions::new().strict_preflight(true)).expect("valid CORS configuration");
+
+        let headers = assert_preflight(
+            preflight_request()
+                .origin("https://app.test")
+                .request_method(method::POST)
+                .check(&cors),
+        );
+
+        assert_header_eq(&headers, header::ACCESS_CONTROL_ALLOW_ORIGIN, "*");
+    }
+}
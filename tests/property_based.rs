@@ -1,7 +1,9 @@
 mod common;
 
+#[cfg(feature = "regex")]
+use bunner_cors_rs::OriginMatcher;
 use bunner_cors_rs::constants::{header, method};
-use bunner_cors_rs::{AllowedHeaders, CorsDecision, Origin, OriginMatcher};
+use bunner_cors_rs::{AllowedHeaders, CorsDecision, Origin};
 use common::asserts::assert_simple;
 use common::builders::{cors, preflight_request, simple_request};
 use common::headers::header_value;
@@ -75,6 +77,7 @@ mod check {
             prop_assert!(is_preflight_accepted);
         }
 
+        #[cfg(feature = "regex")]
         #[test]
         fn should_mirror_origin_when_regex_list_matches_hybrid_subdomain_then_reflect_origin(subdomain in subdomain_strategy()) {
             let origin = format!("https://{}.hybrid.dev", subdomain);
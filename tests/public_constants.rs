@@ -0,0 +1,81 @@
+use bunner_cors_rs::constants::{header, method};
+
+mod header_constants {
+    use super::*;
+
+    #[test]
+    fn should_expose_canonical_names_when_referenced_then_match_specification() {
+        let expected = [
+            (
+                header::ACCESS_CONTROL_ALLOW_ORIGIN,
+                "Access-Control-Allow-Origin",
+            ),
+            (
+                header::ACCESS_CONTROL_ALLOW_METHODS,
+                "Access-Control-Allow-Methods",
+            ),
+            (
+                header::ACCESS_CONTROL_ALLOW_HEADERS,
+                "Access-Control-Allow-Headers",
+            ),
+            (
+                header::ACCESS_CONTROL_ALLOW_CREDENTIALS,
+                "Access-Control-Allow-Credentials",
+            ),
+            (
+                header::ACCESS_CONTROL_ALLOW_PRIVATE_NETWORK,
+                "Access-Control-Allow-Private-Network",
+            ),
+            (
+                header::ACCESS_CONTROL_EXPOSE_HEADERS,
+                "Access-Control-Expose-Headers",
+            ),
+            (header::ACCESS_CONTROL_MAX_AGE, "Access-Control-Max-Age"),
+            (
+                header::ACCESS_CONTROL_REQUEST_HEADERS,
+                "Access-Control-Request-Headers",
+            ),
+            (
+                header::ACCESS_CONTROL_REQUEST_METHOD,
+                "Access-Control-Request-Method",
+            ),
+            (
+                header::ACCESS_CONTROL_REQUEST_PRIVATE_NETWORK,
+                "Access-Control-Request-Private-Network",
+            ),
+            (header::CONTENT_LENGTH, "Content-Length"),
+            (header::HOST, "Host"),
+            (header::SEC_FETCH_SITE, "Sec-Fetch-Site"),
+            (header::TIMING_ALLOW_ORIGIN, "Timing-Allow-Origin"),
+            (header::ORIGIN, "Origin"),
+            (header::VARY, "Vary"),
+        ];
+
+        for (actual, expected) in expected {
+            assert_eq!(actual, expected);
+        }
+    }
+}
+
+mod method_constants {
+    use super::*;
+
+    #[test]
+    fn should_expose_standard_methods_when_referenced_then_use_uppercase_tokens() {
+        let expected = [
+            (method::CONNECT, "CONNECT"),
+            (method::DELETE, "DELETE"),
+            (method::GET, "GET"),
+            (method::HEAD, "HEAD"),
+            (method::OPTIONS, "OPTIONS"),
+            (method::PATCH, "PATCH"),
+            (method::POST, "POST"),
+            (method::PUT, "PUT"),
+            (method::TRACE, "TRACE"),
+        ];
+
+        for (actual, expected) in expected {
+            assert_eq!(actual, expected);
+        }
+    }
+}
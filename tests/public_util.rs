@@ -0,0 +1,66 @@
+use bunner_cors_rs::util::{equals_ignore_case, is_http_token, normalize_lower};
+
+mod normalize_lower {
+    use super::*;
+
+    #[test]
+    fn should_lowercase_ascii_when_input_ascii_then_match_engine_normalization() {
+        assert_eq!(normalize_lower("X-Custom-HEADER"), "x-custom-header");
+        assert_eq!(normalize_lower("HTTPS://App.Test"), "https://app.test");
+    }
+
+    #[test]
+    fn should_lowercase_unicode_when_input_unicode_then_apply_unicode_mapping() {
+        assert_eq!(normalize_lower("TÉST"), "tést");
+        assert_eq!(normalize_lower("HTTPS://MÜNCHEN.DE"), "https://münchen.de");
+        assert_eq!(normalize_lower("straße"), "straße");
+    }
+}
+
+mod equals_ignore_case {
+    use super::*;
+
+    #[test]
+    fn should_compare_ascii_case_insensitively_when_inputs_ascii_then_ignore_case() {
+        assert!(equals_ignore_case("Content-Type", "content-type"));
+        assert!(!equals_ignore_case("Content-Type", "content-length"));
+    }
+
+    #[test]
+    fn should_compare_unicode_case_insensitively_when_inputs_unicode_then_fold_case() {
+        assert!(equals_ignore_case("TÉST", "tést"));
+        assert!(equals_ignore_case(
+            "https://MÜNCHEN.de",
+            "https://münchen.DE"
+        ));
+        assert!(!equals_ignore_case("tést", "test"));
+    }
+
+    #[test]
+    fn should_agree_with_normalize_lower_when_inputs_mixed_then_stay_consistent() {
+        for (a, b) in [("ÀBC", "àbc"), ("X-Test", "x-TEST"), ("Ω", "ω"), ("a", "b")] {
+            assert_eq!(
+                equals_ignore_case(a, b),
+                normalize_lower(a) == normalize_lower(b)
+            );
+        }
+    }
+}
+
+mod is_http_token {
+    use super::*;
+
+    #[test]
+    fn should_accept_token_characters_when_ascii_then_return_true() {
+        assert!(is_http_token("X-Custom_Header.v1"));
+        assert!(is_http_token("!#$%&'*+-.^_`|~"));
+    }
+
+    #[test]
+    fn should_reject_separators_and_unicode_when_outside_grammar_then_return_false() {
+        assert!(!is_http_token(""));
+        assert!(!is_http_token("X Header"));
+        assert!(!is_http_token("X-Header:"));
+        assert!(!is_http_token("X-Tést"));
+    }
+}
@@ -0,0 +1,44 @@
+#![cfg(feature = "regex")]
+
+use bunner_cors_rs::{OriginMatcher, PatternError, RegexSizeLimits};
+
+mod pattern_str_with_limits {
+    use super::*;
+
+    const LARGE: &str = r"^https://[a-z0-9]{2000}\.test$";
+
+    fn tight_limits() -> RegexSizeLimits {
+        RegexSizeLimits::new(16 * 1024, 64 * 1024)
+    }
+
+    #[test]
+    fn should_reject_pattern_when_bounded_repetition_exceeds_limit_then_return_too_complex() {
+        let large = OriginMatcher::pattern_str_with_limits(LARGE, tight_limits());
+        let small =
+            OriginMatcher::pattern_str_with_limits(r"^https://[a-z]+\.test$", tight_limits());
+
+        match large {
+            Err(PatternError::TooComplex { pattern, limit }) => {
+                assert_eq!(pattern, LARGE);
+                assert_eq!(limit, 16 * 1024);
+            }
+            Err(other) => panic!("unexpected pattern error: {other:?}"),
+            Ok(_) => panic!("expected size limit error"),
+        }
+        assert!(
+            small
+                .expect("small pattern fits")
+                .matches("https://app.test")
+        );
+    }
+
+    #[test]
+    fn should_apply_limits_when_pattern_cached_with_defaults_then_keep_entries_apart() {
+        let default = OriginMatcher::pattern_str(LARGE);
+        let limited = OriginMatcher::pattern_str_with_limits(LARGE, tight_limits());
+
+        assert!(default.is_ok());
+        assert!(matches!(limited, Err(PatternError::TooComplex { .. })));
+        assert!(OriginMatcher::pattern_str(LARGE).is_ok());
+    }
+}
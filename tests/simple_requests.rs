@@ -23,12 +23,12 @@ mod check {
     }
 
     #[test]
-    fn should_return_not_applicable_when_simple_request_without_origin_then_skip() {
+    fn should_return_allowed_no_headers_when_simple_request_without_origin_then_emit_nothing() {
         let cors = cors().build();
 
         let decision = simple_request().check(&cors);
 
-        assert!(matches!(decision, CorsDecision::NotApplicable));
+        assert!(matches!(decision, CorsDecision::AllowedNoHeaders));
     }
 
     #[test]
@@ -108,3 +108,230 @@ mod check {
         assert!(matches!(decision, CorsDecision::NotApplicable));
     }
 }
+
+mod self_origin {
+    use super::*;
+    use bunner_cors_rs::{Cors, CorsOptions};
+
+    fn cors_for(self_origin: &str) -> Cors {
+        Cors::new(
+            CorsOptions::new()
+                .origin(Origin::list(["https://partner.test"]))
+                .self_origin(self_origin),
+        )
+        .expect("valid CORS configuration")
+    }
+
+    #[test]
+    fn should_return_not_applicable_when_same_origin_get_then_skip_headers() {
+        let cors = cors_for("https://app.test");
+
+        let decision = simple_request().origin("HTTPS://APP.TEST").check(&cors);
+
+        assert!(matches!(decision, CorsDecision::NotApplicable));
+    }
+
+    #[test]
+    fn should_process_normally_when_cross_origin_get_then_apply_policy() {
+        let cors = cors_for("https://app.test");
+
+        let headers = assert_simple(simple_request().origin("https://partner.test").check(&cors));
+        let rejection =
+            assert_simple_rejected(simple_request().origin("https://other.test").check(&cors));
+
+        assert_eq!(
+            header_value(&headers, header::ACCESS_CONTROL_ALLOW_ORIGIN),
+            Some("https://partner.test"),
+        );
+        assert_eq!(rejection.reason, SimpleRejectionReason::OriginNotAllowed);
+    }
+}
+
+mod reject_disallowed_simple_method {
+    use super::*;
+    use bunner_cors_rs::{AllowedMethods, Cors, CorsOptions};
+
+    fn get_only(reject: bool) -> Cors {
+        Cors::new(
+            CorsOptions::new()
+                .origin(Origin::list(["https://app.test"]))
+                .methods(AllowedMethods::list([method::GET]))
+                .reject_disallowed_simple_method(reject),
+        )
+        .expect("valid CORS configuration")
+    }
+
+    #[test]
+    fn should_reject_with_method_when_enabled_and_delete_disallowed_then_return_method_not_allowed()
+    {
+        let cors = get_only(true);
+
+        let rejection = assert_simple_rejected(
+            simple_request()
+                .method(method::DELETE)
+                .origin("https://app.test")
+                .check(&cors),
+        );
+
+        assert_eq!(
+            rejection.reason,
+            SimpleRejectionReason::MethodNotAllowed {
+                requested_method: "delete".to_string(),
+            }
+        );
+        assert!(has_header(
+            &rejection.headers,
+            header::ACCESS_CONTROL_ALLOW_ORIGIN
+        ));
+    }
+
+    #[test]
+    fn should_return_not_applicable_when_disabled_and_delete_disallowed_then_keep_default() {
+        let cors = get_only(false);
+
+        let decision = simple_request()
+            .method(method::DELETE)
+            .origin("https://app.test")
+            .check(&cors);
+
+        assert!(matches!(decision, CorsDecision::NotApplicable));
+    }
+}
+
+mod allowed_no_headers {
+    use super::*;
+    use common::builders::preflight_request;
+
+    #[test]
+    fn should_return_allowed_no_headers_when_any_origin_and_origin_missing_then_distinguish_from_skip()
+     {
+        let cors = cors().origin(Origin::any()).build();
+
+        let decision = simple_request().method(method::POST).check(&cors);
+
+        assert!(matches!(decision, CorsDecision::AllowedNoHeaders));
+        assert!(!decision.allows_credentials());
+    }
+
+    #[test]
+    fn should_return_not_applicable_when_origin_missing_and_method_disallowed_then_skip() {
+        let cors = cors().origin(Origin::any()).methods([method::GET]).build();
+
+        let decision = simple_request().method(method::DELETE).check(&cors);
+
+        assert!(matches!(decision, CorsDecision::NotApplicable));
+    }
+
+    #[test]
+    fn should_return_not_applicable_when_list_origin_and_origin_missing_then_skip() {
+        let cors = cors().origin(Origin::list(["https://app.test"])).build();
+
+        let decision = simple_request().check(&cors);
+
+        assert!(matches!(decision, CorsDecision::NotApplicable));
+    }
+
+    #[test]
+    fn should_return_not_applicable_when_origin_disabled_then_keep_skip() {
+        let cors = cors().origin(Origin::disabled()).build();
+
+        let decision = simple_request().origin("https://app.test").check(&cors);
+
+        assert!(matches!(decision, CorsDecision::NotApplicable));
+    }
+
+    #[test]
+    fn should_return_not_applicable_when_preflight_without_origin_then_leave_options_alone() {
+        let cors = cors().origin(Origin::any()).build();
+
+        let decision = preflight_request()
+            .request_method(method::POST)
+            .check(&cors);
+
+        assert!(matches!(decision, CorsDecision::NotApplicable));
+    }
+}
+
+mod private_network_on_simple {
+    use super::*;
+    use bunner_cors_rs::{Cors, CorsOptions};
+
+    fn private_network_cors(on_simple: bool) -> Cors {
+        Cors::new(
+            CorsOptions::new()
+                .origin(Origin::exact("https://example.com"))
+                .credentials(true)
+                .allow_private_network(true)
+                .private_network_on_simple(on_simple),
+        )
+        .expect("valid CORS configuration")
+    }
+
+    #[test]
+    fn should_emit_private_network_header_when_enabled_and_flag_sent_then_grant_access() {
+        let cors = private_network_cors(true);
+
+        let headers = assert_simple(
+            simple_request()
+                .origin("https://example.com")
+                .private_network(true)
+                .check(&cors),
+        );
+
+        assert_eq!(
+            header_value(&headers, header::ACCESS_CONTROL_ALLOW_PRIVATE_NETWORK),
+            Some("true"),
+        );
+    }
+
+    #[test]
+    fn should_omit_private_network_header_when_disabled_and_flag_sent_then_keep_preflight_only() {
+        let cors = private_network_cors(false);
+
+        let headers = assert_simple(
+            simple_request()
+                .origin("https://example.com")
+                .private_network(true)
+                .check(&cors),
+        );
+
+        assert!(!has_header(
+            &headers,
+            header::ACCESS_CONTROL_ALLOW_PRIVATE_NETWORK
+        ));
+    }
+
+    #[test]
+    fn should_omit_private_network_header_when_enabled_and_flag_absent_then_skip_header() {
+        let cors = private_network_cors(true);
+
+        let headers = assert_simple(simple_request().origin("https://example.com").check(&cors));
+
+        assert!(!has_header(
+            &headers,
+            header::ACCESS_CONTROL_ALLOW_PRIVATE_NETWORK
+        ));
+    }
+
+    #[test]
+    fn should_omit_private_network_header_when_private_network_disallowed_then_ignore_option() {
+        let cors = Cors::new(
+            CorsOptions::new()
+                .origin(Origin::exact("https://example.com"))
+                .private_network_on_simple(true),
+        )
+        .expect("valid CORS configuration");
+
+        let headers = assert_simple(
+            simple_request()
+                .origin("https://example.com")
+                .private_network(true)
+                .check(&cors),
+        );
+
+        assert!(!has_header(
+            &headers,
+            header::ACCESS_CONTROL_ALLOW_PRIVATE_NETWORK
+        ));
+    }
+}
@@ -69,6 +69,7 @@ mod capture_preflight {
         });
     }
 
+    #[cfg(feature = "regex")]
     #[test]
     fn should_capture_preflight_when_strict_origin_configured_then_match_snapshot() {
         let cors = cors()
@@ -96,3 +97,61 @@ mod capture_preflight {
         });
     }
 }
+
+mod deterministic_header_order {
+    use super::*;
+    use bunner_cors_rs::{CorsOptions, ExposedHeaders, HeaderSink, RequestContext};
+
+    impl HeaderSink for PreflightSnapshot {
+        fn push(&mut self, name: String, value: String) {
+            self.headers.push(HeaderSnapshot { name, value });
+        }
+    }
+
+    fn capture_ordered(cors: &Cors) -> PreflightSnapshot {
+        let request = RequestContext {
+            method: method::OPTIONS,
+            origin: Some("https://ordered.dev"),
+            access_control_request_method: Some(method::POST),
+            access_control_request_headers: Some("X-Trace-Id"),
+            access_control_request_private_network: false,
+            sec_fetch_site: None,
+            host: None,
+        };
+        let mut snapshot = PreflightSnapshot {
+            headers: Vec::new(),
+        };
+        cors.check_with_sink(&request, &mut snapshot)
+            .expect("preflight request evaluation should succeed");
+        snapshot
+    }
+
+    #[test]
+    fn should_push_canonical_order_when_deterministic_order_enabled_then_match_snapshot() {
+        let cors = Cors::new(
+            CorsOptions::new()
+                .origin(Origin::list([OriginMatcher::exact("https://ordered.dev")]))
+                .credentials(true)
+                .allowed_headers(AllowedHeaders::list(["X-Trace-Id"]))
+                .exposed_headers(ExposedHeaders::list(["X-Result"]))
+                .max_age(600)
+                .deterministic_header_order(true),
+        )
+        .expect("valid CORS configuration");
+
+        let first = capture_ordered(&cors);
+        let second = capture_ordered(&cors);
+
+        let names = |snapshot: &PreflightSnapshot| {
+            snapshot
+                .headers
+                .iter()
+                .map(|header| header.name.clone())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(names(&first), names(&second));
+        insta::with_settings!({prepend_module_to_snapshot => false}, {
+            assert_yaml_snapshot!("deterministic_order_preflight_snapshot", first);
+        });
+    }
+}
@@ -58,3 +58,125 @@ mod check {
         }
     }
 }
+
+mod compiled_check {
+    use super::*;
+    use bunner_cors_rs::{CompiledCors, Cors, CorsOptions, ExposedHeaders, RequestContext};
+
+    fn assert_send_sync<T: Send + Sync>() {}
+
+    #[test]
+    fn should_evaluate_concurrently_when_compiled_cors_shared_then_return_identical_headers() {
+        assert_send_sync::<CompiledCors>();
+        let compiled = Arc::new(
+            Cors::new(
+                CorsOptions::new()
+                    .origin(Origin::list(["https://shared.example"]))
+                    .allowed_headers(AllowedHeaders::list(["X-Thread"]))
+                    .exposed_headers(ExposedHeaders::list(["X-Trace"]))
+                    .max_age(60),
+            )
+            .expect("valid CORS configuration")
+            .compile(),
+        );
+
+        let handles: Vec<_> = (0..2)
+            .map(|_| {
+                let compiled = Arc::clone(&compiled);
+                thread::spawn(move || {
+                    (0..100)
+                        .map(|_| {
+                            let request = RequestContext {
+                                method: method::OPTIONS,
+                                origin: Some("https://shared.example"),
+                                access_control_request_method: Some(method::POST),
+                                access_control_request_headers: Some("X-Thread"),
+                                access_control_request_private_network: false,
+                                sec_fetch_site: None,
+                                host: None,
+                            };
+                            assert_preflight(compiled.check(&request).expect("evaluation succeeds"))
+                        })
+                        .collect::<Vec<_>>()
+                })
+            })
+            .collect();
+
+        for handle in handles {
+            for headers in handle.join().expect("thread panic") {
+                assert_eq!(
+                    header_value(&headers, header::ACCESS_CONTROL_ALLOW_HEADERS),
+                    Some("X-Thread"),
+                );
+                assert_eq!(
+                    header_value(&headers, header::ACCESS_CONTROL_MAX_AGE),
+                    Some("60"),
+                );
+            }
+        }
+    }
+}
+
+mod reload {
+    use super::*;
+    use bunner_cors_rs::{AllowedMethods, Cors, CorsOptions, RequestContext};
+    use std::sync::atomic::{AtomicBool, Ordering};
+
+    fn options(method: &str, max_age: u64) -> CorsOptions {
+        CorsOptions::new()
+            .methods(AllowedMethods::list([method]))
+            .max_age(max_age)
+    }
+
+    #[test]
+    fn should_never_observe_torn_configuration_when_reloading_during_evaluation() {
+        let cors = Arc::new(Cors::new(options(method::GET, 10)).expect("valid configuration"));
+        let stop = Arc::new(AtomicBool::new(false));
+
+        let readers: Vec<_> = (0..4)
+            .map(|_| {
+                let cors = Arc::clone(&cors);
+                let stop = Arc::clone(&stop);
+                thread::spawn(move || {
+                    let mut evaluations = 0usize;
+                    while !stop.load(Ordering::Relaxed) || evaluations == 0 {
+                        let request = RequestContext {
+                            method: method::OPTIONS,
+                            origin: Some("https://reload.example"),
+                            access_control_request_method: Some(method::GET),
+                            access_control_request_headers: None,
+                            access_control_request_private_network: false,
+                            sec_fetch_site: None,
+                            host: None,
+                        };
+                        let headers =
+                            assert_preflight(cors.check(&request).expect("evaluation succeeds"));
+                        let methods = header_value(&headers, header::ACCESS_CONTROL_ALLOW_METHODS);
+                        let max_age = header_value(&headers, header::ACCESS_CONTROL_MAX_AGE);
+                        match (methods, max_age) {
+                            (Some("GET"), Some("10")) | (Some("GET,POST"), Some("20")) => {}
+                            other => panic!("torn configuration observed: {:?}", other),
+                        }
+                        evaluations += 1;
+                    }
+                })
+            })
+            .collect();
+
+        for iteration in 0..500 {
+            let next = if iteration % 2 == 0 {
+                CorsOptions::new()
+                    .methods(AllowedMethods::list([method::GET, method::POST]))
+                    .max_age(20)
+            } else {
+                options(method::GET, 10)
+            };
+            cors.reload(next).expect("reload succeeds");
+        }
+        stop.store(true, Ordering::Relaxed);
+
+        for reader in readers {
+            reader.join().expect("thread panic");
+        }
+    }
+}
@@ -0,0 +1,119 @@
+#![cfg(feature = "watch")]
+
+mod common;
+
+use bunner_cors_rs::{Cors, CorsOptions, Origin, OriginFileError, WatchedOriginFile};
+use common::builders::simple_request;
+use std::fs;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::thread;
+use std::time::{Duration, Instant};
+
+struct TempAllowList {
+    directory: PathBuf,
+    path: PathBuf,
+}
+
+impl TempAllowList {
+    fn new(contents: &str) -> Self {
+        static NEXT: AtomicUsize = AtomicUsize::new(0);
+        let directory = std::env::temp_dir().join(format!(
+            "bunner-cors-watch-{}-{}",
+            std::process::id(),
+            NEXT.fetch_add(1, Ordering::Relaxed)
+        ));
+        fs::create_dir_all(&directory).expect("create temp directory");
+        let path = directory.join("origins.txt");
+        fs::write(&path, contents).expect("write allow-list");
+        Self { directory, path }
+    }
+
+    fn rewrite(&self, contents: &str) {
+        let staged = self.path.with_extension("tmp");
+        fs::write(&staged, contents).expect("stage allow-list");
+        fs::rename(&staged, &self.path).expect("replace allow-list");
+    }
+}
+
+impl Drop for TempAllowList {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.directory);
+    }
+}
+
+fn allows(cors: &Cors, origin: &str) -> bool {
+    !matches!(
+        simple_request().origin(origin).check(cors),
+        bunner_cors_rs::CorsDecision::SimpleRejected(_)
+    )
+}
+
+fn cors_for(origin: Origin) -> Cors {
+    Cors::new(CorsOptions::new().origin(origin)).expect("valid CORS configuration")
+}
+
+mod watched_origin_file {
+    use super::*;
+
+    #[test]
+    fn should_switch_allowed_origins_when_file_rewritten_then_apply_after_reload() {
+        let file = TempAllowList::new("https://old.test\n");
+        let watched = WatchedOriginFile::new(&file.path).expect("load allow-list");
+        let cors = cors_for(watched.origin());
+        assert!(allows(&cors, "https://old.test"));
+        assert!(!allows(&cors, "https://new.test"));
+
+        file.rewrite("# rotated\nhttps://new.test\n");
+        watched.reload().expect("reload allow-list");
+
+        assert!(allows(&cors, "https://new.test"));
+        assert!(!allows(&cors, "https://old.test"));
+    }
+
+    #[test]
+    fn should_keep_previous_list_when_rewritten_file_invalid_then_report_pattern_error() {
+        let file = TempAllowList::new("https://old.test\n");
+        let watched = WatchedOriginFile::new(&file.path).expect("load allow-list");
+        let cors = cors_for(watched.origin());
+
+        file.rewrite("re:(unclosed\n");
+        let result = watched.reload();
+
+        assert!(matches!(result, Err(OriginFileError::Pattern { .. })));
+        assert!(allows(&cors, "https://old.test"));
+    }
+
+    #[test]
+    fn should_return_io_error_when_file_missing_then_fail_construction() {
+        let file = TempAllowList::new("");
+        let missing = file.path.with_file_name("missing.txt");
+
+        let result = WatchedOriginFile::new(&missing);
+
+        assert!(matches!(result, Err(OriginFileError::Io { .. })));
+    }
+}
+
+mod from_file_watched {
+    use super::*;
+
+    #[test]
+    fn should_pick_up_file_change_when_watcher_notified_then_allow_new_origin() {
+        let file = TempAllowList::new("https://old.test\n");
+        let cors = cors_for(Origin::from_file_watched(&file.path).expect("watch allow-list"));
+        assert!(!allows(&cors, "https://new.test"));
+
+        file.rewrite("https://new.test\n");
+
+        let deadline = Instant::now() + Duration::from_secs(10);
+        while !allows(&cors, "https://new.test") {
+            assert!(
+                Instant::now() < deadline,
+                "watcher did not reload the allow-list in time"
+            );
+            thread::sleep(Duration::from_millis(20));
+        }
+        assert!(!allows(&cors, "https://old.test"));
+    }
+}